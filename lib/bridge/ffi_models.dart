@@ -6,7 +6,46 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+
+class AnnouncementDto {
+  final String stage;
+  final int step;
+  final String kind;
+  final String? streetName;
+  final double distanceM;
+  final String? thenKind;
+
+  const AnnouncementDto({
+    required this.stage,
+    required this.step,
+    required this.kind,
+    this.streetName,
+    required this.distanceM,
+    this.thenKind,
+  });
+
+  @override
+  int get hashCode =>
+      stage.hashCode ^
+      step.hashCode ^
+      kind.hashCode ^
+      streetName.hashCode ^
+      distanceM.hashCode ^
+      thenKind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AnnouncementDto &&
+          runtimeType == other.runtimeType &&
+          stage == other.stage &&
+          step == other.step &&
+          kind == other.kind &&
+          streetName == other.streetName &&
+          distanceM == other.distanceM &&
+          thenKind == other.thenKind;
+}
 
 class DerivedInstructionDto {
   final String kind;
@@ -33,6 +72,24 @@ class DerivedInstructionDto {
           streetName == other.streetName;
 }
 
+class DeviceMessageDto {
+  final String deviceId;
+  final Uint8List bytes;
+
+  const DeviceMessageDto({required this.deviceId, required this.bytes});
+
+  @override
+  int get hashCode => deviceId.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeviceMessageDto &&
+          runtimeType == other.runtimeType &&
+          deviceId == other.deviceId &&
+          bytes == other.bytes;
+}
+
 class GeocodingResultDto {
   final double latitude;
   final double longitude;
@@ -80,6 +137,30 @@ class GeocodingResultDto {
           osmId == other.osmId;
 }
 
+class LegEtaDto {
+  final int index;
+  final double distanceM;
+  final BigInt etaSeconds;
+
+  const LegEtaDto({
+    required this.index,
+    required this.distanceM,
+    required this.etaSeconds,
+  });
+
+  @override
+  int get hashCode => index.hashCode ^ distanceM.hashCode ^ etaSeconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LegEtaDto &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          distanceM == other.distanceM &&
+          etaSeconds == other.etaSeconds;
+}
+
 class NavigationSessionDto {
   final String id;
   final RouteDto route;
@@ -122,11 +203,35 @@ class NavigationStateDto {
   final double distanceToNextM;
   final double distanceRemainingM;
   final BigInt etaSeconds;
+  final BigInt etaLowSeconds;
+  final BigInt etaHighSeconds;
   final bool isOffRoute;
   final double distanceFromRouteM;
   final double snappedLat;
   final double snappedLon;
   final List<String> constraintAlerts;
+  final List<String> raisedAlerts;
+  final int? speedLimitKmh;
+  final int activeSegment;
+  final String? segmentIntent;
+  final int? nextWaypointIndex;
+  final double? nextWaypointDistanceM;
+  final BigInt? nextWaypointEtaSeconds;
+  final int waypointsPassed;
+  final List<WaypointEtaDto> waypointEtas;
+  final List<LegEtaDto> legEtas;
+  final bool arrived;
+  final List<AnnouncementDto> announcements;
+  final bool estimated;
+  final RejoinDto? rejoin;
+  final WrongWayDto? wrongWay;
+  final double? rangeLeftM;
+  final bool lowRange;
+  final int? nextFuelIndex;
+  final double? nextFuelDistanceM;
+  final BigInt? breakDueRidingS;
+  final int? nextBreakIndex;
+  final double? nextBreakDistanceM;
 
   const NavigationStateDto({
     required this.currentStep,
@@ -135,11 +240,35 @@ class NavigationStateDto {
     required this.distanceToNextM,
     required this.distanceRemainingM,
     required this.etaSeconds,
+    required this.etaLowSeconds,
+    required this.etaHighSeconds,
     required this.isOffRoute,
     required this.distanceFromRouteM,
     required this.snappedLat,
     required this.snappedLon,
     required this.constraintAlerts,
+    required this.raisedAlerts,
+    this.speedLimitKmh,
+    required this.activeSegment,
+    this.segmentIntent,
+    this.nextWaypointIndex,
+    this.nextWaypointDistanceM,
+    this.nextWaypointEtaSeconds,
+    required this.waypointsPassed,
+    required this.waypointEtas,
+    required this.legEtas,
+    required this.arrived,
+    required this.announcements,
+    required this.estimated,
+    this.rejoin,
+    this.wrongWay,
+    this.rangeLeftM,
+    required this.lowRange,
+    this.nextFuelIndex,
+    this.nextFuelDistanceM,
+    this.breakDueRidingS,
+    this.nextBreakIndex,
+    this.nextBreakDistanceM,
   });
 
   @override
@@ -150,11 +279,35 @@ class NavigationStateDto {
       distanceToNextM.hashCode ^
       distanceRemainingM.hashCode ^
       etaSeconds.hashCode ^
+      etaLowSeconds.hashCode ^
+      etaHighSeconds.hashCode ^
       isOffRoute.hashCode ^
       distanceFromRouteM.hashCode ^
       snappedLat.hashCode ^
       snappedLon.hashCode ^
-      constraintAlerts.hashCode;
+      constraintAlerts.hashCode ^
+      raisedAlerts.hashCode ^
+      speedLimitKmh.hashCode ^
+      activeSegment.hashCode ^
+      segmentIntent.hashCode ^
+      nextWaypointIndex.hashCode ^
+      nextWaypointDistanceM.hashCode ^
+      nextWaypointEtaSeconds.hashCode ^
+      waypointsPassed.hashCode ^
+      waypointEtas.hashCode ^
+      legEtas.hashCode ^
+      arrived.hashCode ^
+      announcements.hashCode ^
+      estimated.hashCode ^
+      rejoin.hashCode ^
+      wrongWay.hashCode ^
+      rangeLeftM.hashCode ^
+      lowRange.hashCode ^
+      nextFuelIndex.hashCode ^
+      nextFuelDistanceM.hashCode ^
+      breakDueRidingS.hashCode ^
+      nextBreakIndex.hashCode ^
+      nextBreakDistanceM.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -167,11 +320,70 @@ class NavigationStateDto {
           distanceToNextM == other.distanceToNextM &&
           distanceRemainingM == other.distanceRemainingM &&
           etaSeconds == other.etaSeconds &&
+          etaLowSeconds == other.etaLowSeconds &&
+          etaHighSeconds == other.etaHighSeconds &&
           isOffRoute == other.isOffRoute &&
           distanceFromRouteM == other.distanceFromRouteM &&
           snappedLat == other.snappedLat &&
           snappedLon == other.snappedLon &&
-          constraintAlerts == other.constraintAlerts;
+          constraintAlerts == other.constraintAlerts &&
+          raisedAlerts == other.raisedAlerts &&
+          speedLimitKmh == other.speedLimitKmh &&
+          activeSegment == other.activeSegment &&
+          segmentIntent == other.segmentIntent &&
+          nextWaypointIndex == other.nextWaypointIndex &&
+          nextWaypointDistanceM == other.nextWaypointDistanceM &&
+          nextWaypointEtaSeconds == other.nextWaypointEtaSeconds &&
+          waypointsPassed == other.waypointsPassed &&
+          waypointEtas == other.waypointEtas &&
+          legEtas == other.legEtas &&
+          arrived == other.arrived &&
+          announcements == other.announcements &&
+          estimated == other.estimated &&
+          rejoin == other.rejoin &&
+          wrongWay == other.wrongWay &&
+          rangeLeftM == other.rangeLeftM &&
+          lowRange == other.lowRange &&
+          nextFuelIndex == other.nextFuelIndex &&
+          nextFuelDistanceM == other.nextFuelDistanceM &&
+          breakDueRidingS == other.breakDueRidingS &&
+          nextBreakIndex == other.nextBreakIndex &&
+          nextBreakDistanceM == other.nextBreakDistanceM;
+}
+
+class RejoinDto {
+  final double lat;
+  final double lon;
+  final double distanceM;
+  final double bearingDeg;
+  final String? direction;
+
+  const RejoinDto({
+    required this.lat,
+    required this.lon,
+    required this.distanceM,
+    required this.bearingDeg,
+    this.direction,
+  });
+
+  @override
+  int get hashCode =>
+      lat.hashCode ^
+      lon.hashCode ^
+      distanceM.hashCode ^
+      bearingDeg.hashCode ^
+      direction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RejoinDto &&
+          runtimeType == other.runtimeType &&
+          lat == other.lat &&
+          lon == other.lon &&
+          distanceM == other.distanceM &&
+          bearingDeg == other.bearingDeg &&
+          direction == other.direction;
 }
 
 class RouteDto {
@@ -259,3 +471,45 @@ class WaypointDto {
           longitude == other.longitude &&
           name == other.name;
 }
+
+class WaypointEtaDto {
+  final int index;
+  final double distanceM;
+  final BigInt etaSeconds;
+
+  const WaypointEtaDto({
+    required this.index,
+    required this.distanceM,
+    required this.etaSeconds,
+  });
+
+  @override
+  int get hashCode => index.hashCode ^ distanceM.hashCode ^ etaSeconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WaypointEtaDto &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          distanceM == other.distanceM &&
+          etaSeconds == other.etaSeconds;
+}
+
+class WrongWayDto {
+  final DerivedInstructionDto instruction;
+  final double distanceM;
+
+  const WrongWayDto({required this.instruction, required this.distanceM});
+
+  @override
+  int get hashCode => instruction.hashCode ^ distanceM.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WrongWayDto &&
+          runtimeType == other.runtimeType &&
+          instruction == other.instruction &&
+          distanceM == other.distanceM;
+}
@@ -87,6 +87,11 @@ abstract class RustBridgeApi extends BaseApi {
     required int mtu,
   });
 
+  void crateConfirmDeviceSigningKey({
+    required PlatformInt64 id,
+    required int keyId,
+  });
+
   Uint8List crateCreateControlMessage({
     required String routeId,
     required String commandType,
@@ -117,6 +122,20 @@ abstract class RustBridgeApi extends BaseApi {
     String? tileUrlTemplate,
   });
 
+  Future<NavigationStateDto?> crateEstimateNavigationPosition({
+    required String sessionId,
+    required BigInt timestampMs,
+  });
+
+  Future<String> crateFormatInstructionText({
+    required String kind,
+    String? streetName,
+    int? exitNumber,
+    double? distanceM,
+    required String locale,
+    required String units,
+  });
+
   Future<List<GeocodingResultDto>> crateGeocodeSearch({
     required String query,
     int? limit,
@@ -185,6 +204,8 @@ abstract class RustBridgeApi extends BaseApi {
 
   Future<void> cratePauseNavigation({required String sessionId});
 
+  void cratePollDeviceTransfers();
+
   Uint8List cratePrepareMapRegionMetadataMessage({
     required String regionJson,
     required int totalTiles,
@@ -204,6 +225,13 @@ abstract class RustBridgeApi extends BaseApi {
 
   Uint8List crateReassembleFrames({required List<Uint8List> frameBytes});
 
+  void crateReceiveDeviceBytes({
+    required String deviceId,
+    required List<int> bytes,
+  });
+
+  Future<RouteDto?> crateRequestRejoinConnector({required String sessionId});
+
   Future<void> crateResumeNavigation({required String sessionId});
 
   Future<String> crateReverseGeocode({
@@ -211,6 +239,8 @@ abstract class RustBridgeApi extends BaseApi {
     required double longitude,
   });
 
+  String crateRotateDeviceSigningKey({required PlatformInt64 id});
+
   PlatformInt64 crateSaveDevice({required String deviceJson});
 
   PlatformInt64 crateSavePlace({
@@ -252,12 +282,24 @@ abstract class RustBridgeApi extends BaseApi {
     required String routeJson,
   });
 
+  Future<void> crateSendRouteToRemoteDevice({
+    required String remoteId,
+    required String routeJson,
+  });
+
   Future<void> crateSetNavdspConfig({
     required String baseUrl,
     String? token,
     required bool geocodingEnabled,
   });
 
+  Future<void> crateSetRideLimits({
+    required String sessionId,
+    double? vehicleRangeM,
+    double? rangeLeftM,
+    double? breakAfterS,
+  });
+
   Future<void> crateSetRoutingEngine({required String engine});
 
   Future<NavigationSessionDto> crateStartNavigationSession({
@@ -265,13 +307,44 @@ abstract class RustBridgeApi extends BaseApi {
     required (double, double) currentPosition,
   });
 
+  Future<NavigationSessionDto> crateStartNavigationSessionWithProfile({
+    required List<(double, double)> waypoints,
+    required (double, double) currentPosition,
+    required String profile,
+  });
+
+  Future<void> crateStartNavigationSimulation({
+    required String sessionId,
+    required String configJson,
+    required double timeScale,
+  });
+
   Future<void> crateStopNavigation({required String sessionId});
 
+  Future<bool> crateStopNavigationSimulation({required String sessionId});
+
+  Stream<DeviceMessageDto> crateSubscribeDeviceMessages();
+
+  Future<void> crateTrackAlternativeRoutes({
+    required String sessionId,
+    required List<String> routesJson,
+  });
+
   void crateUpdateDevice({
     required PlatformInt64 id,
     required String deviceJson,
   });
 
+  Future<NavigationStateDto> crateUpdateNavigationFix({
+    required String sessionId,
+    required double latitude,
+    required double longitude,
+    double? accuracyM,
+    double? bearingDeg,
+    double? speedMps,
+    BigInt? timestampMs,
+  });
+
   Future<NavigationStateDto> crateUpdateNavigationPosition({
     required String sessionId,
     required double latitude,
@@ -351,6 +424,36 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["messageBytes", "routeId", "mtu"],
   );
 
+  @override
+  void crateConfirmDeviceSigningKey({
+    required PlatformInt64 id,
+    required int keyId,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          sse_encode_u_32(keyId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateConfirmDeviceSigningKeyConstMeta,
+        argValues: [id, keyId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateConfirmDeviceSigningKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "confirm_device_signing_key",
+        argNames: ["id", "keyId"],
+      );
+
   @override
   Uint8List crateCreateControlMessage({
     required String routeId,
@@ -366,7 +469,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_String(commandType, serializer);
           sse_encode_u_32(statusCode, serializer);
           sse_encode_String(message, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -391,7 +494,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -414,7 +517,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -437,7 +540,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -460,7 +563,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -483,7 +586,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -506,7 +609,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(remoteId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -548,7 +651,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_i_32(minZoom, serializer);
           sse_encode_i_32(maxZoom, serializer);
           sse_encode_opt_String(tileUrlTemplate, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -584,6 +687,83 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     ],
   );
 
+  @override
+  Future<NavigationStateDto?> crateEstimateNavigationPosition({
+    required String sessionId,
+    required BigInt timestampMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_u_64(timestampMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_navigation_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateEstimateNavigationPositionConstMeta,
+        argValues: [sessionId, timestampMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateEstimateNavigationPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "estimate_navigation_position",
+        argNames: ["sessionId", "timestampMs"],
+      );
+
+  @override
+  Future<String> crateFormatInstructionText({
+    required String kind,
+    String? streetName,
+    int? exitNumber,
+    double? distanceM,
+    required String locale,
+    required String units,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(kind, serializer);
+          sse_encode_opt_String(streetName, serializer);
+          sse_encode_opt_box_autoadd_u_32(exitNumber, serializer);
+          sse_encode_opt_box_autoadd_f_64(distanceM, serializer);
+          sse_encode_String(locale, serializer);
+          sse_encode_String(units, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateFormatInstructionTextConstMeta,
+        argValues: [kind, streetName, exitNumber, distanceM, locale, units],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateFormatInstructionTextConstMeta => const TaskConstMeta(
+    debugName: "format_instruction_text",
+    argNames: ["kind", "streetName", "exitNumber", "distanceM", "locale", "units"],
+  );
+
   @override
   Future<List<GeocodingResultDto>> crateGeocodeSearch({
     required String query,
@@ -598,7 +778,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 14,
             port: port_,
           );
         },
@@ -627,7 +807,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 15,
             port: port_,
           );
         },
@@ -651,7 +831,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -673,7 +853,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -695,7 +875,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -717,7 +897,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -739,7 +919,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -762,7 +942,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -785,7 +965,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(remoteId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -815,7 +995,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 23,
             port: port_,
           );
         },
@@ -842,7 +1022,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -875,7 +1055,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_f_64(south, serializer);
           sse_encode_f_64(east, serializer);
           sse_encode_f_64(west, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -909,7 +1089,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_i_32(z, serializer);
           sse_encode_i_32(x, serializer);
           sse_encode_i_32(y, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -935,7 +1115,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(regionId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -960,7 +1140,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -991,7 +1171,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 29,
             port: port_,
           );
         },
@@ -1018,7 +1198,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1041,7 +1221,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1066,7 +1246,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 32,
             port: port_,
           );
         },
@@ -1091,7 +1271,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1114,7 +1294,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1146,7 +1326,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 35,
             port: port_,
           );
         },
@@ -1173,7 +1353,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_prim_u_8_loose(bytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1201,7 +1381,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 37,
             port: port_,
           );
         },
@@ -1221,6 +1401,28 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["sessionId"],
   );
 
+  @override
+  void cratePollDeviceTransfers() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCratePollDeviceTransfersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCratePollDeviceTransfersConstMeta =>
+      const TaskConstMeta(debugName: "poll_device_transfers", argNames: []);
+
   @override
   Uint8List cratePrepareMapRegionMetadataMessage({
     required String regionJson,
@@ -1232,7 +1434,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(regionJson, serializer);
           sse_encode_u_32(totalTiles, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1258,7 +1460,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(mapSourceId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1284,7 +1486,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(routeJson, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1319,7 +1521,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_i_32(x, serializer);
           sse_encode_i_32(y, serializer);
           sse_encode_list_prim_u_8_loose(data, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1345,7 +1547,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_list_list_prim_u_8_strict(frameBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_prim_u_8_strict,
@@ -1363,6 +1565,66 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["frameBytes"],
   );
 
+  @override
+  void crateReceiveDeviceBytes({
+    required String deviceId,
+    required List<int> bytes,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(deviceId, serializer);
+          sse_encode_list_prim_u_8_loose(bytes, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateReceiveDeviceBytesConstMeta,
+        argValues: [deviceId, bytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateReceiveDeviceBytesConstMeta => const TaskConstMeta(
+    debugName: "receive_device_bytes",
+    argNames: ["deviceId", "bytes"],
+  );
+
+  @override
+  Future<RouteDto?> crateRequestRejoinConnector({required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_route_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateRequestRejoinConnectorConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateRequestRejoinConnectorConstMeta =>
+      const TaskConstMeta(
+        debugName: "request_rejoin_connector",
+        argNames: ["sessionId"],
+      );
+
   @override
   Future<void> crateResumeNavigation({required String sessionId}) {
     return handler.executeNormal(
@@ -1373,7 +1635,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 40,
+            funcId: 46,
             port: port_,
           );
         },
@@ -1407,7 +1669,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 41,
+            funcId: 47,
             port: port_,
           );
         },
@@ -1427,6 +1689,32 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["latitude", "longitude"],
   );
 
+  @override
+  String crateRotateDeviceSigningKey({required PlatformInt64 id}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateRotateDeviceSigningKeyConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateRotateDeviceSigningKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "rotate_device_signing_key",
+        argNames: ["id"],
+      );
+
   @override
   PlatformInt64 crateSaveDevice({required String deviceJson}) {
     return handler.executeSync(
@@ -1434,7 +1722,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(deviceJson, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
@@ -1471,7 +1759,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_opt_String(source, serializer);
           sse_encode_opt_box_autoadd_i_64(typeId, serializer);
           sse_encode_opt_String(remoteId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
@@ -1500,7 +1788,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(routeJson, serializer);
           sse_encode_String(source, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -1535,7 +1823,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_opt_String(polylineEncoded, serializer);
           sse_encode_opt_box_autoadd_f_64(distanceM, serializer);
           sse_encode_opt_box_autoadd_u_64(durationS, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
@@ -1582,7 +1870,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           sse_encode_opt_String(destinationLabel, serializer);
           sse_encode_opt_String(routeId, serializer);
           sse_encode_opt_String(polylineEncoded, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_i_64,
@@ -1632,7 +1920,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 47,
+            funcId: 54,
             port: port_,
           );
         },
@@ -1653,10 +1941,45 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
   );
 
   @override
-  Future<void> crateSetNavdspConfig({
-    required String baseUrl,
-    String? token,
-    required bool geocodingEnabled,
+  Future<void> crateSendRouteToRemoteDevice({
+    required String remoteId,
+    required String routeJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(remoteId, serializer);
+          sse_encode_String(routeJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateSendRouteToRemoteDeviceConstMeta,
+        argValues: [remoteId, routeJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateSendRouteToRemoteDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_route_to_remote_device",
+        argNames: ["remoteId", "routeJson"],
+      );
+
+  @override
+  Future<void> crateSetNavdspConfig({
+    required String baseUrl,
+    String? token,
+    required bool geocodingEnabled,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -1668,7 +1991,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 48,
+            funcId: 56,
             port: port_,
           );
         },
@@ -1688,6 +2011,44 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["baseUrl", "token", "geocodingEnabled"],
   );
 
+  @override
+  Future<void> crateSetRideLimits({
+    required String sessionId,
+    double? vehicleRangeM,
+    double? rangeLeftM,
+    double? breakAfterS,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_opt_box_autoadd_f_64(vehicleRangeM, serializer);
+          sse_encode_opt_box_autoadd_f_64(rangeLeftM, serializer);
+          sse_encode_opt_box_autoadd_f_64(breakAfterS, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateSetRideLimitsConstMeta,
+        argValues: [sessionId, vehicleRangeM, rangeLeftM, breakAfterS],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateSetRideLimitsConstMeta => const TaskConstMeta(
+    debugName: "set_ride_limits",
+    argNames: ["sessionId", "vehicleRangeM", "rangeLeftM", "breakAfterS"],
+  );
+
   @override
   Future<void> crateSetRoutingEngine({required String engine}) {
     return handler.executeNormal(
@@ -1698,7 +2059,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 49,
+            funcId: 58,
             port: port_,
           );
         },
@@ -1732,7 +2093,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 50,
+            funcId: 59,
             port: port_,
           );
         },
@@ -1753,6 +2114,80 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         argNames: ["waypoints", "currentPosition"],
       );
 
+  @override
+  Future<NavigationSessionDto> crateStartNavigationSessionWithProfile({
+    required List<(double, double)> waypoints,
+    required (double, double) currentPosition,
+    required String profile,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_record_f_64_f_64(waypoints, serializer);
+          sse_encode_box_autoadd_record_f_64_f_64(currentPosition, serializer);
+          sse_encode_String(profile, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_navigation_session_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateStartNavigationSessionWithProfileConstMeta,
+        argValues: [waypoints, currentPosition, profile],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateStartNavigationSessionWithProfileConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_navigation_session_with_profile",
+        argNames: ["waypoints", "currentPosition", "profile"],
+      );
+
+  @override
+  Future<void> crateStartNavigationSimulation({
+    required String sessionId,
+    required String configJson,
+    required double timeScale,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_String(configJson, serializer);
+          sse_encode_f_64(timeScale, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateStartNavigationSimulationConstMeta,
+        argValues: [sessionId, configJson, timeScale],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateStartNavigationSimulationConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_navigation_simulation",
+        argNames: ["sessionId", "configJson", "timeScale"],
+      );
+
   @override
   Future<void> crateStopNavigation({required String sessionId}) {
     return handler.executeNormal(
@@ -1763,7 +2198,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 51,
+            funcId: 62,
             port: port_,
           );
         },
@@ -1783,6 +2218,107 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["sessionId"],
   );
 
+  @override
+  Future<bool> crateStopNavigationSimulation({required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateStopNavigationSimulationConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateStopNavigationSimulationConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_navigation_simulation",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Stream<DeviceMessageDto> crateSubscribeDeviceMessages() {
+    final sink = RustStreamSink<DeviceMessageDto>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_device_message_dto_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 64,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateSubscribeDeviceMessagesConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateSubscribeDeviceMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_device_messages",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<void> crateTrackAlternativeRoutes({
+    required String sessionId,
+    required List<String> routesJson,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_list_String(routesJson, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateTrackAlternativeRoutesConstMeta,
+        argValues: [sessionId, routesJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateTrackAlternativeRoutesConstMeta =>
+      const TaskConstMeta(
+        debugName: "track_alternative_routes",
+        argNames: ["sessionId", "routesJson"],
+      );
+
   @override
   void crateUpdateDevice({
     required PlatformInt64 id,
@@ -1794,7 +2330,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_i_64(id, serializer);
           sse_encode_String(deviceJson, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -1812,6 +2348,50 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     argNames: ["id", "deviceJson"],
   );
 
+  @override
+  Future<NavigationStateDto> crateUpdateNavigationFix({
+    required String sessionId,
+    required double latitude,
+    required double longitude,
+    double? accuracyM,
+    double? bearingDeg,
+    double? speedMps,
+    BigInt? timestampMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_f_64(latitude, serializer);
+          sse_encode_f_64(longitude, serializer);
+          sse_encode_opt_box_autoadd_f_64(accuracyM, serializer);
+          sse_encode_opt_box_autoadd_f_64(bearingDeg, serializer);
+          sse_encode_opt_box_autoadd_f_64(speedMps, serializer);
+          sse_encode_opt_box_autoadd_u_64(timestampMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_navigation_state_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateUpdateNavigationFixConstMeta,
+        argValues: [sessionId, latitude, longitude, accuracyM, bearingDeg, speedMps, timestampMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateUpdateNavigationFixConstMeta => const TaskConstMeta(
+    debugName: "update_navigation_fix",
+    argNames: ["sessionId", "latitude", "longitude", "accuracyM", "bearingDeg", "speedMps", "timestampMs"],
+  );
+
   @override
   Future<NavigationStateDto> crateUpdateNavigationPosition({
     required String sessionId,
@@ -1828,7 +2408,7 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 53,
+            funcId: 68,
             port: port_,
           );
         },
@@ -1855,12 +2435,36 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return AnyhowException(raw as String);
   }
 
+  @protected
+  RustStreamSink<DeviceMessageDto> dco_decode_StreamSink_device_message_dto_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as String;
   }
 
+  @protected
+  AnnouncementDto dco_decode_announcement_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AnnouncementDto(
+      stage: dco_decode_String(arr[0]),
+      step: dco_decode_u_32(arr[1]),
+      kind: dco_decode_String(arr[2]),
+      streetName: dco_decode_opt_String(arr[3]),
+      distanceM: dco_decode_f_64(arr[4]),
+      thenKind: dco_decode_opt_String(arr[5]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1899,6 +2503,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return raw as (double, double);
   }
 
+  @protected
+  RejoinDto dco_decode_box_autoadd_rejoin_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rejoin_dto(raw);
+  }
+
+  @protected
+  RouteDto dco_decode_box_autoadd_route_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_route_dto(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1911,6 +2527,12 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return dco_decode_u_64(raw);
   }
 
+  @protected
+  WrongWayDto dco_decode_box_autoadd_wrong_way_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_wrong_way_dto(raw);
+  }
+
   @protected
   DerivedInstructionDto dco_decode_derived_instruction_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1924,6 +2546,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     );
   }
 
+  @protected
+  DeviceMessageDto dco_decode_device_message_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DeviceMessageDto(
+      deviceId: dco_decode_String(arr[0]),
+      bytes: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
   @protected
   double dco_decode_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1960,12 +2594,31 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  LegEtaDto dco_decode_leg_eta_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LegEtaDto(
+      index: dco_decode_u_32(arr[0]),
+      distanceM: dco_decode_f_64(arr[1]),
+      etaSeconds: dco_decode_u_64(arr[2]),
+    );
+  }
+
   @protected
   List<String> dco_decode_list_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<AnnouncementDto> dco_decode_list_announcement_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_announcement_dto).toList();
+  }
+
   @protected
   List<DerivedInstructionDto> dco_decode_list_derived_instruction_dto(
     dynamic raw,
@@ -1982,6 +2635,12 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (raw as List<dynamic>).map(dco_decode_geocoding_result_dto).toList();
   }
 
+  @protected
+  List<LegEtaDto> dco_decode_list_leg_eta_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_leg_eta_dto).toList();
+  }
+
   @protected
   List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2012,6 +2671,12 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (raw as List<dynamic>).map(dco_decode_waypoint_dto).toList();
   }
 
+  @protected
+  List<WaypointEtaDto> dco_decode_list_waypoint_eta_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_waypoint_eta_dto).toList();
+  }
+
   @protected
   NavigationSessionDto dco_decode_navigation_session_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2031,8 +2696,8 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
   NavigationStateDto dco_decode_navigation_state_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    if (arr.length != 35)
+      throw Exception('unexpected arr length: expect 35 but see ${arr.length}');
     return NavigationStateDto(
       currentStep: dco_decode_u_32(arr[0]),
       currentInstruction: dco_decode_derived_instruction_dto(arr[1]),
@@ -2042,11 +2707,35 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       distanceToNextM: dco_decode_f_64(arr[3]),
       distanceRemainingM: dco_decode_f_64(arr[4]),
       etaSeconds: dco_decode_u_64(arr[5]),
-      isOffRoute: dco_decode_bool(arr[6]),
-      distanceFromRouteM: dco_decode_f_64(arr[7]),
-      snappedLat: dco_decode_f_64(arr[8]),
-      snappedLon: dco_decode_f_64(arr[9]),
-      constraintAlerts: dco_decode_list_String(arr[10]),
+      etaLowSeconds: dco_decode_u_64(arr[6]),
+      etaHighSeconds: dco_decode_u_64(arr[7]),
+      isOffRoute: dco_decode_bool(arr[8]),
+      distanceFromRouteM: dco_decode_f_64(arr[9]),
+      snappedLat: dco_decode_f_64(arr[10]),
+      snappedLon: dco_decode_f_64(arr[11]),
+      constraintAlerts: dco_decode_list_String(arr[12]),
+      raisedAlerts: dco_decode_list_String(arr[13]),
+      speedLimitKmh: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      activeSegment: dco_decode_u_32(arr[15]),
+      segmentIntent: dco_decode_opt_String(arr[16]),
+      nextWaypointIndex: dco_decode_opt_box_autoadd_u_32(arr[17]),
+      nextWaypointDistanceM: dco_decode_opt_box_autoadd_f_64(arr[18]),
+      nextWaypointEtaSeconds: dco_decode_opt_box_autoadd_u_64(arr[19]),
+      waypointsPassed: dco_decode_u_32(arr[20]),
+      waypointEtas: dco_decode_list_waypoint_eta_dto(arr[21]),
+      legEtas: dco_decode_list_leg_eta_dto(arr[22]),
+      arrived: dco_decode_bool(arr[23]),
+      announcements: dco_decode_list_announcement_dto(arr[24]),
+      estimated: dco_decode_bool(arr[25]),
+      rejoin: dco_decode_opt_box_autoadd_rejoin_dto(arr[26]),
+      wrongWay: dco_decode_opt_box_autoadd_wrong_way_dto(arr[27]),
+      rangeLeftM: dco_decode_opt_box_autoadd_f_64(arr[28]),
+      lowRange: dco_decode_bool(arr[29]),
+      nextFuelIndex: dco_decode_opt_box_autoadd_u_32(arr[30]),
+      nextFuelDistanceM: dco_decode_opt_box_autoadd_f_64(arr[31]),
+      breakDueRidingS: dco_decode_opt_box_autoadd_u_64(arr[32]),
+      nextBreakIndex: dco_decode_opt_box_autoadd_u_32(arr[33]),
+      nextBreakDistanceM: dco_decode_opt_box_autoadd_f_64(arr[34]),
     );
   }
 
@@ -2088,6 +2777,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
         : dco_decode_box_autoadd_navigation_state_dto(raw);
   }
 
+  @protected
+  RejoinDto? dco_decode_opt_box_autoadd_rejoin_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rejoin_dto(raw);
+  }
+
+  @protected
+  RouteDto? dco_decode_opt_box_autoadd_route_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_route_dto(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2100,6 +2801,12 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  WrongWayDto? dco_decode_opt_box_autoadd_wrong_way_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_wrong_way_dto(raw);
+  }
+
   @protected
   (double, double) dco_decode_record_f_64_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2110,6 +2817,21 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (dco_decode_f_64(arr[0]), dco_decode_f_64(arr[1]));
   }
 
+  @protected
+  RejoinDto dco_decode_rejoin_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RejoinDto(
+      lat: dco_decode_f_64(arr[0]),
+      lon: dco_decode_f_64(arr[1]),
+      distanceM: dco_decode_f_64(arr[2]),
+      bearingDeg: dco_decode_f_64(arr[3]),
+      direction: dco_decode_opt_String(arr[4]),
+    );
+  }
+
   @protected
   RouteDto dco_decode_route_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2157,21 +2879,46 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  WaypointDto dco_decode_waypoint_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WaypointDto(
+      latitude: dco_decode_f_64(arr[0]),
+      longitude: dco_decode_f_64(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  WaypointEtaDto dco_decode_waypoint_eta_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WaypointEtaDto(
+      index: dco_decode_u_32(arr[0]),
+      distanceM: dco_decode_f_64(arr[1]),
+      etaSeconds: dco_decode_u_64(arr[2]),
+    );
   }
 
   @protected
-  WaypointDto dco_decode_waypoint_dto(dynamic raw) {
+  WrongWayDto dco_decode_wrong_way_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return WaypointDto(
-      latitude: dco_decode_f_64(arr[0]),
-      longitude: dco_decode_f_64(arr[1]),
-      name: dco_decode_opt_String(arr[2]),
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WrongWayDto(
+      instruction: dco_decode_derived_instruction_dto(arr[0]),
+      distanceM: dco_decode_f_64(arr[1]),
     );
   }
 
@@ -2182,6 +2929,14 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return AnyhowException(inner);
   }
 
+  @protected
+  RustStreamSink<DeviceMessageDto> sse_decode_StreamSink_device_message_dto_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2189,6 +2944,25 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return utf8.decoder.convert(inner);
   }
 
+  @protected
+  AnnouncementDto sse_decode_announcement_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_String(deserializer);
+    var var_step = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_streetName = sse_decode_opt_String(deserializer);
+    var var_distanceM = sse_decode_f_64(deserializer);
+    var var_thenKind = sse_decode_opt_String(deserializer);
+    return AnnouncementDto(
+      stage: var_stage,
+      step: var_step,
+      kind: var_kind,
+      streetName: var_streetName,
+      distanceM: var_distanceM,
+      thenKind: var_thenKind,
+    );
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2231,6 +3005,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (sse_decode_record_f_64_f_64(deserializer));
   }
 
+  @protected
+  RejoinDto sse_decode_box_autoadd_rejoin_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rejoin_dto(deserializer));
+  }
+
+  @protected
+  RouteDto sse_decode_box_autoadd_route_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_route_dto(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2243,6 +3029,14 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (sse_decode_u_64(deserializer));
   }
 
+  @protected
+  WrongWayDto sse_decode_box_autoadd_wrong_way_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_wrong_way_dto(deserializer));
+  }
+
   @protected
   DerivedInstructionDto sse_decode_derived_instruction_dto(
     SseDeserializer deserializer,
@@ -2258,6 +3052,14 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     );
   }
 
+  @protected
+  DeviceMessageDto sse_decode_device_message_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deviceId = sse_decode_String(deserializer);
+    var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+    return DeviceMessageDto(deviceId: var_deviceId, bytes: var_bytes);
+  }
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2301,6 +3103,19 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  LegEtaDto sse_decode_leg_eta_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_distanceM = sse_decode_f_64(deserializer);
+    var var_etaSeconds = sse_decode_u_64(deserializer);
+    return LegEtaDto(
+      index: var_index,
+      distanceM: var_distanceM,
+      etaSeconds: var_etaSeconds,
+    );
+  }
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2313,6 +3128,20 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<AnnouncementDto> sse_decode_list_announcement_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AnnouncementDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_announcement_dto(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<DerivedInstructionDto> sse_decode_list_derived_instruction_dto(
     SseDeserializer deserializer,
@@ -2341,6 +3170,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<LegEtaDto> sse_decode_list_leg_eta_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LegEtaDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_leg_eta_dto(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
     SseDeserializer deserializer,
@@ -2395,6 +3236,20 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return ans_;
   }
 
+  @protected
+  List<WaypointEtaDto> sse_decode_list_waypoint_eta_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WaypointEtaDto>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_waypoint_eta_dto(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   NavigationSessionDto sse_decode_navigation_session_dto(
     SseDeserializer deserializer,
@@ -2428,11 +3283,39 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     var var_distanceToNextM = sse_decode_f_64(deserializer);
     var var_distanceRemainingM = sse_decode_f_64(deserializer);
     var var_etaSeconds = sse_decode_u_64(deserializer);
+    var var_etaLowSeconds = sse_decode_u_64(deserializer);
+    var var_etaHighSeconds = sse_decode_u_64(deserializer);
     var var_isOffRoute = sse_decode_bool(deserializer);
     var var_distanceFromRouteM = sse_decode_f_64(deserializer);
     var var_snappedLat = sse_decode_f_64(deserializer);
     var var_snappedLon = sse_decode_f_64(deserializer);
     var var_constraintAlerts = sse_decode_list_String(deserializer);
+    var var_raisedAlerts = sse_decode_list_String(deserializer);
+    var var_speedLimitKmh = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_activeSegment = sse_decode_u_32(deserializer);
+    var var_segmentIntent = sse_decode_opt_String(deserializer);
+    var var_nextWaypointIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nextWaypointDistanceM = sse_decode_opt_box_autoadd_f_64(
+      deserializer,
+    );
+    var var_nextWaypointEtaSeconds = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
+    var var_waypointsPassed = sse_decode_u_32(deserializer);
+    var var_waypointEtas = sse_decode_list_waypoint_eta_dto(deserializer);
+    var var_legEtas = sse_decode_list_leg_eta_dto(deserializer);
+    var var_arrived = sse_decode_bool(deserializer);
+    var var_announcements = sse_decode_list_announcement_dto(deserializer);
+    var var_estimated = sse_decode_bool(deserializer);
+    var var_rejoin = sse_decode_opt_box_autoadd_rejoin_dto(deserializer);
+    var var_wrongWay = sse_decode_opt_box_autoadd_wrong_way_dto(deserializer);
+    var var_rangeLeftM = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_lowRange = sse_decode_bool(deserializer);
+    var var_nextFuelIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nextFuelDistanceM = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_breakDueRidingS = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_nextBreakIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nextBreakDistanceM = sse_decode_opt_box_autoadd_f_64(deserializer);
     return NavigationStateDto(
       currentStep: var_currentStep,
       currentInstruction: var_currentInstruction,
@@ -2440,11 +3323,35 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
       distanceToNextM: var_distanceToNextM,
       distanceRemainingM: var_distanceRemainingM,
       etaSeconds: var_etaSeconds,
+      etaLowSeconds: var_etaLowSeconds,
+      etaHighSeconds: var_etaHighSeconds,
       isOffRoute: var_isOffRoute,
       distanceFromRouteM: var_distanceFromRouteM,
       snappedLat: var_snappedLat,
       snappedLon: var_snappedLon,
       constraintAlerts: var_constraintAlerts,
+      raisedAlerts: var_raisedAlerts,
+      speedLimitKmh: var_speedLimitKmh,
+      activeSegment: var_activeSegment,
+      segmentIntent: var_segmentIntent,
+      nextWaypointIndex: var_nextWaypointIndex,
+      nextWaypointDistanceM: var_nextWaypointDistanceM,
+      nextWaypointEtaSeconds: var_nextWaypointEtaSeconds,
+      waypointsPassed: var_waypointsPassed,
+      waypointEtas: var_waypointEtas,
+      legEtas: var_legEtas,
+      arrived: var_arrived,
+      announcements: var_announcements,
+      estimated: var_estimated,
+      rejoin: var_rejoin,
+      wrongWay: var_wrongWay,
+      rangeLeftM: var_rangeLeftM,
+      lowRange: var_lowRange,
+      nextFuelIndex: var_nextFuelIndex,
+      nextFuelDistanceM: var_nextFuelDistanceM,
+      breakDueRidingS: var_breakDueRidingS,
+      nextBreakIndex: var_nextBreakIndex,
+      nextBreakDistanceM: var_nextBreakDistanceM,
     );
   }
 
@@ -2507,6 +3414,30 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  RejoinDto? sse_decode_opt_box_autoadd_rejoin_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rejoin_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RouteDto? sse_decode_opt_box_autoadd_route_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_route_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2529,6 +3460,19 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  WrongWayDto? sse_decode_opt_box_autoadd_wrong_way_dto(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_wrong_way_dto(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   (double, double) sse_decode_record_f_64_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2537,6 +3481,23 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     return (var_field0, var_field1);
   }
 
+  @protected
+  RejoinDto sse_decode_rejoin_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lat = sse_decode_f_64(deserializer);
+    var var_lon = sse_decode_f_64(deserializer);
+    var var_distanceM = sse_decode_f_64(deserializer);
+    var var_bearingDeg = sse_decode_f_64(deserializer);
+    var var_direction = sse_decode_opt_String(deserializer);
+    return RejoinDto(
+      lat: var_lat,
+      lon: var_lon,
+      distanceM: var_distanceM,
+      bearingDeg: var_bearingDeg,
+      direction: var_direction,
+    );
+  }
+
   @protected
   RouteDto sse_decode_route_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2603,6 +3564,27 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     );
   }
 
+  @protected
+  WaypointEtaDto sse_decode_waypoint_eta_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_distanceM = sse_decode_f_64(deserializer);
+    var var_etaSeconds = sse_decode_u_64(deserializer);
+    return WaypointEtaDto(
+      index: var_index,
+      distanceM: var_distanceM,
+      etaSeconds: var_etaSeconds,
+    );
+  }
+
+  @protected
+  WrongWayDto sse_decode_wrong_way_dto(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_instruction = sse_decode_derived_instruction_dto(deserializer);
+    var var_distanceM = sse_decode_f_64(deserializer);
+    return WrongWayDto(instruction: var_instruction, distanceM: var_distanceM);
+  }
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
@@ -2612,12 +3594,43 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_device_message_dto_Sse(
+    RustStreamSink<DeviceMessageDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_device_message_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
+  @protected
+  void sse_encode_announcement_dto(
+    AnnouncementDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.stage, serializer);
+    sse_encode_u_32(self.step, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_opt_String(self.streetName, serializer);
+    sse_encode_f_64(self.distanceM, serializer);
+    sse_encode_opt_String(self.thenKind, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2666,6 +3679,24 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_record_f_64_f_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_rejoin_dto(
+    RejoinDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rejoin_dto(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_route_dto(
+    RouteDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_route_dto(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2678,6 +3709,15 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_u_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_wrong_way_dto(
+    WrongWayDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_wrong_way_dto(self, serializer);
+  }
+
   @protected
   void sse_encode_derived_instruction_dto(
     DerivedInstructionDto self,
@@ -2689,6 +3729,16 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_opt_String(self.streetName, serializer);
   }
 
+  @protected
+  void sse_encode_device_message_dto(
+    DeviceMessageDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_list_prim_u_8_strict(self.bytes, serializer);
+  }
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2723,6 +3773,14 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_leg_eta_dto(LegEtaDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_f_64(self.distanceM, serializer);
+    sse_encode_u_64(self.etaSeconds, serializer);
+  }
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2732,6 +3790,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_announcement_dto(
+    List<AnnouncementDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_announcement_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_derived_instruction_dto(
     List<DerivedInstructionDto> self,
@@ -2756,6 +3826,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_leg_eta_dto(
+    List<LegEtaDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_leg_eta_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
     List<Uint8List> self,
@@ -2814,6 +3896,18 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_list_waypoint_eta_dto(
+    List<WaypointEtaDto> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_waypoint_eta_dto(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_navigation_session_dto(
     NavigationSessionDto self,
@@ -2842,11 +3936,35 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_f_64(self.distanceToNextM, serializer);
     sse_encode_f_64(self.distanceRemainingM, serializer);
     sse_encode_u_64(self.etaSeconds, serializer);
+    sse_encode_u_64(self.etaLowSeconds, serializer);
+    sse_encode_u_64(self.etaHighSeconds, serializer);
     sse_encode_bool(self.isOffRoute, serializer);
     sse_encode_f_64(self.distanceFromRouteM, serializer);
     sse_encode_f_64(self.snappedLat, serializer);
     sse_encode_f_64(self.snappedLon, serializer);
     sse_encode_list_String(self.constraintAlerts, serializer);
+    sse_encode_list_String(self.raisedAlerts, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.speedLimitKmh, serializer);
+    sse_encode_u_32(self.activeSegment, serializer);
+    sse_encode_opt_String(self.segmentIntent, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.nextWaypointIndex, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.nextWaypointDistanceM, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.nextWaypointEtaSeconds, serializer);
+    sse_encode_u_32(self.waypointsPassed, serializer);
+    sse_encode_list_waypoint_eta_dto(self.waypointEtas, serializer);
+    sse_encode_list_leg_eta_dto(self.legEtas, serializer);
+    sse_encode_bool(self.arrived, serializer);
+    sse_encode_list_announcement_dto(self.announcements, serializer);
+    sse_encode_bool(self.estimated, serializer);
+    sse_encode_opt_box_autoadd_rejoin_dto(self.rejoin, serializer);
+    sse_encode_opt_box_autoadd_wrong_way_dto(self.wrongWay, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.rangeLeftM, serializer);
+    sse_encode_bool(self.lowRange, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.nextFuelIndex, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.nextFuelDistanceM, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.breakDueRidingS, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.nextBreakIndex, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.nextBreakDistanceM, serializer);
   }
 
   @protected
@@ -2908,6 +4026,32 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_rejoin_dto(
+    RejoinDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_rejoin_dto(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_route_dto(
+    RouteDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_route_dto(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2928,6 +4072,19 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_wrong_way_dto(
+    WrongWayDto? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_wrong_way_dto(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_record_f_64_f_64(
     (double, double) self,
@@ -2938,6 +4095,16 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_f_64(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_rejoin_dto(RejoinDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.lat, serializer);
+    sse_encode_f_64(self.lon, serializer);
+    sse_encode_f_64(self.distanceM, serializer);
+    sse_encode_f_64(self.bearingDeg, serializer);
+    sse_encode_opt_String(self.direction, serializer);
+  }
+
   @protected
   void sse_encode_route_dto(RouteDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2989,4 +4156,22 @@ class RustBridgeApiImpl extends RustBridgeApiImplPlatform
     sse_encode_f_64(self.longitude, serializer);
     sse_encode_opt_String(self.name, serializer);
   }
+  @protected
+  void sse_encode_waypoint_eta_dto(
+    WaypointEtaDto self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_f_64(self.distanceM, serializer);
+    sse_encode_u_64(self.etaSeconds, serializer);
+  }
+
+  @protected
+  void sse_encode_wrong_way_dto(WrongWayDto self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_derived_instruction_dto(self.instruction, serializer);
+    sse_encode_f_64(self.distanceM, serializer);
+  }
+
 }
@@ -22,9 +22,17 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<DeviceMessageDto> dco_decode_StreamSink_device_message_dto_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AnnouncementDto dco_decode_announcement_dto(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -45,15 +53,27 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   (double, double) dco_decode_box_autoadd_record_f_64_f_64(dynamic raw);
 
+  @protected
+  RejoinDto dco_decode_box_autoadd_rejoin_dto(dynamic raw);
+
+  @protected
+  RouteDto dco_decode_box_autoadd_route_dto(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  WrongWayDto dco_decode_box_autoadd_wrong_way_dto(dynamic raw);
+
   @protected
   DerivedInstructionDto dco_decode_derived_instruction_dto(dynamic raw);
 
+  @protected
+  DeviceMessageDto dco_decode_device_message_dto(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
@@ -66,9 +86,15 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  LegEtaDto dco_decode_leg_eta_dto(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AnnouncementDto> dco_decode_list_announcement_dto(dynamic raw);
+
   @protected
   List<DerivedInstructionDto> dco_decode_list_derived_instruction_dto(
     dynamic raw,
@@ -77,6 +103,9 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   List<GeocodingResultDto> dco_decode_list_geocoding_result_dto(dynamic raw);
 
+  @protected
+  List<LegEtaDto> dco_decode_list_leg_eta_dto(dynamic raw);
+
   @protected
   List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
 
@@ -92,6 +121,9 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   List<WaypointDto> dco_decode_list_waypoint_dto(dynamic raw);
 
+  @protected
+  List<WaypointEtaDto> dco_decode_list_waypoint_eta_dto(dynamic raw);
+
   @protected
   NavigationSessionDto dco_decode_navigation_session_dto(dynamic raw);
 
@@ -117,15 +149,27 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     dynamic raw,
   );
 
+  @protected
+  RejoinDto? dco_decode_opt_box_autoadd_rejoin_dto(dynamic raw);
+
+  @protected
+  RouteDto? dco_decode_opt_box_autoadd_route_dto(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  WrongWayDto? dco_decode_opt_box_autoadd_wrong_way_dto(dynamic raw);
+
   @protected
   (double, double) dco_decode_record_f_64_f_64(dynamic raw);
 
+  @protected
+  RejoinDto dco_decode_rejoin_dto(dynamic raw);
+
   @protected
   RouteDto dco_decode_route_dto(dynamic raw);
 
@@ -147,12 +191,26 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   WaypointDto dco_decode_waypoint_dto(dynamic raw);
 
+  @protected
+  WaypointEtaDto dco_decode_waypoint_eta_dto(dynamic raw);
+
+  @protected
+  WrongWayDto dco_decode_wrong_way_dto(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<DeviceMessageDto> sse_decode_StreamSink_device_message_dto_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AnnouncementDto sse_decode_announcement_dto(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -177,17 +235,31 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RejoinDto sse_decode_box_autoadd_rejoin_dto(SseDeserializer deserializer);
+
+  @protected
+  RouteDto sse_decode_box_autoadd_route_dto(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  WrongWayDto sse_decode_box_autoadd_wrong_way_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   DerivedInstructionDto sse_decode_derived_instruction_dto(
     SseDeserializer deserializer,
   );
 
+  @protected
+  DeviceMessageDto sse_decode_device_message_dto(SseDeserializer deserializer);
+
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
@@ -202,9 +274,17 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  LegEtaDto sse_decode_leg_eta_dto(SseDeserializer deserializer);
+
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AnnouncementDto> sse_decode_list_announcement_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   List<DerivedInstructionDto> sse_decode_list_derived_instruction_dto(
     SseDeserializer deserializer,
@@ -215,6 +295,9 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<LegEtaDto> sse_decode_list_leg_eta_dto(SseDeserializer deserializer);
+
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
     SseDeserializer deserializer,
@@ -234,6 +317,11 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   List<WaypointDto> sse_decode_list_waypoint_dto(SseDeserializer deserializer);
 
+  @protected
+  List<WaypointEtaDto> sse_decode_list_waypoint_eta_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   NavigationSessionDto sse_decode_navigation_session_dto(
     SseDeserializer deserializer,
@@ -263,15 +351,31 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RejoinDto? sse_decode_opt_box_autoadd_rejoin_dto(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RouteDto? sse_decode_opt_box_autoadd_route_dto(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  WrongWayDto? sse_decode_opt_box_autoadd_wrong_way_dto(
+    SseDeserializer deserializer,
+  );
+
   @protected
   (double, double) sse_decode_record_f_64_f_64(SseDeserializer deserializer);
 
+  @protected
+  RejoinDto sse_decode_rejoin_dto(SseDeserializer deserializer);
+
   @protected
   RouteDto sse_decode_route_dto(SseDeserializer deserializer);
 
@@ -293,15 +397,33 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   WaypointDto sse_decode_waypoint_dto(SseDeserializer deserializer);
 
+  @protected
+  WaypointEtaDto sse_decode_waypoint_eta_dto(SseDeserializer deserializer);
+
+  @protected
+  WrongWayDto sse_decode_wrong_way_dto(SseDeserializer deserializer);
+
   @protected
   void sse_encode_AnyhowException(
     AnyhowException self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_device_message_dto_Sse(
+    RustStreamSink<DeviceMessageDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_announcement_dto(
+    AnnouncementDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -332,18 +454,42 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_rejoin_dto(
+    RejoinDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_route_dto(
+    RouteDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_wrong_way_dto(
+    WrongWayDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_derived_instruction_dto(
     DerivedInstructionDto self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_device_message_dto(
+    DeviceMessageDto self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
@@ -359,9 +505,18 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_leg_eta_dto(LegEtaDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_announcement_dto(
+    List<AnnouncementDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_derived_instruction_dto(
     List<DerivedInstructionDto> self,
@@ -374,6 +529,12 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_leg_eta_dto(
+    List<LegEtaDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_list_prim_u_8_strict(
     List<Uint8List> self,
@@ -401,6 +562,12 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_waypoint_eta_dto(
+    List<WaypointEtaDto> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_navigation_session_dto(
     NavigationSessionDto self,
@@ -437,18 +604,39 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_rejoin_dto(
+    RejoinDto? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_route_dto(
+    RouteDto? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_wrong_way_dto(
+    WrongWayDto? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_record_f_64_f_64(
     (double, double) self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_rejoin_dto(RejoinDto self, SseSerializer serializer);
+
   @protected
   void sse_encode_route_dto(RouteDto self, SseSerializer serializer);
 
@@ -472,6 +660,15 @@ abstract class RustBridgeApiImplPlatform extends BaseApiImpl<RustBridgeWire> {
 
   @protected
   void sse_encode_waypoint_dto(WaypointDto self, SseSerializer serializer);
+  @protected
+  void sse_encode_waypoint_eta_dto(
+    WaypointEtaDto self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_wrong_way_dto(WrongWayDto self, SseSerializer serializer);
+
 }
 
 // Section: wire_class
@@ -53,6 +53,17 @@ Future<NavigationSessionDto> startNavigationSession({
   currentPosition: currentPosition,
 );
 
+/// Start a new navigation session for a vehicle profile ("car", "motorcycle", "bicycle", "foot")
+Future<NavigationSessionDto> startNavigationSessionWithProfile({
+  required List<(double, double)> waypoints,
+  required (double, double) currentPosition,
+  required String profile,
+}) => RustBridge.instance.api.crateStartNavigationSessionWithProfile(
+  waypoints: waypoints,
+  currentPosition: currentPosition,
+  profile: profile,
+);
+
 /// Update current position during navigation. Returns `NavigationStateDto`.
 Future<NavigationStateDto> updateNavigationPosition({
   required String sessionId,
@@ -64,6 +75,62 @@ Future<NavigationStateDto> updateNavigationPosition({
   longitude: longitude,
 );
 
+/// Update position with a full GPS fix (accuracy, bearing, speed, timestamp in Unix ms).
+Future<NavigationStateDto> updateNavigationFix({
+  required String sessionId,
+  required double latitude,
+  required double longitude,
+  double? accuracyM,
+  double? bearingDeg,
+  double? speedMps,
+  BigInt? timestampMs,
+}) => RustBridge.instance.api.crateUpdateNavigationFix(
+  sessionId: sessionId,
+  latitude: latitude,
+  longitude: longitude,
+  accuracyM: accuracyM,
+  bearingDeg: bearingDeg,
+  speedMps: speedMps,
+  timestampMs: timestampMs,
+);
+
+/// Dead-reckoned navigation state while no GPS fix arrives (tunnels).
+/// Returns null when there is no recent fix to extrapolate from.
+Future<NavigationStateDto?> estimateNavigationPosition({
+  required String sessionId,
+  required BigInt timestampMs,
+}) => RustBridge.instance.api.crateEstimateNavigationPosition(
+  sessionId: sessionId,
+  timestampMs: timestampMs,
+);
+
+/// Connector route back to the rejoin point while off a fixed-geometry track.
+/// Returns null when the session is not in rejoin mode.
+Future<RouteDto?> requestRejoinConnector({required String sessionId}) =>
+    RustBridge.instance.api.crateRequestRejoinConnector(sessionId: sessionId);
+
+/// Offer alternative routes (Nav-IR JSON each); the session follows whichever one is driven.
+Future<void> trackAlternativeRoutes({
+  required String sessionId,
+  required List<String> routesJson,
+}) => RustBridge.instance.api.crateTrackAlternativeRoutes(
+  sessionId: sessionId,
+  routesJson: routesJson,
+);
+
+/// Set the vehicle range and break interval of a session; range and break events follow.
+Future<void> setRideLimits({
+  required String sessionId,
+  double? vehicleRangeM,
+  double? rangeLeftM,
+  double? breakAfterS,
+}) => RustBridge.instance.api.crateSetRideLimits(
+  sessionId: sessionId,
+  vehicleRangeM: vehicleRangeM,
+  rangeLeftM: rangeLeftM,
+  breakAfterS: breakAfterS,
+);
+
 /// Get the latest navigation state for an active session without moving.
 /// Returns `NavigationStateDto` or null if session not found.
 Future<NavigationStateDto?> getNavigationState({required String sessionId}) =>
@@ -81,6 +148,23 @@ Future<void> pauseNavigation({required String sessionId}) =>
 Future<void> resumeNavigation({required String sessionId}) =>
     RustBridge.instance.api.crateResumeNavigation(sessionId: sessionId);
 
+/// Drive a session with simulated GPS fixes (demo / testing without riding).
+/// `config_json` is a simulator config (speed profile, noise, dropouts, detours); "" for defaults.
+/// Returns immediately; navigation events flow as for real GPS.
+Future<void> startNavigationSimulation({
+  required String sessionId,
+  required String configJson,
+  required double timeScale,
+}) => RustBridge.instance.api.crateStartNavigationSimulation(
+  sessionId: sessionId,
+  configJson: configJson,
+  timeScale: timeScale,
+);
+
+/// Stop a running simulation. Returns false if none was running.
+Future<bool> stopNavigationSimulation({required String sessionId}) =>
+    RustBridge.instance.api.crateStopNavigationSimulation(sessionId: sessionId);
+
 /// Stop and complete navigation session
 Future<void> stopNavigation({required String sessionId}) =>
     RustBridge.instance.api.crateStopNavigation(sessionId: sessionId);
@@ -94,6 +178,23 @@ Future<List<DerivedInstructionDto>> getRouteSteps({
   required String sessionId,
 }) => RustBridge.instance.api.crateGetRouteSteps(sessionId: sessionId);
 
+/// Localized instruction text ("en", "nl", "de", "fr"; "metric" or "imperial")
+Future<String> formatInstructionText({
+  required String kind,
+  String? streetName,
+  int? exitNumber,
+  double? distanceM,
+  required String locale,
+  required String units,
+}) => RustBridge.instance.api.crateFormatInstructionText(
+  kind: kind,
+  streetName: streetName,
+  exitNumber: exitNumber,
+  distanceM: distanceM,
+  locale: locale,
+  units: units,
+);
+
 /// Search for locations by address/name
 Future<List<GeocodingResultDto>> geocodeSearch({
   required String query,
@@ -214,9 +315,7 @@ void deleteSavedRoute({required PlatformInt64 id}) =>
 
 /// Send route data to a connected device via Bluetooth.
 ///
-/// Serialises the route to protobuf via `nav_protocol` and emits the bytes on the
-/// device message channel. Flutter must be subscribed to `subscribe_device_messages()`
-/// to receive and write them over BLE.
+/// Looks up the device's remote ID and sends the route like `send_route_to_remote_device`.
 Future<void> sendRouteToDevice({
   required PlatformInt64 deviceId,
   required String routeJson,
@@ -225,6 +324,37 @@ Future<void> sendRouteToDevice({
   routeJson: routeJson,
 );
 
+/// Send route data to the device with BLE remote ID `remote_id`.
+///
+/// The route is encoded for the device's capabilities, signed with its key and emitted as
+/// frames on the device message channel (through a reliable transfer if the device ACKs).
+/// Flutter must be listening to `subscribe_device_messages()` to write them over BLE.
+Future<void> sendRouteToRemoteDevice({
+  required String remoteId,
+  required String routeJson,
+}) => RustBridge.instance.api.crateSendRouteToRemoteDevice(
+  remoteId: remoteId,
+  routeJson: routeJson,
+);
+
+/// Stream every message the device adapter sends to devices (route frames, resent frames,
+/// position updates); Flutter writes each one to the BLE peripheral `device_id`.
+Stream<DeviceMessageDto> subscribeDeviceMessages() =>
+    RustBridge.instance.api.crateSubscribeDeviceMessages();
+
+/// Pass bytes received from a device over BLE (ACK/NACK for route transfers)
+void receiveDeviceBytes({
+  required String deviceId,
+  required List<int> bytes,
+}) => RustBridge.instance.api.crateReceiveDeviceBytes(
+  deviceId: deviceId,
+  bytes: bytes,
+);
+
+/// Resend route frames whose ACK timed out; call periodically during a transfer
+void pollDeviceTransfers() =>
+    RustBridge.instance.api.cratePollDeviceTransfers();
+
 /// Get all devices as JSON array
 String getAllDevices() => RustBridge.instance.api.crateGetAllDevices();
 
@@ -252,6 +382,20 @@ void deleteDevice({required PlatformInt64 id}) =>
 bool deviceExistsByRemoteId({required String remoteId}) =>
     RustBridge.instance.api.crateDeviceExistsByRemoteId(remoteId: remoteId);
 
+/// Give a device a new route signing key; returns {"key_id", "key" (hex)} for provisioning
+String rotateDeviceSigningKey({required PlatformInt64 id}) =>
+    RustBridge.instance.api.crateRotateDeviceSigningKey(id: id);
+
+/// Sign a device's routes with its new key once the device has stored it.
+/// Devices that report the key in their capabilities handshake are confirmed automatically.
+void confirmDeviceSigningKey({
+  required PlatformInt64 id,
+  required int keyId,
+}) => RustBridge.instance.api.crateConfirmDeviceSigningKey(
+  id: id,
+  keyId: keyId,
+);
+
 /// Prepare a route message for sending to a device
 /// Takes route JSON and returns serialized protobuf message bytes
 Uint8List prepareRouteMessage({required String routeJson}) =>
@@ -1,5 +1,8 @@
+import 'dart:async';
 import 'dart:convert';
 import 'dart:typed_data';
+import 'package:flutter/foundation.dart';
+import 'package:nav_e/bridge/ffi_models.dart' show DeviceMessageDto;
 import 'package:nav_e/bridge/lib.dart' as api;
 import 'package:nav_e/core/device_comm/device_comm_transport.dart';
 import 'package:nav_e/core/device_comm/proto/navigation.pb.dart' as proto;
@@ -7,10 +10,30 @@ import 'package:uuid/uuid.dart';
 
 /// Service for communicating with external devices via a transport (BLE or Wear).
 /// Handles message serialization, chunking, and delegates sending to the transport.
+///
+/// Routes are sent by the native device adapter: every message it emits is written to
+/// its device here. Route frame ACKs/NACKs and capability handshakes from devices are
+/// passed on to it, and a timer lets it resend frames whose ACK timed out.
 class DeviceCommunicationService {
-  DeviceCommunicationService(this._transport);
+  DeviceCommunicationService(this._transport) {
+    _nativeSubscription = _transport.messageStream.listen(_forwardToNative);
+    _outgoingSubscription = api
+        .subscribeDeviceMessages()
+        .asyncMap(_writeToDevice)
+        .listen(null);
+    _transferPollTimer = Timer.periodic(
+      _transferPollInterval,
+      (_) => api.pollDeviceTransfers(),
+    );
+  }
+
+  /// Well under the native ACK timeout (1.5 s), so resends aren't late by much.
+  static const Duration _transferPollInterval = Duration(milliseconds: 250);
 
   final DeviceCommTransport _transport;
+  late final StreamSubscription<DeviceMessage> _nativeSubscription;
+  late final StreamSubscription<void> _outgoingSubscription;
+  late final Timer _transferPollTimer;
 
   /// Stream of incoming messages from devices
   Stream<DeviceMessage> get messageStream => _transport.messageStream;
@@ -19,22 +42,10 @@ class DeviceCommunicationService {
   Future<List<ConnectedDeviceInfo>> getConnectedDeviceIds() =>
       _transport.getConnectedDeviceIds();
 
-  /// Send a route to a connected device
-  Future<void> sendRoute({
-    required String remoteId,
-    required String routeJson,
-    void Function(double progress)? onProgress,
-  }) async {
-    final messageBytes = api.prepareRouteMessage(routeJson: routeJson);
-    final mtu = await _transport.getMtu(remoteId);
-    final routeId = _generateRouteId();
-    final frames = api.chunkMessageForBle(
-      messageBytes: messageBytes,
-      routeId: routeId,
-      mtu: mtu,
-    );
-    await _transport.sendFrames(remoteId, frames, onProgress: onProgress);
-  }
+  /// Send a route to a connected device. The native device adapter encodes it for
+  /// the device, signs and chunks it; the frames go out through [_writeToDevice].
+  Future<void> sendRoute({required String remoteId, required String routeJson}) =>
+      api.sendRouteToRemoteDevice(remoteId: remoteId, routeJson: routeJson);
 
   /// Send a map region (metadata + tile chunks) to a connected device.
   Future<void> sendMapRegion({
@@ -133,7 +144,44 @@ class DeviceCommunicationService {
     return const Uuid().v4();
   }
 
+  /// Write a message from the native device adapter to its device. Messages are
+  /// written one at a time, so a route's frames keep their order.
+  Future<void> _writeToDevice(DeviceMessageDto message) async {
+    try {
+      await _transport.sendFrames(message.deviceId, [message.bytes]);
+    } catch (e) {
+      debugPrint(
+        '[DeviceCommunicationService] Write to ${message.deviceId} failed: $e',
+      );
+    }
+  }
+
+  /// Hand ACK/NACK controls and capability handshakes to the native adapter; it
+  /// ignores controls for devices without a transfer in progress.
+  void _forwardToNative(DeviceMessage message) {
+    switch (message.message.whichPayload()) {
+      case proto.Message_Payload.control:
+      case proto.Message_Payload.deviceCapabilities:
+        try {
+          api.receiveDeviceBytes(
+            deviceId: message.deviceId,
+            bytes: message.message.writeToBuffer(),
+          );
+        } catch (e) {
+          debugPrint(
+            '[DeviceCommunicationService] Passing ${message.deviceId} bytes '
+            'to native failed: $e',
+          );
+        }
+      default:
+        break;
+    }
+  }
+
   void dispose() {
+    _transferPollTimer.cancel();
+    _nativeSubscription.cancel();
+    _outgoingSubscription.cancel();
     _transport.dispose();
   }
 }
@@ -1736,6 +1736,11 @@ class DeviceCapabilities extends $pb.GeneratedMessage {
     $core.int? screenHeightPx,
     $core.int? batteryLevelPct,
     $core.bool? lowPowerMode,
+    $core.bool? supportsCompression,
+    $core.bool? prefersRawPoints,
+    $core.int? maxRoutePoints,
+    $core.bool? supportsAcks,
+    $core.int? signingKeyId,
   }) {
     final result = create();
     if (header != null) result.header = header;
@@ -1747,6 +1752,12 @@ class DeviceCapabilities extends $pb.GeneratedMessage {
     if (screenHeightPx != null) result.screenHeightPx = screenHeightPx;
     if (batteryLevelPct != null) result.batteryLevelPct = batteryLevelPct;
     if (lowPowerMode != null) result.lowPowerMode = lowPowerMode;
+    if (supportsCompression != null)
+      result.supportsCompression = supportsCompression;
+    if (prefersRawPoints != null) result.prefersRawPoints = prefersRawPoints;
+    if (maxRoutePoints != null) result.maxRoutePoints = maxRoutePoints;
+    if (supportsAcks != null) result.supportsAcks = supportsAcks;
+    if (signingKeyId != null) result.signingKeyId = signingKeyId;
     return result;
   }
 
@@ -1772,6 +1783,13 @@ class DeviceCapabilities extends $pb.GeneratedMessage {
     ..aI(7, _omitFieldNames ? '' : 'screenHeightPx')
     ..aI(8, _omitFieldNames ? '' : 'batteryLevelPct')
     ..aOB(9, _omitFieldNames ? '' : 'lowPowerMode')
+    ..aOB(10, _omitFieldNames ? '' : 'supportsCompression')
+    ..aOB(11, _omitFieldNames ? '' : 'prefersRawPoints')
+    ..aI(12, _omitFieldNames ? '' : 'maxRoutePoints',
+        fieldType: $pb.PbFieldType.OU3)
+    ..aOB(13, _omitFieldNames ? '' : 'supportsAcks')
+    ..aI(14, _omitFieldNames ? '' : 'signingKeyId',
+        fieldType: $pb.PbFieldType.OU3)
     ..hasRequiredFields = false;
 
   @$core.Deprecated('See https://github.com/google/protobuf.dart/issues/998.')
@@ -1875,6 +1893,51 @@ class DeviceCapabilities extends $pb.GeneratedMessage {
   $core.bool hasLowPowerMode() => $_has(8);
   @$pb.TagNumber(9)
   void clearLowPowerMode() => $_clearField(9);
+
+  @$pb.TagNumber(10)
+  $core.bool get supportsCompression => $_getBF(9);
+  @$pb.TagNumber(10)
+  set supportsCompression($core.bool value) => $_setBool(9, value);
+  @$pb.TagNumber(10)
+  $core.bool hasSupportsCompression() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearSupportsCompression() => $_clearField(10);
+
+  @$pb.TagNumber(11)
+  $core.bool get prefersRawPoints => $_getBF(10);
+  @$pb.TagNumber(11)
+  set prefersRawPoints($core.bool value) => $_setBool(10, value);
+  @$pb.TagNumber(11)
+  $core.bool hasPrefersRawPoints() => $_has(10);
+  @$pb.TagNumber(11)
+  void clearPrefersRawPoints() => $_clearField(11);
+
+  @$pb.TagNumber(12)
+  $core.int get maxRoutePoints => $_getIZ(11);
+  @$pb.TagNumber(12)
+  set maxRoutePoints($core.int value) => $_setUnsignedInt32(11, value);
+  @$pb.TagNumber(12)
+  $core.bool hasMaxRoutePoints() => $_has(11);
+  @$pb.TagNumber(12)
+  void clearMaxRoutePoints() => $_clearField(12);
+
+  @$pb.TagNumber(13)
+  $core.bool get supportsAcks => $_getBF(12);
+  @$pb.TagNumber(13)
+  set supportsAcks($core.bool value) => $_setBool(12, value);
+  @$pb.TagNumber(13)
+  $core.bool hasSupportsAcks() => $_has(12);
+  @$pb.TagNumber(13)
+  void clearSupportsAcks() => $_clearField(13);
+
+  @$pb.TagNumber(14)
+  $core.int get signingKeyId => $_getIZ(13);
+  @$pb.TagNumber(14)
+  set signingKeyId($core.int value) => $_setUnsignedInt32(13, value);
+  @$pb.TagNumber(14)
+  $core.bool hasSigningKeyId() => $_has(13);
+  @$pb.TagNumber(14)
+  void clearSigningKeyId() => $_clearField(14);
 }
 
 /// Battery status for power management
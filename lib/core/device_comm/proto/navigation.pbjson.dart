@@ -559,18 +559,45 @@ const DeviceCapabilities$json = {
     {'1': 'screen_height_px', '3': 7, '4': 1, '5': 5, '10': 'screenHeightPx'},
     {'1': 'battery_level_pct', '3': 8, '4': 1, '5': 5, '10': 'batteryLevelPct'},
     {'1': 'low_power_mode', '3': 9, '4': 1, '5': 8, '10': 'lowPowerMode'},
+    {
+      '1': 'supports_compression',
+      '3': 10,
+      '4': 1,
+      '5': 8,
+      '10': 'supportsCompression'
+    },
+    {
+      '1': 'prefers_raw_points',
+      '3': 11,
+      '4': 1,
+      '5': 8,
+      '10': 'prefersRawPoints'
+    },
+    {
+      '1': 'max_route_points',
+      '3': 12,
+      '4': 1,
+      '5': 13,
+      '10': 'maxRoutePoints'
+    },
+    {'1': 'supports_acks', '3': 13, '4': 1, '5': 8, '10': 'supportsAcks'},
+    {'1': 'signing_key_id', '3': 14, '4': 1, '5': 13, '10': 'signingKeyId'},
   ],
 };
 
 /// Descriptor for `DeviceCapabilities`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List deviceCapabilitiesDescriptor = $convert.base64Decode(
-    'ChJEZXZpY2VDYXBhYmlsaXRpZXMSKgoGaGVhZGVyGAEgASgLMhIubmF2aWdhdGlvbi5IZWFkZX'
-    'JSBmhlYWRlchIbCglkZXZpY2VfaWQYAiABKAlSCGRldmljZUlkEikKEGZpcm13YXJlX3ZlcnNp'
-    'b24YAyABKAlSD2Zpcm13YXJlVmVyc2lvbhItChJzdXBwb3J0c192aWJyYXRpb24YBCABKAhSEX'
-    'N1cHBvcnRzVmlicmF0aW9uEiUKDnN1cHBvcnRzX3ZvaWNlGAUgASgIUg1zdXBwb3J0c1ZvaWNl'
-    'EiYKD3NjcmVlbl93aWR0aF9weBgGIAEoBVINc2NyZWVuV2lkdGhQeBIoChBzY3JlZW5faGVpZ2'
-    'h0X3B4GAcgASgFUg5zY3JlZW5IZWlnaHRQeBIqChFiYXR0ZXJ5X2xldmVsX3BjdBgIIAEoBVIP'
-    'YmF0dGVyeUxldmVsUGN0EiQKDmxvd19wb3dlcl9tb2RlGAkgASgIUgxsb3dQb3dlck1vZGU=');
+    'ChJEZXZpY2VDYXBhYmlsaXRpZXMSKgoGaGVhZGVyGAEgASgLMhIubmF2aWdhdGlvbi5IZWFkZXJS'
+    'BmhlYWRlchIbCglkZXZpY2VfaWQYAiABKAlSCGRldmljZUlkEikKEGZpcm13YXJlX3ZlcnNpb24Y'
+    'AyABKAlSD2Zpcm13YXJlVmVyc2lvbhItChJzdXBwb3J0c192aWJyYXRpb24YBCABKAhSEXN1cHBv'
+    'cnRzVmlicmF0aW9uEiUKDnN1cHBvcnRzX3ZvaWNlGAUgASgIUg1zdXBwb3J0c1ZvaWNlEiYKD3Nj'
+    'cmVlbl93aWR0aF9weBgGIAEoBVINc2NyZWVuV2lkdGhQeBIoChBzY3JlZW5faGVpZ2h0X3B4GAcg'
+    'ASgFUg5zY3JlZW5IZWlnaHRQeBIqChFiYXR0ZXJ5X2xldmVsX3BjdBgIIAEoBVIPYmF0dGVyeUxl'
+    'dmVsUGN0EiQKDmxvd19wb3dlcl9tb2RlGAkgASgIUgxsb3dQb3dlck1vZGUSMQoUc3VwcG9ydHNf'
+    'Y29tcHJlc3Npb24YCiABKAhSE3N1cHBvcnRzQ29tcHJlc3Npb24SLAoScHJlZmVyc19yYXdfcG9p'
+    'bnRzGAsgASgIUhBwcmVmZXJzUmF3UG9pbnRzEigKEG1heF9yb3V0ZV9wb2ludHMYDCABKA1SDm1h'
+    'eFJvdXRlUG9pbnRzEiMKDXN1cHBvcnRzX2Fja3MYDSABKAhSDHN1cHBvcnRzQWNrcxIkCg5zaWdu'
+    'aW5nX2tleV9pZBgOIAEoDVIMc2lnbmluZ0tleUlk');
 
 @$core.Deprecated('Use batteryStatusDescriptor instead')
 const BatteryStatus$json = {
@@ -50,9 +50,6 @@ class DeviceCommBloc extends Bloc<DeviceCommEvent, DeviceCommState> {
       await _deviceCommService.sendRoute(
         remoteId: event.remoteId,
         routeJson: event.routeJson,
-        onProgress: (progress) {
-          emit(DeviceCommSending(remoteId: event.remoteId, progress: progress));
-        },
       );
 
       emit(DeviceCommSuccess(remoteId: event.remoteId));
@@ -358,9 +358,9 @@ class _TopTurnBar extends StatelessWidget {
         final textTheme = Theme.of(context).textTheme;
         final onPrimaryFaded = colorScheme.onPrimary.withValues(alpha: 0.75);
 
-        // Extract speed limit from constraint alerts if present.
+        // Show the speed limit while the engine reports speeding.
         final speedLimitAlert = state.constraintAlerts
-            .where((a) => a.startsWith('speed_limit:'))
+            .where((a) => a.startsWith('speeding:'))
             .firstOrNull;
         final speedLimitKmh = speedLimitAlert != null
             ? int.tryParse(speedLimitAlert.split(':').last)
@@ -4,17 +4,15 @@
 /// serialises domain objects to protobuf bytes and broadcasts them on a channel; Flutter
 /// subscribes to that channel and performs the actual BLE write via `flutter_blue_plus`.
 use anyhow::{Context, Result};
+use tokio::sync::broadcast;
 
 use crate::app::container::get_container;
 
 /// Send route data to a connected device via Bluetooth.
 ///
-/// Resolves the device by ID (database), prepares the protobuf route message via
-/// `device_comm`, and emits the bytes on the device adapter's outgoing channel.
-/// Flutter must be subscribed to `subscribe_device_messages()` to perform the BLE write.
+/// Resolves the device by ID (database) and sends the route to its `remote_id` with
+/// `send_route_to_remote_device`.
 pub fn send_route_to_device(device_id: i64, route_json: String) -> Result<()> {
-    let container = get_container();
-
     let device_str = super::devices::get_device_by_id(device_id)
         .context("Failed to load device from database")?;
     let device: serde_json::Value =
@@ -25,14 +23,31 @@ pub fn send_route_to_device(device_id: i64, route_json: String) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Device missing remote_id (MAC address)"))?
         .to_string();
 
-    // Serialize the route to protobuf bytes using device_comm.
-    let message_bytes = device_comm::prepare_route_message(route_json)
-        .context("Failed to prepare route message")?;
+    send_route_to_remote_device(remote_id, route_json)
+}
 
-    // Emit on the BLE adapter channel — Flutter receives and writes over BLE.
-    container.send_device_bytes(remote_id, message_bytes);
+/// Send route data to the device with BLE ID `remote_id`, saved or not.
+///
+/// The device adapter encodes the route as the device's capabilities ask (RawPoints,
+/// compression), signs it with the device's key (if it has one) and emits its frames: all at
+/// once, or through a reliable transfer for devices that ACK. Flutter must be subscribed to
+/// `subscribe_device_messages()` to perform the BLE writes.
+pub fn send_route_to_remote_device(remote_id: String, route_json: String) -> Result<()> {
+    get_container().send_route_json(remote_id, &route_json)
+}
 
-    Ok(())
+/// Pass bytes received from a device over BLE to the device adapter.
+///
+/// ACK/NACK controls for a route transfer in progress move it along; other messages are
+/// ignored.
+pub fn receive_device_bytes(device_id: String, bytes: Vec<u8>) -> Result<()> {
+    get_container().receive_device_bytes(&device_id, &bytes)
+}
+
+/// Resend frames whose ACK timed out. Call every few hundred milliseconds while a route
+/// transfer is in progress; transfers that run out of retries are reported as failed.
+pub fn poll_device_transfers() {
+    get_container().poll_device_transfers();
 }
 
 // Re-export device_comm message preparation APIs so FFI uses nav_core::api::*
@@ -43,5 +58,22 @@ pub use device_comm::{
 };
 
 // Re-export subscribe_device_messages and DeviceMessage so the API surface stays at nav_core::api::*
-pub use crate::app::container::subscribe_device_messages;
-pub use crate::devices::infrastructure::ble_adapter::DeviceMessage;
+pub use crate::app::container::{subscribe_device_messages, subscribe_device_transfers};
+pub use crate::devices::infrastructure::ble_adapter::{DeviceMessage, TransferReport};
+
+/// Call `deliver` with every message sent to devices from now on, on a background thread,
+/// until it returns false. Messages it falls too far behind on are skipped.
+pub fn forward_device_messages(mut deliver: impl FnMut(DeviceMessage) -> bool + Send + 'static) {
+    let mut rx = subscribe_device_messages();
+    std::thread::spawn(move || loop {
+        match rx.blocking_recv() {
+            Ok(message) => {
+                if !deliver(message) {
+                    break;
+                }
+            }
+            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => break,
+        }
+    });
+}
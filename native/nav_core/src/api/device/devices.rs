@@ -43,11 +43,9 @@ pub fn update_device(id: i64, device_json: String) -> Result<()> {
         .update_device(UpdateDeviceCommand { id, device_json })
 }
 
-/// Delete a device by ID
+/// Delete a device by ID; routes are no longer signed for it
 pub fn delete_device(id: i64) -> Result<()> {
-    get_container()
-        .devices
-        .delete_device(DeleteDeviceCommand { id })
+    get_container().delete_device(id)
 }
 
 /// Check if a device exists by remote ID
@@ -56,3 +54,20 @@ pub fn device_exists_by_remote_id(remote_id: String) -> Result<bool> {
         .devices
         .device_exists_by_remote_id(DeviceExistsByRemoteIdQuery { remote_id })
 }
+
+/// Give a device a new RouteBlob signing key and return it as JSON
+/// (`{"key_id": 2, "key": "<64 hex chars>"}`) for provisioning to the device.
+///
+/// Routes sent to the device stay signed with the previous key until the device has stored
+/// the new one and `confirm_device_signing_key` is called with its `key_id`.
+pub fn rotate_device_signing_key(id: i64) -> Result<String> {
+    let key = get_container().rotate_device_signing_key(id)?;
+    let hex: String = key.secret.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok(serde_json::json!({ "key_id": key.key_id, "key": hex }).to_string())
+}
+
+/// The device has stored signing key `key_id` (from `rotate_device_signing_key`); sign its
+/// routes with that key from now on. Fails if `key_id` isn't the device's pending key.
+pub fn confirm_device_signing_key(id: i64, key_id: u32) -> Result<()> {
+    get_container().confirm_device_signing_key(id, key_id)
+}
@@ -18,23 +18,122 @@ pub struct NavigationStateDto {
     pub distance_to_next_m: f64,
     pub distance_remaining_m: f64,
     pub eta_seconds: u64,
+    /// Likely range of `eta_seconds` (80%).
+    pub eta_low_seconds: u64,
+    pub eta_high_seconds: u64,
     pub is_off_route: bool,
     pub distance_from_route_m: f64,
     pub snapped_lat: f64,
     pub snapped_lon: f64,
+    /// Constraint alerts in force: "speeding:<max_kmh>", "avoid_highway", "avoid_toll",
+    /// "avoid_unpaved".
     pub constraint_alerts: Vec<String>,
+    /// Alerts raised by this fix (play a chime once), same format.
+    pub raised_alerts: Vec<String>,
+    /// Speed limit at the current position, if the route knows it.
+    pub speed_limit_kmh: Option<u32>,
+    /// Index of the route segment being ridden.
+    pub active_segment: u32,
+    /// Intent of the active segment: "fixed_geometry", "recalculatable", "advisory_track".
+    pub segment_intent: Option<String>,
+    /// Next Via / Stop / Fuel / Break waypoint (index across all route segments).
+    pub next_waypoint_index: Option<u32>,
+    pub next_waypoint_distance_m: Option<f64>,
+    pub next_waypoint_eta_seconds: Option<u64>,
+    pub waypoints_passed: u32,
+    /// Every waypoint still ahead, in route order.
+    pub waypoint_etas: Vec<WaypointEtaDto>,
+    /// Every route leg not yet finished, in route order.
+    pub leg_etas: Vec<LegEtaDto>,
+    /// Final destination reached; the session has been completed.
+    pub arrived: bool,
+    /// Voice / haptic prompts to play for this fix (usually empty).
+    pub announcements: Vec<AnnouncementDto>,
+    /// Position was dead-reckoned along the route (signal gap or inaccurate fix).
+    pub estimated: bool,
+    /// Way back onto a `FixedGeometry` track while off it.
+    pub rejoin: Option<RejoinDto>,
+    /// Riding against the route direction: show the "u_turn" instruction instead.
+    pub wrong_way: Option<WrongWayDto>,
+    /// Fuel range left (meters), when the session has a vehicle range.
+    pub range_left_m: Option<f64>,
+    /// The next Fuel waypoint (or the destination, with none left) is out of range.
+    pub low_range: bool,
+    /// Next Fuel waypoint ahead (index across all route segments) and its distance.
+    pub next_fuel_index: Option<u32>,
+    pub next_fuel_distance_m: Option<f64>,
+    /// Riding time since the last break (seconds), set while a break is due.
+    pub break_due_riding_s: Option<u64>,
+    /// Next Break waypoint ahead while a break is due, and its distance.
+    pub next_break_index: Option<u32>,
+    pub next_break_distance_m: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WrongWayDto {
+    /// Kind "u_turn".
+    pub instruction: DerivedInstructionDto,
+    /// Along-route distance lost since turning the wrong way (meters).
+    pub distance_m: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaypointEtaDto {
+    /// Waypoint index across all route segments.
+    pub index: u32,
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegEtaDto {
+    /// Leg index across all route segments.
+    pub index: u32,
+    /// Distance to the end of the leg (meters).
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejoinDto {
+    pub lat: f64,
+    pub lon: f64,
+    /// Straight-line distance to the rejoin point (meters).
+    pub distance_m: f64,
+    /// Compass bearing to the rejoin point (degrees, 0 = north).
+    pub bearing_deg: f64,
+    /// Turn towards it relative to the rider's course ("turn_left", …), if moving.
+    pub direction: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnouncementDto {
+    /// Prompt stage: "early", "prepare", "now".
+    pub stage: String,
+    /// Index of the announced instruction in the route steps.
+    pub step: u32,
+    pub kind: String,
+    pub street_name: Option<String>,
+    pub distance_m: f64,
+    /// Kind of a maneuver following right after this one ("…, then turn right").
+    pub then_kind: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DerivedInstructionDto {
     /// Maneuver kind: "depart", "turn_left", "turn_right", "slight_left", "slight_right",
-    /// "sharp_left", "sharp_right", "continue", "arrive".
+    /// "sharp_left", "sharp_right", "continue", "segment_transition", "arrive", "u_turn".
     pub kind: String,
     pub distance_to_next_m: f64,
     pub street_name: Option<String>,
 }
 
 pub(crate) fn navigation_state_to_dto(state: nav_engine::NavigationState) -> NavigationStateDto {
+    let next_fuel = state.range.as_ref().and_then(|r| r.next_fuel.as_ref());
+    let next_break = state
+        .break_suggestion
+        .as_ref()
+        .and_then(|b| b.next_break.as_ref());
     NavigationStateDto {
         current_step: state.current_step as u32,
         current_instruction: instruction_to_dto(state.current_instruction),
@@ -42,6 +141,8 @@ pub(crate) fn navigation_state_to_dto(state: nav_engine::NavigationState) -> Nav
         distance_to_next_m: state.distance_to_next_m,
         distance_remaining_m: state.distance_remaining_m,
         eta_seconds: state.eta_seconds,
+        eta_low_seconds: state.eta_interval.low_seconds,
+        eta_high_seconds: state.eta_interval.high_seconds,
         is_off_route: state.off_route.is_off_route,
         distance_from_route_m: state.off_route.distance_from_route_m,
         snapped_lat: state.snapped_position.latitude,
@@ -51,6 +152,72 @@ pub(crate) fn navigation_state_to_dto(state: nav_engine::NavigationState) -> Nav
             .iter()
             .map(alert_to_string)
             .collect(),
+        raised_alerts: state.raised_alerts.iter().map(alert_to_string).collect(),
+        speed_limit_kmh: state.speed_limit_kmh,
+        active_segment: state.active_segment as u32,
+        segment_intent: state.segment_intent.map(|i| intent_to_str(i).to_string()),
+        next_waypoint_index: state.next_waypoint.as_ref().map(|w| w.index as u32),
+        next_waypoint_distance_m: state.next_waypoint.as_ref().map(|w| w.distance_m),
+        next_waypoint_eta_seconds: state.next_waypoint.as_ref().map(|w| w.eta_seconds),
+        waypoints_passed: state.waypoints_passed as u32,
+        waypoint_etas: state
+            .upcoming_waypoints
+            .iter()
+            .map(|w| WaypointEtaDto {
+                index: w.index as u32,
+                distance_m: w.distance_m,
+                eta_seconds: w.eta_seconds,
+            })
+            .collect(),
+        leg_etas: state
+            .leg_etas
+            .iter()
+            .map(|l| LegEtaDto {
+                index: l.index as u32,
+                distance_m: l.distance_m,
+                eta_seconds: l.eta_seconds,
+            })
+            .collect(),
+        arrived: state.arrived,
+        announcements: state
+            .announcements
+            .into_iter()
+            .map(|a| AnnouncementDto {
+                stage: a.stage.as_str().to_string(),
+                step: a.step as u32,
+                kind: a.kind.as_str().to_string(),
+                street_name: a.street_name,
+                distance_m: a.distance_m,
+                then_kind: a.then.map(|k| k.as_str().to_string()),
+            })
+            .collect(),
+        estimated: state.estimated,
+        rejoin: state.rejoin.map(|r| RejoinDto {
+            lat: r.point.latitude,
+            lon: r.point.longitude,
+            distance_m: r.distance_m,
+            bearing_deg: r.bearing_deg,
+            direction: r.direction.map(|k| k.as_str().to_string()),
+        }),
+        wrong_way: state.wrong_way.map(|w| WrongWayDto {
+            instruction: instruction_to_dto(w.instruction),
+            distance_m: w.distance_m,
+        }),
+        range_left_m: state.range.as_ref().map(|r| r.range_left_m),
+        low_range: state.range.as_ref().is_some_and(|r| r.low),
+        next_fuel_index: next_fuel.map(|w| w.index as u32),
+        next_fuel_distance_m: next_fuel.map(|w| w.distance_m),
+        break_due_riding_s: state.break_suggestion.as_ref().map(|b| b.riding_s),
+        next_break_index: next_break.map(|w| w.index as u32),
+        next_break_distance_m: next_break.map(|w| w.distance_m),
+    }
+}
+
+fn intent_to_str(intent: nav_ir::SegmentIntent) -> &'static str {
+    match intent {
+        nav_ir::SegmentIntent::FixedGeometry => "fixed_geometry",
+        nav_ir::SegmentIntent::Recalculatable => "recalculatable",
+        nav_ir::SegmentIntent::AdvisoryTrack => "advisory_track",
     }
 }
 
@@ -64,11 +231,10 @@ pub(crate) fn instruction_to_dto(inst: nav_engine::DerivedInstruction) -> Derive
 
 fn alert_to_string(alert: &nav_engine::ConstraintAlert) -> String {
     match alert {
-        nav_engine::ConstraintAlert::SpeedLimit { max_kmh } => {
-            format!("speed_limit:{}", max_kmh)
-        }
+        nav_engine::ConstraintAlert::Speeding { max_kmh } => format!("speeding:{}", max_kmh),
         nav_engine::ConstraintAlert::AvoidHighway => "avoid_highway".to_string(),
         nav_engine::ConstraintAlert::AvoidToll => "avoid_toll".to_string(),
+        nav_engine::ConstraintAlert::AvoidUnpaved => "avoid_unpaved".to_string(),
     }
 }
 
@@ -288,6 +454,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: nav_ir::SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: nav_ir::RoutePolicies::default(),
         };
@@ -327,6 +495,14 @@ mod tests {
             updated_at: Utc::now(),
             current_step_index: 0,
             distance_traveled_m: 0.0,
+            profile: nav_engine::VehicleProfile::Car,
+            progress: None,
+            reroute_offset_m: 0.0,
+            engine_snapshot: None,
+            alternatives: vec![],
+            vehicle_range_m: None,
+            break_after_s: None,
+            next_turn: None,
         };
 
         let dto = navigation_session_to_dto(&session);
@@ -11,6 +11,19 @@ pub fn start_navigation_session(
     waypoints: Vec<(f64, f64)>,
     current_position: (f64, f64),
 ) -> Result<NavigationSessionDto> {
+    start_navigation_session_with_profile(waypoints, current_position, "car".to_string())
+}
+
+/// Start a new navigation session with a vehicle profile ("car", "motorcycle", "bicycle", "foot").
+/// The profile is stored with the session and selects the engine's threshold preset.
+pub fn start_navigation_session_with_profile(
+    waypoints: Vec<(f64, f64)>,
+    current_position: (f64, f64),
+    profile: String,
+) -> Result<NavigationSessionDto> {
+    let profile = profile
+        .parse::<nav_engine::VehicleProfile>()
+        .map_err(|e| anyhow::anyhow!(e))?;
     query_async(|| async {
         let waypoint_positions: Result<Vec<Position>> = waypoints
             .into_iter()
@@ -24,6 +37,7 @@ pub fn start_navigation_session(
             waypoints: waypoint_positions?,
             current_position: current_pos,
             device_id: None,
+            profile,
         };
 
         let session = get_container().navigation.start(command).await?;
@@ -44,6 +58,39 @@ pub fn update_navigation_position(
         let command = UpdatePositionCommand {
             session_id: session_uuid,
             position,
+            accuracy_m: None,
+            bearing_deg: None,
+            speed_mps: None,
+            timestamp_ms: None,
+        };
+
+        let nav_state = get_container().navigation.update_position(command).await?;
+        Ok(navigation_state_to_dto(nav_state))
+    })
+}
+
+/// Update the position with a full GPS fix. Timed fixes are smoothed along the route and
+/// fixes with a poor `accuracy_m` are replaced by dead reckoning (`estimated` in the state).
+pub fn update_navigation_fix(
+    session_id: String,
+    latitude: f64,
+    longitude: f64,
+    accuracy_m: Option<f64>,
+    bearing_deg: Option<f64>,
+    speed_mps: Option<f64>,
+    timestamp_ms: Option<u64>,
+) -> Result<NavigationStateDto> {
+    query_async(|| async {
+        let position = Position::new(latitude, longitude).map_err(|e| anyhow::anyhow!(e))?;
+        let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+
+        let command = UpdatePositionCommand {
+            session_id: session_uuid,
+            position,
+            accuracy_m,
+            bearing_deg,
+            speed_mps,
+            timestamp_ms,
         };
 
         let nav_state = get_container().navigation.update_position(command).await?;
@@ -51,6 +98,83 @@ pub fn update_navigation_position(
     })
 }
 
+/// Dead-reckoned navigation state at `timestamp_ms` while no GPS fix arrives (tunnels).
+/// Returns None when there has been no recent fix to extrapolate from.
+pub fn estimate_navigation_position(
+    session_id: String,
+    timestamp_ms: u64,
+) -> Result<Option<NavigationStateDto>> {
+    query_async(|| async {
+        let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+        let nav_state = get_container()
+            .navigation
+            .estimate_position(EstimatePositionCommand {
+                session_id: session_uuid,
+                timestamp_ms,
+            })
+            .await?;
+        Ok(nav_state.map(navigation_state_to_dto))
+    })
+}
+
+/// Connector route from the current position back to the rejoin point of a `FixedGeometry`
+/// track. Returns None when the session is not off such a track.
+pub fn request_rejoin_connector(session_id: String) -> Result<Option<RouteDto>> {
+    query_async(|| async {
+        let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+        let route = get_container()
+            .navigation
+            .request_rejoin_connector(RequestRejoinConnectorCommand {
+                session_id: session_uuid,
+            })
+            .await?;
+        Ok(route.as_ref().map(route_to_dto))
+    })
+}
+
+/// Offer alternative routes (Nav-IR JSON each) next to the session's current route. Fixes are
+/// matched against all of them; once the rider clearly drives one, the session switches to it
+/// and a `RouteChosen` event names it. An empty list keeps the current route.
+pub fn track_alternative_routes(session_id: String, routes_json: Vec<String>) -> Result<()> {
+    let routes = routes_json
+        .iter()
+        .map(|json| serde_json::from_str::<nav_ir::Route>(json).context("Invalid route JSON"))
+        .collect::<Result<Vec<_>>>()?;
+    command_async(|| async {
+        let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+        get_container()
+            .navigation
+            .track_alternative_routes(TrackAlternativeRoutesCommand {
+                session_id: session_uuid,
+                routes,
+            })
+            .await
+    })
+}
+
+/// Set the vehicle's range on a full tank and the riding time before a break is suggested
+/// (`None` keeps the profile default). `range_left_m` is what is left right now, when the tank
+/// isn't full. `LowRange` and `BreakSuggested` events follow during navigation.
+pub fn set_ride_limits(
+    session_id: String,
+    vehicle_range_m: Option<f64>,
+    range_left_m: Option<f64>,
+    break_after_s: Option<f64>,
+) -> Result<()> {
+    command_async(|| async {
+        let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+        get_container()
+            .navigation
+            .set_ride_limits(SetRideLimitsCommand {
+                session_id: session_uuid,
+                vehicle_range_m,
+                range_left_m,
+                break_after_s,
+            })
+            .await
+    })
+}
+
 /// Get the latest navigation state for a session without updating position.
 pub fn get_navigation_state(session_id: String) -> Result<Option<NavigationStateDto>> {
     block_on(async {
@@ -65,11 +189,7 @@ pub fn get_navigation_state(session_id: String) -> Result<Option<NavigationState
                     s.current_position.latitude,
                     s.current_position.longitude,
                 );
-                let mut engine = nav_engine::NavigationEngine::new_with_state(
-                    s.route,
-                    s.current_step_index,
-                    s.distance_traveled_m,
-                );
+                let mut engine = s.build_engine();
                 let nav_state = engine.update_position(coord, None);
                 Ok(Some(navigation_state_to_dto(nav_state)))
             }
@@ -126,11 +246,7 @@ pub fn get_route_steps(session_id: String) -> Result<Vec<DerivedInstructionDto>>
             .load_session(session_uuid)
             .await?
             .context("Session not found")?;
-        let engine = nav_engine::NavigationEngine::new_with_state(
-            session.route,
-            session.current_step_index,
-            session.distance_traveled_m,
-        );
+        let engine = session.build_engine();
         let steps: Vec<DerivedInstructionDto> = engine
             .instructions()
             .iter()
@@ -141,6 +257,37 @@ pub fn get_route_steps(session_id: String) -> Result<Vec<DerivedInstructionDto>>
     })
 }
 
+/// Build localized instruction text, e.g. "In 200 m, turn left onto Main Street".
+///
+/// `kind` is a `DerivedInstructionDto::kind` code, `locale` a language tag ("en", "nl-BE",
+/// "de", "fr") and `units` "metric" or "imperial".
+pub fn format_instruction_text(
+    kind: String,
+    street_name: Option<String>,
+    exit_number: Option<u32>,
+    distance_m: Option<f64>,
+    locale: String,
+    units: String,
+) -> Result<String> {
+    let kind = kind
+        .parse::<nav_engine::DerivedInstructionKind>()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let locale = locale
+        .parse::<nav_engine::Locale>()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let units = units
+        .parse::<nav_engine::UnitSystem>()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let mut phrase = nav_engine::Phrase::new(kind).street(street_name.as_deref());
+    if let Some(exit) = exit_number {
+        phrase = phrase.exit(exit);
+    }
+    if let Some(d) = distance_m {
+        phrase = phrase.distance(d);
+    }
+    Ok(nav_engine::InstructionText::new(locale, units).phrase(&phrase))
+}
+
 /// Get aggregated stats across all non-cancelled navigation sessions.
 pub fn get_session_stats() -> Result<SessionStatsDto> {
     block_on(async {
@@ -153,6 +300,43 @@ pub fn get_session_stats() -> Result<SessionStatsDto> {
     })
 }
 
+/// Drive a session with simulated GPS fixes along its route, in a background thread.
+///
+/// `config_json` is a `nav_engine::SimulatorConfig`; every field is optional and `""` uses the
+/// defaults, e.g. `{"speed":{"kind":"constant","speed_mps":15},"noise_m":5,
+/// "dropout_probability":0.05,"detours":[{"start_m":800,"length_m":400,"offset_m":120}]}`.
+/// `time_scale` speeds up playback (1.0 = real time). Returns immediately; the fixes produce
+/// the usual navigation events until arrival, `stop_navigation_simulation` or the session ends.
+pub fn start_navigation_simulation(
+    session_id: String,
+    config_json: String,
+    time_scale: f64,
+) -> Result<()> {
+    let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+    let simulator = if config_json.trim().is_empty() {
+        nav_engine::SimulatorConfig::default()
+    } else {
+        serde_json::from_str(&config_json).context("Invalid simulator config")?
+    };
+    let container = get_container();
+    std::thread::Builder::new()
+        .name("nav-simulation".into())
+        .spawn(move || {
+            let _ = block_on(container.navigation.simulate(SimulateSessionCommand {
+                session_id: session_uuid,
+                simulator,
+                time_scale,
+            }));
+        })?;
+    Ok(())
+}
+
+/// Stop a running simulation. Returns false if none was running for the session.
+pub fn stop_navigation_simulation(session_id: String) -> Result<bool> {
+    let session_uuid = uuid::Uuid::parse_str(&session_id)?;
+    Ok(get_container().navigation.stop_simulation(session_uuid))
+}
+
 /// Stop and complete navigation session
 pub fn stop_navigation(session_id: String) -> Result<()> {
     command_async(|| async {
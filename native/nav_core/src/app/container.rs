@@ -4,8 +4,13 @@
 //! bounded context owns its pre-constructed handlers. The `OnceLock` guarantees a
 //! single initialization for the lifetime of the mobile app process.
 
+use crate::devices::commands::{
+    ConfirmSigningKeyCommand, ConfirmStoredSigningKeyCommand, DeleteDeviceCommand,
+    RotateSigningKeyCommand,
+};
 use crate::devices::handlers::DevicesHandlers;
-use crate::devices::infrastructure::{DeviceMessage, ProtobufDeviceAdapter};
+use crate::devices::infrastructure::{DeviceMessage, ProtobufDeviceAdapter, TransferReport};
+use crate::devices::queries::{GetDeviceByIdQuery, GetSigningKeysQuery};
 use crate::infrastructure::database::{
     Database, DeviceRepository, OfflineRegionsRepository, SavedPlacesRepository,
     SavedRoutesRepository, TripsRepository,
@@ -18,6 +23,8 @@ use crate::navigation::domain::{
 use crate::navigation::infrastructure::SqliteNavigationRepository;
 use crate::offline::handlers::OfflineHandlers;
 use crate::places::handlers::PlacesHandlers;
+use anyhow::Context;
+use device_comm::SigningKey;
 use std::sync::{Arc, OnceLock};
 use tokio::sync::broadcast;
 
@@ -42,7 +49,7 @@ impl AppContainer {
         db_path: String,
         route_service: Arc<dyn RouteService>,
         geocoding_service: Arc<dyn GeocodingService>,
-    ) -> Self {
+    ) -> anyhow::Result<Self> {
         let path = std::path::PathBuf::from(&db_path);
         let db = Database::new(path.clone()).expect("Failed to initialize database");
         let db_conn = db.get_connection();
@@ -60,8 +67,17 @@ impl AppContainer {
         // Real device adapter — serialises domain messages to protobuf bytes.
         // Navigation uses it via DeviceCommunicationPort; Flutter subscribes to its channel.
         let device_adapter = Arc::new(ProtobufDeviceAdapter::new());
+        let devices = DevicesHandlers::new(DeviceRepository::new(Arc::clone(&db_conn)));
+        // Starting without the keys would send unsigned routes to devices that expect signed
+        // ones, so a failed load fails initialization.
+        let keys = devices
+            .get_signing_keys(GetSigningKeysQuery)
+            .context("Failed to load device signing keys")?;
+        for (remote_id, key) in keys {
+            device_adapter.set_signing_key(remote_id, key);
+        }
 
-        Self {
+        Ok(Self {
             navigation: NavigationHandlers::new(
                 route_service,
                 navigation_repo,
@@ -74,13 +90,13 @@ impl AppContainer {
                 TripsRepository::new(Arc::clone(&db_conn)),
                 SavedRoutesRepository::new(Arc::clone(&db_conn)),
             ),
-            devices: DevicesHandlers::new(DeviceRepository::new(Arc::clone(&db_conn))),
+            devices,
             offline: OfflineHandlers::new(OfflineRegionsRepository::new(
                 Arc::clone(&db_conn),
                 storage_base,
             )),
             device_adapter,
-        }
+        })
     }
 
     /// Subscribe to outgoing device messages serialised by `ProtobufDeviceAdapter`.
@@ -92,10 +108,73 @@ impl AppContainer {
         self.device_adapter.subscribe()
     }
 
-    /// Send pre-built protobuf bytes to a device. Used by `send_route_to_device` to
-    /// emit messages that were prepared via the `device_comm` helper functions.
-    pub(crate) fn send_device_bytes(&self, device_id: String, bytes: Vec<u8>) {
-        self.device_adapter.send_raw(device_id, bytes);
+    /// Send a route given as route JSON to `device_id`: signed with its key, compressed if
+    /// it supports that, and through a reliable transfer if it ACKs.
+    pub(crate) fn send_route_json(
+        &self,
+        device_id: String,
+        route_json: &str,
+    ) -> anyhow::Result<()> {
+        self.device_adapter.send_route_json(device_id, route_json)
+    }
+
+    /// Issue a new RouteBlob signing key for a device. Routes stay signed with its current
+    /// key until `confirm_device_signing_key`. Returns the new key for provisioning.
+    pub(crate) fn rotate_device_signing_key(&self, id: i64) -> anyhow::Result<SigningKey> {
+        self.devices
+            .rotate_signing_key(RotateSigningKeyCommand { id })
+    }
+
+    /// The device has stored signing key `key_id`: sign its routes with that key from now on.
+    pub(crate) fn confirm_device_signing_key(&self, id: i64, key_id: u32) -> anyhow::Result<()> {
+        let key = self
+            .devices
+            .confirm_signing_key(ConfirmSigningKeyCommand { id, key_id })?;
+        let device = self
+            .devices
+            .get_device_by_id(GetDeviceByIdQuery { id })?
+            .ok_or_else(|| anyhow::anyhow!("Device {} not found", id))?;
+        self.device_adapter.set_signing_key(device.remote_id, key);
+        Ok(())
+    }
+
+    /// Delete a device and stop signing routes for it.
+    pub(crate) fn delete_device(&self, id: i64) -> anyhow::Result<()> {
+        let device = self.devices.get_device_by_id(GetDeviceByIdQuery { id })?;
+        self.devices.delete_device(DeleteDeviceCommand { id })?;
+        if let Some(device) = device {
+            self.device_adapter.remove_signing_key(&device.remote_id);
+        }
+        Ok(())
+    }
+
+    /// Subscribe to the outcome (complete or failed) of reliable route transfers.
+    pub fn subscribe_device_transfers(&self) -> broadcast::Receiver<TransferReport> {
+        self.device_adapter.subscribe_transfers()
+    }
+
+    /// Hand bytes received from a device to the adapter (ACK/NACK for transfers). A handshake
+    /// naming the device's pending signing key confirms that key.
+    pub(crate) fn receive_device_bytes(&self, device_id: &str, bytes: &[u8]) -> anyhow::Result<()> {
+        let Some(key_id) = self.device_adapter.on_device_bytes(device_id, bytes)? else {
+            return Ok(());
+        };
+        let confirmed =
+            self.devices
+                .confirm_stored_signing_key(ConfirmStoredSigningKeyCommand {
+                    remote_id: device_id.to_string(),
+                    key_id,
+                })?;
+        if let Some(key) = confirmed {
+            self.device_adapter
+                .set_signing_key(device_id.to_string(), key);
+        }
+        Ok(())
+    }
+
+    /// Resend timed-out frames and fail transfers that ran out of retries.
+    pub(crate) fn poll_device_transfers(&self) {
+        self.device_adapter.poll_transfers();
     }
 }
 
@@ -116,7 +195,11 @@ pub fn initialize_database(
     route_service: Arc<dyn RouteService>,
     geocoding_service: Arc<dyn GeocodingService>,
 ) -> anyhow::Result<()> {
-    APP_CONTAINER.get_or_init(|| AppContainer::new(db_path, route_service, geocoding_service));
+    if APP_CONTAINER.get().is_none() {
+        let container = AppContainer::new(db_path, route_service, geocoding_service)?;
+        // A concurrent call may have won the race; its container is kept.
+        let _ = APP_CONTAINER.set(container);
+    }
     Ok(())
 }
 
@@ -131,3 +214,8 @@ pub fn subscribe_navigation_events() -> broadcast::Receiver<NavigationEvent> {
 pub fn subscribe_device_messages() -> broadcast::Receiver<DeviceMessage> {
     get_container().subscribe_device_messages()
 }
+
+/// Subscribe to the outcome of reliable route transfers to devices.
+pub fn subscribe_device_transfers() -> broadcast::Receiver<TransferReport> {
+    get_container().subscribe_device_transfers()
+}
@@ -17,3 +17,24 @@ pub struct UpdateDeviceCommand {
 pub struct DeleteDeviceCommand {
     pub id: i64,
 }
+
+/// Issue a new random RouteBlob signing key for the device. Routes stay signed with the
+/// current key until the device confirms the new one.
+#[derive(Debug, Clone)]
+pub struct RotateSigningKeyCommand {
+    pub id: i64,
+}
+
+/// The device has stored the signing key `key_id`; sign its routes with it from now on.
+#[derive(Debug, Clone)]
+pub struct ConfirmSigningKeyCommand {
+    pub id: i64,
+    pub key_id: u32,
+}
+
+/// The device with `remote_id` says in its handshake that it has stored signing key `key_id`.
+#[derive(Debug, Clone)]
+pub struct ConfirmStoredSigningKeyCommand {
+    pub remote_id: String,
+    pub key_id: u32,
+}
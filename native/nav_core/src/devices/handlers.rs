@@ -1,12 +1,16 @@
 // Device application service — handles all device management use cases.
 use anyhow::Result;
 use chrono::Utc;
+use device_comm::SigningKey;
 
 use crate::devices::commands::*;
 use crate::devices::queries::*;
 use crate::infrastructure::database::{DeviceEntity, DeviceRepository};
 use crate::navigation::domain::ports::Repository;
 
+/// Length of a generated signing secret (bytes), the HMAC-SHA256 output size.
+const SIGNING_KEY_BYTES: usize = 32;
+
 /// Handles all use cases for device registration and lookup.
 ///
 /// Constructed once in [`AppContext`] and held for the lifetime of the app.
@@ -58,6 +62,57 @@ impl DevicesHandlers {
     pub fn delete_device(&self, cmd: DeleteDeviceCommand) -> Result<()> {
         self.device_repo.delete(cmd.id)
     }
+
+    /// Give the device a fresh random pending signing key, `key_id` one past the latest one,
+    /// and return it so it can be provisioned to the device. Routes stay signed with the
+    /// current key until `confirm_signing_key`; rotating again replaces the pending key.
+    pub fn rotate_signing_key(&self, cmd: RotateSigningKeyCommand) -> Result<SigningKey> {
+        let latest = self
+            .device_repo
+            .get_pending_signing_key(cmd.id)?
+            .or(self.device_repo.get_signing_key(cmd.id)?);
+        let key_id = latest.map_or(1, |key| key.key_id.wrapping_add(1));
+        let mut secret = vec![0u8; SIGNING_KEY_BYTES];
+        getrandom::fill(&mut secret)
+            .map_err(|e| anyhow::anyhow!("Failed to generate signing key: {}", e))?;
+        let key = SigningKey { key_id, secret };
+        self.device_repo.set_pending_signing_key(cmd.id, &key)?;
+        Ok(key)
+    }
+
+    /// Switch the device to its pending key once it has confirmed `key_id`, and return the key.
+    pub fn confirm_signing_key(&self, cmd: ConfirmSigningKeyCommand) -> Result<SigningKey> {
+        self.device_repo
+            .confirm_signing_key(cmd.id, cmd.key_id)?
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Device {} has no pending signing key {}",
+                    cmd.id,
+                    cmd.key_id
+                )
+            })
+    }
+
+    /// Confirm the pending key of the device with `remote_id` if it is the stored `key_id`,
+    /// and return it. `None` for unknown devices and keys that aren't pending (e.g. a handshake
+    /// repeating an already confirmed key).
+    pub fn confirm_stored_signing_key(
+        &self,
+        cmd: ConfirmStoredSigningKeyCommand,
+    ) -> Result<Option<SigningKey>> {
+        let Some(id) = self
+            .device_repo
+            .get_by_remote_id(&cmd.remote_id)?
+            .and_then(|device| device.id)
+        else {
+            return Ok(None);
+        };
+        self.device_repo.confirm_signing_key(id, cmd.key_id)
+    }
+
+    pub fn get_signing_keys(&self, _: GetSigningKeysQuery) -> Result<Vec<(String, SigningKey)>> {
+        self.device_repo.signing_keys()
+    }
 }
 
 #[cfg(test)]
@@ -212,6 +267,88 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn rotated_signing_key_is_used_once_the_device_confirms_it() {
+        let h = handlers(setup_db());
+        let id = h
+            .register_device(RegisterDeviceCommand {
+                device_json: device_json("KEY-1"),
+            })
+            .unwrap();
+        let first = h
+            .rotate_signing_key(RotateSigningKeyCommand { id })
+            .unwrap();
+        assert!(h.get_signing_keys(GetSigningKeysQuery).unwrap().is_empty());
+        h.confirm_signing_key(ConfirmSigningKeyCommand { id, key_id: 1 })
+            .unwrap();
+
+        // Until key 2 is confirmed, key 1 stays in use; rotating again replaces key 2.
+        let second = h
+            .rotate_signing_key(RotateSigningKeyCommand { id })
+            .unwrap();
+        let third = h
+            .rotate_signing_key(RotateSigningKeyCommand { id })
+            .unwrap();
+        assert_eq!((first.key_id, second.key_id, third.key_id), (1, 2, 3));
+        assert_eq!(third.secret.len(), 32);
+        assert_ne!(first.secret, third.secret);
+        assert_eq!(
+            h.get_signing_keys(GetSigningKeysQuery).unwrap(),
+            vec![("KEY-1".to_string(), first)]
+        );
+        assert!(h
+            .confirm_signing_key(ConfirmSigningKeyCommand { id, key_id: 2 })
+            .is_err());
+        let confirmed = h
+            .confirm_signing_key(ConfirmSigningKeyCommand { id, key_id: 3 })
+            .unwrap();
+        assert_eq!(confirmed, third);
+        assert_eq!(
+            h.get_signing_keys(GetSigningKeysQuery).unwrap(),
+            vec![("KEY-1".to_string(), third)]
+        );
+        assert!(h
+            .rotate_signing_key(RotateSigningKeyCommand { id: id + 1 })
+            .is_err());
+    }
+
+    #[test]
+    fn stored_signing_key_from_the_handshake_confirms_the_pending_key() {
+        let h = handlers(setup_db());
+        let id = h
+            .register_device(RegisterDeviceCommand {
+                device_json: device_json("KEY-2"),
+            })
+            .unwrap();
+        let stored = |key_id| {
+            h.confirm_stored_signing_key(ConfirmStoredSigningKeyCommand {
+                remote_id: "KEY-2".into(),
+                key_id,
+            })
+            .unwrap()
+        };
+        let key = h
+            .rotate_signing_key(RotateSigningKeyCommand { id })
+            .unwrap();
+
+        assert_eq!(stored(7), None);
+        assert_eq!(stored(1), Some(key.clone()));
+        // Later handshakes repeat the key id; it is already in use.
+        assert_eq!(stored(1), None);
+        assert_eq!(
+            h.get_signing_keys(GetSigningKeysQuery).unwrap(),
+            vec![("KEY-2".to_string(), key)]
+        );
+        assert_eq!(
+            h.confirm_stored_signing_key(ConfirmStoredSigningKeyCommand {
+                remote_id: "NOPE".into(),
+                key_id: 1,
+            })
+            .unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn register_device_rejects_invalid_json() {
         let h = handlers(setup_db());
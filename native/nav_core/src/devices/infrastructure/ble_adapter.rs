@@ -4,17 +4,31 @@
 //! a broadcast channel. Actual BLE writes are Flutter-side (`flutter_blue_plus`): Flutter
 //! subscribes to the outgoing stream via `subscribe_device_messages()` and writes each payload
 //! to the connected BLE peripheral.
+//!
+//! Route blobs go out as frames. For devices whose `DeviceCapabilities` handshake announced
+//! `supports_acks`, they go through a `ReliableSender` per device: Flutter passes what the
+//! device sends back to `on_device_bytes` and calls `poll_transfers` on a timer so lost frames
+//! are sent again, and the outcome of each transfer is published as a `TransferReport`. Other
+//! devices get every frame at once. Blobs are LZ4-compressed for devices that announced support
+//! for it, and signed with the device's key once it has one.
 
 use crate::navigation::domain::ports::{ControlCommand, DeviceCommunicationPort};
 use crate::navigation::domain::session::{NavigationSession, TrafficEvent, TrafficSeverity};
 use crate::shared::value_objects::Position;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use device_comm::{create_header, nav_ir_route_to_route_blob, proto};
+use device_comm::{
+    chunk_route_blob, create_header, nav_ir_route_to_route_blob, proto, route_json_to_nav_ir,
+    sign_route_blob, Clock, ReliableConfig, ReliableSender, RouteBlobOptions, SigningKey,
+    SystemClock, TransferStatus, DEFAULT_MTU,
+};
+use nav_engine::InstructionText;
 use nav_ir::Route as NavIrRoute;
 use prost::Message as ProstMessage;
-use std::sync::Mutex;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tokio::sync::broadcast;
+use uuid::Uuid;
 
 /// An outgoing device message: the target device identifier plus serialized protobuf bytes.
 ///
@@ -26,6 +40,19 @@ pub struct DeviceMessage {
     pub bytes: Vec<u8>,
 }
 
+/// How a reliable transfer to a device ended: `status` is `Complete` or `Failed`.
+#[derive(Clone, Debug)]
+pub struct TransferReport {
+    pub device_id: String,
+    pub route_id: Uuid,
+    pub status: TransferStatus,
+}
+
+struct Transfer {
+    route_id: Uuid,
+    sender: ReliableSender,
+}
+
 /// Infrastructure adapter — serialises `DeviceCommunicationPort` calls to protobuf and
 /// publishes them for Flutter's BLE layer.
 ///
@@ -33,6 +60,15 @@ pub struct DeviceMessage {
 /// their `DeviceCommunicationPort`; the device API uses it to send explicit route bytes.
 pub struct ProtobufDeviceAdapter {
     tx: broadcast::Sender<DeviceMessage>,
+    reports: broadcast::Sender<TransferReport>,
+    /// The transfer in progress per device; a new one replaces it.
+    transfers: Mutex<HashMap<String, Transfer>>,
+    /// Latest `DeviceCapabilities` handshake per device.
+    capabilities: Mutex<HashMap<String, proto::DeviceCapabilities>>,
+    /// RouteBlob signing key per device; devices without one get unsigned blobs.
+    signing_keys: Mutex<HashMap<String, SigningKey>>,
+    reliable: ReliableConfig,
+    clock: Arc<dyn Clock>,
     /// Last known position + timestamp for bearing and speed calculation.
     #[allow(dead_code)]
     last_pos: Mutex<Option<(nav_ir::Coordinate, chrono::DateTime<chrono::Utc>)>>,
@@ -40,23 +76,173 @@ pub struct ProtobufDeviceAdapter {
 
 impl ProtobufDeviceAdapter {
     pub fn new() -> Self {
-        let (tx, _) = broadcast::channel(64);
+        // Room for every frame of a long route sent at once to a device without ACKs.
+        let (tx, _) = broadcast::channel(1024);
+        let (reports, _) = broadcast::channel(16);
         Self {
             tx,
+            reports,
+            transfers: Mutex::new(HashMap::new()),
+            capabilities: Mutex::new(HashMap::new()),
+            signing_keys: Mutex::new(HashMap::new()),
+            reliable: ReliableConfig::default(),
+            clock: Arc::new(SystemClock),
             last_pos: Mutex::new(None),
         }
     }
 
+    /// Use `clock` for transfer timeouts instead of the wall clock.
+    #[allow(dead_code)]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn with_reliable_config(mut self, config: ReliableConfig) -> Self {
+        self.reliable = config;
+        self
+    }
+
     /// Subscribe to outgoing device messages. Each subscriber receives a full copy of every
     /// message published after the subscribe call.
     pub fn subscribe(&self) -> broadcast::Receiver<DeviceMessage> {
         self.tx.subscribe()
     }
 
-    /// Publish raw serialized bytes to a device. Used by the device API to send pre-built
-    /// messages (e.g. from `device_comm::prepare_route_message`) without going through the port.
-    pub fn send_raw(&self, device_id: String, bytes: Vec<u8>) {
-        let _ = self.tx.send(DeviceMessage { device_id, bytes });
+    /// How to encode route geometry for `device_id`, per its last `DeviceCapabilities`;
+    /// the encoded polyline for devices that haven't sent any.
+    pub fn route_blob_options(&self, device_id: &str) -> RouteBlobOptions {
+        self.capabilities
+            .lock()
+            .unwrap()
+            .get(device_id)
+            .map(RouteBlobOptions::from_capabilities)
+            .unwrap_or_default()
+    }
+
+    /// Sign route blobs for `device_id` with `key` from now on.
+    pub fn set_signing_key(&self, device_id: String, key: SigningKey) {
+        self.signing_keys.lock().unwrap().insert(device_id, key);
+    }
+
+    /// Stop signing route blobs for `device_id`.
+    pub fn remove_signing_key(&self, device_id: &str) {
+        self.signing_keys.lock().unwrap().remove(device_id);
+    }
+
+    /// Sign `blob` with the device's key; false if the device has none.
+    fn sign(&self, device_id: &str, blob: &mut proto::RouteBlob) -> bool {
+        match self.signing_keys.lock().unwrap().get(device_id) {
+            Some(key) => {
+                sign_route_blob(blob, key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Send a route given as route JSON (see `device_comm::route_json_to_nav_ir`) the way
+    /// `send_route_blob` sends a session's route.
+    pub fn send_route_json(&self, device_id: String, route_json: &str) -> Result<()> {
+        let route = route_json_to_nav_ir(route_json)?;
+        self.send_route(device_id, &route)
+    }
+
+    /// Build `route`'s blob for `device_id`, sign it and send its frames: compressed if the
+    /// device supports it, and through a reliable transfer if it ACKs.
+    fn send_route(&self, device_id: String, route: &NavIrRoute) -> Result<()> {
+        let options = self.route_blob_options(&device_id);
+        let mut blob = nav_ir_route_to_route_blob(route, create_header(1), &options)?;
+        self.sign(&device_id, &mut blob);
+        let (compress, acks) = self
+            .capabilities
+            .lock()
+            .unwrap()
+            .get(&device_id)
+            .map_or((false, false), |c| {
+                (c.supports_compression, c.supports_acks)
+            });
+        let frames = chunk_route_blob(blob, DEFAULT_MTU, compress)?;
+        if acks {
+            self.start_transfer(device_id, route.id.0, frames);
+        } else {
+            // Fire-and-forget: the device won't ACK, so nothing would move a window along.
+            self.transfers.lock().unwrap().remove(&device_id);
+            for frame in frames {
+                self.emit(device_id.clone(), frame.encode_to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Subscribe to the outcome of reliable transfers.
+    pub fn subscribe_transfers(&self) -> broadcast::Receiver<TransferReport> {
+        self.reports.subscribe()
+    }
+
+    /// Handle bytes received from a device. ACK/NACK controls drive that device's transfer and
+    /// a `DeviceCapabilities` handshake is kept for later sends; anything else is ignored.
+    /// Returns the signing key id a handshake says the device has stored, if any.
+    pub fn on_device_bytes(&self, device_id: &str, bytes: &[u8]) -> Result<Option<u32>> {
+        let message = proto::Message::decode(bytes).context("Failed to decode device message")?;
+        let control = match message.payload {
+            Some(proto::message::Payload::Control(control)) => control,
+            Some(proto::message::Payload::DeviceCapabilities(capabilities)) => {
+                let stored_key_id = Some(capabilities.signing_key_id).filter(|&id| id != 0);
+                self.capabilities
+                    .lock()
+                    .unwrap()
+                    .insert(device_id.to_string(), capabilities);
+                return Ok(stored_key_id);
+            }
+            _ => return Ok(None),
+        };
+        let mut transfers = self.transfers.lock().unwrap();
+        if let Some(transfer) = transfers.get_mut(device_id) {
+            if transfer.sender.on_control(&control) {
+                self.drive(device_id, &mut transfers);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Send frames whose ACK timed out again, and fail transfers that ran out of retries.
+    pub fn poll_transfers(&self) {
+        let mut transfers = self.transfers.lock().unwrap();
+        let device_ids: Vec<String> = transfers.keys().cloned().collect();
+        for device_id in device_ids {
+            self.drive(&device_id, &mut transfers);
+        }
+    }
+
+    fn start_transfer(&self, device_id: String, route_id: Uuid, frames: Vec<proto::Frame>) {
+        let sender = ReliableSender::new(frames, self.reliable, Arc::clone(&self.clock));
+        let mut transfers = self.transfers.lock().unwrap();
+        transfers.insert(device_id.clone(), Transfer { route_id, sender });
+        self.drive(&device_id, &mut transfers);
+    }
+
+    /// Emit the frames due for `device_id` and report the transfer once it has finished.
+    fn drive(&self, device_id: &str, transfers: &mut HashMap<String, Transfer>) {
+        let Some(transfer) = transfers.get_mut(device_id) else {
+            return;
+        };
+        if let Ok(frames) = transfer.sender.poll() {
+            for frame in frames {
+                self.emit(device_id.to_string(), frame.encode_to_vec());
+            }
+        }
+        let status = transfer.sender.status();
+        if status != TransferStatus::InProgress {
+            let route_id = transfer.route_id;
+            transfers.remove(device_id);
+            let _ = self.reports.send(TransferReport {
+                device_id: device_id.to_string(),
+                route_id,
+                status,
+            });
+        }
     }
 
     fn serialize<T: ProstMessage>(msg: &T) -> Result<Vec<u8>> {
@@ -108,7 +294,7 @@ impl DeviceCommunicationPort for ProtobufDeviceAdapter {
             route_id: route.id.0.as_bytes().to_vec(),
             distance_m,
             eta_unix_ms,
-            next_turn_text: String::from("Continue straight"),
+            next_turn_text: session.next_turn_text(InstructionText::default()),
             next_turn_bearing_deg: 0,
             remaining_distance_m: distance_m,
             estimated_duration_s: duration_s,
@@ -120,10 +306,7 @@ impl DeviceCommunicationPort for ProtobufDeviceAdapter {
     }
 
     async fn send_route_blob(&self, device_id: String, route: &NavIrRoute) -> Result<()> {
-        let blob = nav_ir_route_to_route_blob(route, create_header(1))?;
-        let bytes = Self::serialize(&blob)?;
-        self.emit(device_id, bytes);
-        Ok(())
+        self.send_route(device_id, route)
     }
 
     async fn send_position_update(&self, device_id: String, position: Position) -> Result<()> {
@@ -238,7 +421,6 @@ fn compute_bearing(from: nav_ir::Coordinate, to: nav_ir::Coordinate) -> f64 {
     (y.atan2(x).to_degrees() + 360.0) % 360.0
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,23 +456,382 @@ mod tests {
         assert!(!msg.bytes.is_empty());
     }
 
+    fn long_route() -> NavIrRoute {
+        let coords: Vec<geo_types::Coord<f64>> = (0..600)
+            .map(|i| geo_types::Coord {
+                x: 4.9 + i as f64 * 0.0007,
+                y: 52.37 + (i as f64 * 0.3).sin() * 0.002,
+            })
+            .collect();
+        let polyline = polyline::encode_coordinates(coords, 5).unwrap();
+        nav_ir::normalize_custom(&[(52.37, 4.9), (52.37, 5.11)], &polyline, None, None).unwrap()
+    }
+
+    /// `long_route` as the route JSON Flutter sends.
+    fn route_json() -> String {
+        let polyline: Vec<[f64; 2]> = (0..600)
+            .map(|i| {
+                [
+                    52.37 + (i as f64 * 0.3).sin() * 0.002,
+                    4.9 + i as f64 * 0.0007,
+                ]
+            })
+            .collect();
+        serde_json::json!({
+            "waypoints": [[52.37, 4.9], [52.37, 5.11]],
+            "polyline": polyline,
+            "distance_m": 14_300.0,
+            "duration_s": 1_200,
+        })
+        .to_string()
+    }
+
+    /// Reassemble the uncompressed RouteBlob in the frames emitted so far.
+    fn received_blob(rx: &mut broadcast::Receiver<DeviceMessage>) -> proto::RouteBlob {
+        let mut receiver = device_comm::ReliableReceiver::new();
+        while let Ok(msg) = rx.try_recv() {
+            let frame = proto::Frame::decode(&msg.bytes[..]).unwrap();
+            receiver.on_frame(frame).unwrap();
+        }
+        let message = proto::Message::decode(&receiver.assemble().unwrap()[..]).unwrap();
+        let Some(proto::message::Payload::RouteBlob(blob)) = message.payload else {
+            panic!("expected a RouteBlob");
+        };
+        blob
+    }
+
+    fn handshake(adapter: &ProtobufDeviceAdapter, capabilities: proto::DeviceCapabilities) {
+        let device_id = capabilities.device_id.clone();
+        let message = proto::Message {
+            payload: Some(proto::message::Payload::DeviceCapabilities(capabilities)),
+        };
+        adapter
+            .on_device_bytes(&device_id, &message.encode_to_vec())
+            .unwrap();
+    }
+
+    fn acking(device_id: &str) -> proto::DeviceCapabilities {
+        proto::DeviceCapabilities {
+            device_id: device_id.into(),
+            supports_acks: true,
+            ..Default::default()
+        }
+    }
+
+    fn control(bytes: &[u8]) -> Vec<u8> {
+        let frame = proto::Frame::decode(bytes).unwrap();
+        let mut receiver = device_comm::ReliableReceiver::new();
+        let controls = receiver.on_frame(frame).unwrap();
+        proto::Message {
+            payload: Some(proto::message::Payload::Control(controls[0].clone())),
+        }
+        .encode_to_vec()
+    }
+
     #[tokio::test]
-    async fn send_raw_emits_verbatim() {
+    async fn route_blob_transfer_completes_when_every_frame_is_acked() {
         let adapter = ProtobufDeviceAdapter::new();
         let mut rx = adapter.subscribe();
+        let mut reports = adapter.subscribe_transfers();
+        let route = long_route();
+        handshake(&adapter, acking("dev-5"));
+
+        adapter
+            .send_route_blob("dev-5".into(), &route)
+            .await
+            .unwrap();
 
-        let payload = vec![1u8, 2, 3, 4];
-        adapter.send_raw("dev-3".into(), payload.clone());
+        let mut received = device_comm::ReliableReceiver::new();
+        while let Ok(msg) = rx.try_recv() {
+            let frame = proto::Frame::decode(&msg.bytes[..]).unwrap();
+            for control in received.on_frame(frame).unwrap() {
+                let bytes = proto::Message {
+                    payload: Some(proto::message::Payload::Control(control)),
+                }
+                .encode_to_vec();
+                adapter.on_device_bytes("dev-5", &bytes).unwrap();
+            }
+        }
 
-        let msg = rx.recv().await.unwrap();
-        assert_eq!(msg.device_id, "dev-3");
-        assert_eq!(msg.bytes, payload);
+        assert!(received.is_complete());
+        let report = reports.try_recv().unwrap();
+        assert_eq!(report.device_id, "dev-5");
+        assert_eq!(report.route_id, route.id.0);
+        assert_eq!(report.status, TransferStatus::Complete);
+    }
+
+    #[test]
+    fn route_json_goes_through_a_reliable_transfer_for_devices_that_ack() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let mut rx = adapter.subscribe();
+        let mut reports = adapter.subscribe_transfers();
+        handshake(&adapter, acking("dev-12"));
+
+        adapter
+            .send_route_json("dev-12".into(), &route_json())
+            .unwrap();
+        let mut first_window = Vec::new();
+        while let Ok(msg) = rx.try_recv() {
+            first_window.push(msg);
+        }
+        assert_eq!(first_window.len() as u32, ReliableConfig::default().window);
+
+        let mut received = device_comm::ReliableReceiver::new();
+        let mut pending = first_window;
+        while !pending.is_empty() {
+            for msg in pending.drain(..) {
+                let frame = proto::Frame::decode(&msg.bytes[..]).unwrap();
+                for control in received.on_frame(frame).unwrap() {
+                    let bytes = proto::Message {
+                        payload: Some(proto::message::Payload::Control(control)),
+                    }
+                    .encode_to_vec();
+                    adapter.on_device_bytes("dev-12", &bytes).unwrap();
+                }
+            }
+            while let Ok(msg) = rx.try_recv() {
+                pending.push(msg);
+            }
+        }
+
+        assert!(received.is_complete());
+        let report = reports.try_recv().unwrap();
+        assert_eq!(report.device_id, "dev-12");
+        assert_eq!(report.status, TransferStatus::Complete);
+    }
+
+    #[tokio::test]
+    async fn route_blob_goes_out_whole_to_devices_without_acks() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let mut rx = adapter.subscribe();
+        let mut reports = adapter.subscribe_transfers();
+
+        adapter
+            .send_route_blob("dev-10".into(), &long_route())
+            .await
+            .unwrap();
+        let mut receiver = device_comm::ReliableReceiver::new();
+        let mut sent = 0;
+        while let Ok(msg) = rx.try_recv() {
+            let frame = proto::Frame::decode(&msg.bytes[..]).unwrap();
+            receiver.on_frame(frame).unwrap();
+            sent += 1;
+        }
+        // More than one window's worth, all without a single ACK.
+        assert!(sent > ReliableConfig::default().window);
+        assert!(receiver.is_complete());
+
+        // No transfer is kept, so polling sends nothing and reports nothing.
+        adapter.poll_transfers();
+        assert!(rx.try_recv().is_err());
+        assert!(reports.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn route_blob_is_compressed_after_the_capabilities_handshake() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let mut rx = adapter.subscribe();
+        let route = long_route();
+
+        adapter
+            .send_route_blob("dev-7".into(), &route)
+            .await
+            .unwrap();
+        let plain = proto::Frame::decode(&rx.try_recv().unwrap().bytes[..]).unwrap();
+        assert_eq!(plain.flags, 0);
+
+        let handshake = proto::Message {
+            payload: Some(proto::message::Payload::DeviceCapabilities(
+                proto::DeviceCapabilities {
+                    device_id: "dev-7".into(),
+                    supports_compression: true,
+                    ..Default::default()
+                },
+            )),
+        };
+        adapter
+            .on_device_bytes("dev-7", &handshake.encode_to_vec())
+            .unwrap();
+        while rx.try_recv().is_ok() {}
+        adapter
+            .send_route_blob("dev-7".into(), &route)
+            .await
+            .unwrap();
+        let compressed = proto::Frame::decode(&rx.try_recv().unwrap().bytes[..]).unwrap();
+        assert_eq!(compressed.flags, device_comm::FLAG_COMPRESSED);
+        assert!(compressed.total_seqs < plain.total_seqs);
+    }
+
+    #[test]
+    fn route_json_is_compressed_for_devices_that_support_it() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let mut rx = adapter.subscribe();
+        handshake(
+            &adapter,
+            proto::DeviceCapabilities {
+                device_id: "dev-11".into(),
+                supports_compression: true,
+                ..Default::default()
+            },
+        );
+
+        adapter
+            .send_route_json("dev-11".into(), &route_json())
+            .unwrap();
+        let mut receiver = device_comm::ReliableReceiver::new();
+        while let Ok(msg) = rx.try_recv() {
+            let frame = proto::Frame::decode(&msg.bytes[..]).unwrap();
+            assert_eq!(frame.flags, device_comm::FLAG_COMPRESSED);
+            receiver.on_frame(frame).unwrap();
+        }
+        let message = proto::Message::decode(&receiver.assemble().unwrap()[..]).unwrap();
+        assert!(matches!(
+            message.payload,
+            Some(proto::message::Payload::RouteBlob(_))
+        ));
+    }
+
+    #[test]
+    fn capabilities_handshake_picks_the_route_geometry_encoding() {
+        let adapter = ProtobufDeviceAdapter::new();
+        assert_eq!(
+            adapter.route_blob_options("dev-5"),
+            RouteBlobOptions::default()
+        );
+
+        let handshake = proto::Message {
+            payload: Some(proto::message::Payload::DeviceCapabilities(
+                proto::DeviceCapabilities {
+                    device_id: "dev-5".into(),
+                    prefers_raw_points: true,
+                    max_route_points: 300,
+                    ..Default::default()
+                },
+            )),
+        };
+        adapter
+            .on_device_bytes("dev-5", &handshake.encode_to_vec())
+            .unwrap();
+        assert_eq!(
+            adapter.route_blob_options("dev-5"),
+            RouteBlobOptions {
+                raw_points: true,
+                max_points: Some(300),
+            }
+        );
+        assert_eq!(
+            adapter.route_blob_options("dev-6"),
+            RouteBlobOptions::default()
+        );
+    }
+
+    #[test]
+    fn capabilities_handshake_reports_the_stored_signing_key() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let handshake = |signing_key_id| {
+            proto::Message {
+                payload: Some(proto::message::Payload::DeviceCapabilities(
+                    proto::DeviceCapabilities {
+                        device_id: "dev-13".into(),
+                        signing_key_id,
+                        ..Default::default()
+                    },
+                )),
+            }
+            .encode_to_vec()
+        };
+        assert_eq!(
+            adapter.on_device_bytes("dev-13", &handshake(3)).unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            adapter.on_device_bytes("dev-13", &handshake(0)).unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn route_blob_is_signed_with_the_device_key() {
+        let adapter = ProtobufDeviceAdapter::new();
+        let mut rx = adapter.subscribe();
+        let key = SigningKey {
+            key_id: 4,
+            secret: vec![9; 32],
+        };
+        adapter.set_signing_key("dev-8".into(), key.clone());
+
+        adapter
+            .send_route_blob("dev-8".into(), &long_route())
+            .await
+            .unwrap();
+        let blob = received_blob(&mut rx);
+        device_comm::verify_route_blob_signature(&blob, std::slice::from_ref(&key)).unwrap();
+
+        // Routes Flutter sends as route JSON are signed too; other devices' stay unsigned.
+        adapter
+            .send_route_json("dev-8".into(), &route_json())
+            .unwrap();
+        let blob = received_blob(&mut rx);
+        device_comm::verify_route_blob_signature(&blob, &[key]).unwrap();
+        adapter
+            .send_route_json("dev-9".into(), &route_json())
+            .unwrap();
+        assert!(received_blob(&mut rx).signature.is_none());
+
+        adapter.remove_signing_key("dev-8");
+        adapter
+            .send_route_json("dev-8".into(), &route_json())
+            .unwrap();
+        assert!(received_blob(&mut rx).signature.is_none());
+    }
+
+    #[tokio::test]
+    async fn route_blob_transfer_fails_when_the_device_stays_silent() {
+        let clock = device_comm::ManualClock::new(0);
+        let adapter = ProtobufDeviceAdapter::new()
+            .with_clock(Arc::new(clock.clone()))
+            .with_reliable_config(ReliableConfig {
+                window: 4,
+                ack_timeout_ms: 1_000,
+                max_retries: 2,
+            });
+        let mut rx = adapter.subscribe();
+        let mut reports = adapter.subscribe_transfers();
+        handshake(&adapter, acking("dev-6"));
+
+        adapter
+            .send_route_blob("dev-6".into(), &long_route())
+            .await
+            .unwrap();
+        let first = rx.try_recv().unwrap();
+        // Only the first frame gets through; its ACK moves the window by one.
+        adapter
+            .on_device_bytes("dev-6", &control(&first.bytes))
+            .unwrap();
+
+        for _ in 0..3 {
+            clock.advance(1_000);
+            adapter.poll_transfers();
+        }
+        let report = reports.try_recv().unwrap();
+        assert!(matches!(
+            report.status,
+            TransferStatus::Failed(device_comm::TransferFailure::Timeout { seq_no: 1 })
+        ));
+        // Finished transfers are dropped; later controls are ignored.
+        adapter
+            .on_device_bytes("dev-6", &control(&first.bytes))
+            .unwrap();
+        adapter.poll_transfers();
+        assert!(reports.try_recv().is_err());
     }
 
     #[test]
     fn no_subscribers_does_not_panic() {
         let adapter = ProtobufDeviceAdapter::new();
         // No receiver held — emit should silently discard
-        adapter.send_raw("dev-4".into(), vec![0xff]);
+        adapter
+            .send_route_json("dev-4".into(), &route_json())
+            .unwrap();
     }
 }
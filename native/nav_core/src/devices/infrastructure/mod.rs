@@ -1,2 +1,2 @@
 pub mod ble_adapter;
-pub use ble_adapter::{DeviceMessage, ProtobufDeviceAdapter};
+pub use ble_adapter::{DeviceMessage, ProtobufDeviceAdapter, TransferReport};
@@ -17,3 +17,7 @@ pub struct GetDeviceByRemoteIdQuery {
 pub struct DeviceExistsByRemoteIdQuery {
     pub remote_id: String,
 }
+
+/// Signing keys of all devices that have one, by remote_id.
+#[derive(Debug, Clone)]
+pub struct GetSigningKeysQuery;
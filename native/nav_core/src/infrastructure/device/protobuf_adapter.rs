@@ -4,7 +4,8 @@ use crate::navigation::domain::{ports::*, session::*};
 use crate::shared::value_objects::*;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use device_comm::{create_header, nav_ir_route_to_route_blob, proto};
+use device_comm::{create_header, nav_ir_route_to_route_blob, proto, RouteBlobOptions};
+use nav_engine::InstructionText;
 use nav_ir::Route as NavIrRoute;
 use prost::Message as ProstMessage;
 use std::sync::Arc;
@@ -53,7 +54,7 @@ impl DeviceCommunicationPort for ProtobufDeviceCommunicator {
             route_id: route.id.0.as_bytes().to_vec(),
             distance_m,
             eta_unix_ms: (chrono::Utc::now().timestamp() + duration_s as i64) as u64 * 1000,
-            next_turn_text: String::from("Continue straight"),
+            next_turn_text: session.next_turn_text(InstructionText::default()),
             next_turn_bearing_deg: 0,
             remaining_distance_m: distance_m,
             estimated_duration_s: duration_s,
@@ -65,7 +66,8 @@ impl DeviceCommunicationPort for ProtobufDeviceCommunicator {
     }
 
     async fn send_route_blob(&self, device_id: String, route: &NavIrRoute) -> Result<()> {
-        let blob = nav_ir_route_to_route_blob(route, create_header(1))?;
+        let blob =
+            nav_ir_route_to_route_blob(route, create_header(1), &RouteBlobOptions::default())?;
 
         let data = Self::serialize_protobuf(&blob)?;
 
@@ -3,7 +3,8 @@ use crate::migrations::{get_all_migrations, MigrationManager};
 use crate::navigation::domain::ports::Repository;
 /// SQLite database infrastructure for persistent storage
 use anyhow::{Context, Result};
-use rusqlite::{Connection, Row};
+use device_comm::SigningKey;
+use rusqlite::{Connection, OptionalExtension, Row};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
@@ -374,6 +375,87 @@ impl DeviceRepository {
         )?;
         Ok(count > 0)
     }
+
+    // Signing keys live in their own columns, outside DeviceEntity, so device JSON never
+    // carries the secret and a device update leaves the key alone. `signing_key` is the key
+    // routes are signed with; `pending_signing_key` is a rotated one the device hasn't
+    // confirmed yet.
+    pub fn get_signing_key(&self, id: i64) -> Result<Option<SigningKey>> {
+        self.query_key(
+            "SELECT signing_key_id, signing_key FROM devices WHERE id = ?",
+            id,
+        )
+    }
+
+    pub fn get_pending_signing_key(&self, id: i64) -> Result<Option<SigningKey>> {
+        self.query_key(
+            "SELECT pending_signing_key_id, pending_signing_key FROM devices WHERE id = ?",
+            id,
+        )
+    }
+
+    fn query_key(&self, sql: &str, id: i64) -> Result<Option<SigningKey>> {
+        let conn = self.base.db().lock().unwrap();
+        let key = conn
+            .query_row(sql, [id], |row| {
+                Ok(row
+                    .get::<_, Option<u32>>(0)?
+                    .zip(row.get::<_, Option<Vec<u8>>>(1)?))
+            })
+            .optional()?
+            .flatten();
+        Ok(key.map(|(key_id, secret)| SigningKey { key_id, secret }))
+    }
+
+    /// Store `key` as the device's pending key, replacing any earlier pending one.
+    pub fn set_pending_signing_key(&self, id: i64, key: &SigningKey) -> Result<()> {
+        let conn = self.base.db().lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE devices SET pending_signing_key_id = ?, pending_signing_key = ? WHERE id = ?",
+            rusqlite::params![key.key_id, key.secret, id],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Device {} not found", id);
+        }
+        Ok(())
+    }
+
+    /// Make the pending key the signing key if its id is `key_id`. Returns the new signing
+    /// key, or `None` if the device has no pending key with that id.
+    pub fn confirm_signing_key(&self, id: i64, key_id: u32) -> Result<Option<SigningKey>> {
+        let updated = self.base.db().lock().unwrap().execute(
+            "UPDATE devices SET signing_key_id = pending_signing_key_id,
+                 signing_key = pending_signing_key,
+                 pending_signing_key_id = NULL, pending_signing_key = NULL
+             WHERE id = ? AND pending_signing_key_id = ? AND pending_signing_key IS NOT NULL",
+            rusqlite::params![id, key_id],
+        )?;
+        if updated == 0 {
+            return Ok(None);
+        }
+        self.get_signing_key(id)
+    }
+
+    /// Signing keys of all devices that have one, by remote_id.
+    pub fn signing_keys(&self) -> Result<Vec<(String, SigningKey)>> {
+        let conn = self.base.db().lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT remote_id, signing_key_id, signing_key FROM devices
+             WHERE signing_key_id IS NOT NULL AND signing_key IS NOT NULL",
+        )?;
+        let keys = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    SigningKey {
+                        key_id: row.get(1)?,
+                        secret: row.get(2)?,
+                    },
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(keys)
+    }
 }
 
 // Delegate to base repository implementation
@@ -590,6 +672,48 @@ mod tests {
         assert!(repo.get_by_remote_id("nonexistent").unwrap().is_none());
     }
 
+    #[test]
+    fn device_repo_signing_key_is_used_once_confirmed() {
+        let conn = setup_db();
+        let repo = DeviceRepository::new(conn);
+        let id = repo.insert(make_device("dev-key")).unwrap();
+        assert!(repo.get_signing_key(id).unwrap().is_none());
+
+        let key = SigningKey {
+            key_id: 3,
+            secret: vec![0x5a; 32],
+        };
+        repo.set_pending_signing_key(id, &key).unwrap();
+        assert_eq!(repo.get_pending_signing_key(id).unwrap(), Some(key.clone()));
+        assert!(repo.get_signing_key(id).unwrap().is_none());
+        assert!(repo.signing_keys().unwrap().is_empty());
+
+        // Only the pending key_id confirms it.
+        assert!(repo.confirm_signing_key(id, 2).unwrap().is_none());
+        assert_eq!(repo.confirm_signing_key(id, 3).unwrap(), Some(key.clone()));
+        assert!(repo.get_pending_signing_key(id).unwrap().is_none());
+        assert!(repo.confirm_signing_key(id, 3).unwrap().is_none());
+
+        // A device update leaves the key alone.
+        let mut device = make_device("dev-key");
+        device.name = "Renamed".to_string();
+        repo.update(id, device).unwrap();
+        assert_eq!(repo.get_signing_key(id).unwrap(), Some(key.clone()));
+        assert_eq!(
+            repo.signing_keys().unwrap(),
+            vec![("dev-key".to_string(), key)]
+        );
+        assert!(repo
+            .set_pending_signing_key(
+                id + 1,
+                &SigningKey {
+                    key_id: 1,
+                    secret: vec![]
+                }
+            )
+            .is_err());
+    }
+
     #[test]
     fn device_repo_exists_by_remote_id() {
         let conn = setup_db();
@@ -131,6 +131,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
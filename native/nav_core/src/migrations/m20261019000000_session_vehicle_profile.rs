@@ -0,0 +1,24 @@
+use super::Migration;
+
+pub struct SessionVehicleProfileSchema {}
+
+impl Migration for SessionVehicleProfileSchema {
+    fn version(&self) -> i64 {
+        20261019000000
+    }
+
+    fn description(&self) -> &str {
+        "Add vehicle_profile column to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN vehicle_profile TEXT DEFAULT 'car';
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
@@ -0,0 +1,25 @@
+use super::Migration;
+
+pub struct SessionRouteProgressSchema {}
+
+impl Migration for SessionRouteProgressSchema {
+    fn version(&self) -> i64 {
+        20261019000001
+    }
+
+    fn description(&self) -> &str {
+        "Add matched route progress (segment + fraction) to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN progress_segment_index INTEGER;
+        ALTER TABLE navigation_sessions ADD COLUMN progress_fraction REAL;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use super::Migration;
+
+pub struct SessionRerouteOffsetSchema {}
+
+impl Migration for SessionRerouteOffsetSchema {
+    fn version(&self) -> i64 {
+        20261019000002
+    }
+
+    fn description(&self) -> &str {
+        "Add distance traveled before the last reroute to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN reroute_offset_m REAL NOT NULL DEFAULT 0;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use super::Migration;
+
+pub struct SessionEngineSnapshotSchema {}
+
+impl Migration for SessionEngineSnapshotSchema {
+    fn version(&self) -> i64 {
+        20261019000003
+    }
+
+    fn description(&self) -> &str {
+        "Add the serialized nav_engine snapshot to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN engine_snapshot_json TEXT;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use super::Migration;
+
+pub struct SessionAlternativeRoutesSchema {}
+
+impl Migration for SessionAlternativeRoutesSchema {
+    fn version(&self) -> i64 {
+        20261019000004
+    }
+
+    fn description(&self) -> &str {
+        "Add the alternative routes still being tracked to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN alternatives_json TEXT;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
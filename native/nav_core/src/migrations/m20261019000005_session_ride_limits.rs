@@ -0,0 +1,25 @@
+use super::Migration;
+
+pub struct SessionRideLimitsSchema {}
+
+impl Migration for SessionRideLimitsSchema {
+    fn version(&self) -> i64 {
+        20261019000005
+    }
+
+    fn description(&self) -> &str {
+        "Add vehicle range and break interval to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN vehicle_range_m REAL;
+        ALTER TABLE navigation_sessions ADD COLUMN break_after_s REAL;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
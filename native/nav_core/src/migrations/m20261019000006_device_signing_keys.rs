@@ -0,0 +1,27 @@
+use super::Migration;
+
+pub struct DeviceSigningKeysSchema {}
+
+impl Migration for DeviceSigningKeysSchema {
+    fn version(&self) -> i64 {
+        20261019000006
+    }
+
+    fn description(&self) -> &str {
+        "Add RouteBlob signing key material to devices"
+    }
+
+    fn up(&self) -> &str {
+        // The secret is stored in plaintext: anyone who can read the app's database can sign
+        // routes for the device. The database lives in the app's private storage.
+        "
+        ALTER TABLE devices ADD COLUMN signing_key_id INTEGER;
+        ALTER TABLE devices ADD COLUMN signing_key BLOB;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
@@ -0,0 +1,24 @@
+use super::Migration;
+
+pub struct SessionNextTurnSchema {}
+
+impl Migration for SessionNextTurnSchema {
+    fn version(&self) -> i64 {
+        20261019000007
+    }
+
+    fn description(&self) -> &str {
+        "Add the next turn recorded from the engine to navigation_sessions"
+    }
+
+    fn up(&self) -> &str {
+        "
+        ALTER TABLE navigation_sessions ADD COLUMN next_turn_json TEXT;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
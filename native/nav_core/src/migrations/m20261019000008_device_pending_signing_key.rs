@@ -0,0 +1,26 @@
+use super::Migration;
+
+pub struct DevicePendingSigningKeySchema {}
+
+impl Migration for DevicePendingSigningKeySchema {
+    fn version(&self) -> i64 {
+        20261019000008
+    }
+
+    fn description(&self) -> &str {
+        "Add the rotated signing key a device hasn't confirmed yet to devices"
+    }
+
+    fn up(&self) -> &str {
+        // Stored in plaintext like `signing_key`, see m20261019000006.
+        "
+        ALTER TABLE devices ADD COLUMN pending_signing_key_id INTEGER;
+        ALTER TABLE devices ADD COLUMN pending_signing_key BLOB;
+        "
+    }
+
+    fn down(&self) -> Option<&str> {
+        // SQLite does not support DROP COLUMN on older versions; leave as-is on rollback
+        None
+    }
+}
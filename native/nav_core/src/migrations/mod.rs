@@ -18,6 +18,15 @@ mod m20250224000000_trips;
 mod m20250225000000_saved_routes;
 mod m20260307000000_navigation_sessions;
 mod m20260309000000_nav_engine_state;
+mod m20261019000000_session_vehicle_profile;
+mod m20261019000001_session_route_progress;
+mod m20261019000002_session_reroute_offset;
+mod m20261019000003_session_engine_snapshot;
+mod m20261019000004_session_alternative_routes;
+mod m20261019000005_session_ride_limits;
+mod m20261019000006_device_signing_keys;
+mod m20261019000007_session_next_turn;
+mod m20261019000008_device_pending_signing_key;
 
 // Re-export migrations (internal use only, not for FFI)
 pub use m20231201000000_initial_schema::InitialSchema;
@@ -257,6 +266,15 @@ pub fn get_all_migrations() -> Vec<Box<dyn Migration>> {
         Box::new(m20250225000000_saved_routes::SavedRoutesSchema {}),
         Box::new(m20260307000000_navigation_sessions::NavigationSessionsSchema {}),
         Box::new(m20260309000000_nav_engine_state::NavEngineStateSchema {}),
+        Box::new(m20261019000000_session_vehicle_profile::SessionVehicleProfileSchema {}),
+        Box::new(m20261019000001_session_route_progress::SessionRouteProgressSchema {}),
+        Box::new(m20261019000002_session_reroute_offset::SessionRerouteOffsetSchema {}),
+        Box::new(m20261019000003_session_engine_snapshot::SessionEngineSnapshotSchema {}),
+        Box::new(m20261019000004_session_alternative_routes::SessionAlternativeRoutesSchema {}),
+        Box::new(m20261019000005_session_ride_limits::SessionRideLimitsSchema {}),
+        Box::new(m20261019000006_device_signing_keys::DeviceSigningKeysSchema {}),
+        Box::new(m20261019000007_session_next_turn::SessionNextTurnSchema {}),
+        Box::new(m20261019000008_device_pending_signing_key::DevicePendingSigningKeySchema {}),
     ]
 }
 
@@ -1,5 +1,6 @@
 // Commands - Write operations
 use crate::shared::value_objects::*;
+use nav_engine::VehicleProfile;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -9,6 +10,9 @@ pub struct StartNavigationCommand {
     pub waypoints: Vec<Position>,
     pub current_position: Position,
     pub device_id: Option<String>,
+    /// Vehicle profile persisted with the session; selects engine thresholds.
+    #[serde(default)]
+    pub profile: VehicleProfile,
 }
 
 /// Update current position during navigation
@@ -16,6 +20,57 @@ pub struct StartNavigationCommand {
 pub struct UpdatePositionCommand {
     pub session_id: Uuid,
     pub position: Position,
+    /// Horizontal accuracy of the fix (meters); poor fixes are replaced by dead reckoning.
+    #[serde(default)]
+    pub accuracy_m: Option<f64>,
+    /// GPS course over ground (degrees).
+    #[serde(default)]
+    pub bearing_deg: Option<f64>,
+    #[serde(default)]
+    pub speed_mps: Option<f64>,
+    /// Fix time (Unix ms); needed for smoothing and dead reckoning.
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+}
+
+/// Dead-reckon the position along the route when no GPS fix arrived (tunnels)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatePositionCommand {
+    pub session_id: Uuid,
+    pub timestamp_ms: u64,
+}
+
+/// Ask for a connector route from the rider back to the rejoin point of a `FixedGeometry` track
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestRejoinConnectorCommand {
+    pub session_id: Uuid,
+}
+
+/// Offer alternatives to the session's route; the rider's driving picks one, no tap needed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackAlternativeRoutesCommand {
+    pub session_id: Uuid,
+    pub routes: Vec<nav_ir::Route>,
+}
+
+/// Set the vehicle's range and break interval for a session; `range_left_m` is what is left
+/// right now when the tank isn't full
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRideLimitsCommand {
+    pub session_id: Uuid,
+    pub vehicle_range_m: Option<f64>,
+    pub range_left_m: Option<f64>,
+    pub break_after_s: Option<f64>,
+}
+
+/// Drive a session with synthetic GPS fixes along its route (demos, testing without riding)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateSessionCommand {
+    pub session_id: Uuid,
+    #[serde(default)]
+    pub simulator: nav_engine::SimulatorConfig,
+    /// Playback speed: 1.0 is real time, 10.0 plays ten times faster.
+    pub time_scale: f64,
 }
 
 /// Pause active navigation
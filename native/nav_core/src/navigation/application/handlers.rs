@@ -1,8 +1,11 @@
 // Command and Query Handlers - Application logic
 use crate::navigation::application::{commands::*, queries::*};
-use crate::navigation::domain::{events::NavigationEvent, ports::*, session::*};
+use crate::navigation::domain::{
+    events::NavigationEvent, ports::*, reroute::RerouteThrottle, session::*,
+};
 use crate::shared::value_objects::*;
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::broadcast;
 
@@ -16,7 +19,8 @@ pub struct NavigationHandlers {
     route_service: Arc<dyn RouteService>,
     navigation_repo: Arc<dyn NavigationRepository>,
     start_handler: StartNavigationHandler,
-    update_position_handler: UpdatePositionHandler,
+    update_position_handler: Arc<UpdatePositionHandler>,
+    simulate_handler: SimulateSessionHandler,
     pause_handler: PauseNavigationHandler,
     resume_handler: ResumeNavigationHandler,
     stop_handler: StopNavigationHandler,
@@ -31,6 +35,11 @@ impl NavigationHandlers {
         device_comm: Arc<dyn DeviceCommunicationPort>,
         event_bus: broadcast::Sender<NavigationEvent>,
     ) -> Self {
+        let update_position_handler = Arc::new(UpdatePositionHandler::new(
+            Arc::clone(&route_service),
+            Arc::clone(&navigation_repo),
+            event_bus.clone(),
+        ));
         Self {
             start_handler: StartNavigationHandler::new(
                 Arc::clone(&route_service),
@@ -38,14 +47,16 @@ impl NavigationHandlers {
                 Arc::clone(&device_comm),
                 event_bus.clone(),
             ),
-            update_position_handler: UpdatePositionHandler::new(
+            update_position_handler: Arc::clone(&update_position_handler),
+            simulate_handler: SimulateSessionHandler::new(
                 Arc::clone(&navigation_repo),
-                event_bus.clone(),
+                Arc::clone(&update_position_handler),
             ),
             pause_handler: PauseNavigationHandler::new(Arc::clone(&navigation_repo)),
             resume_handler: ResumeNavigationHandler::new(Arc::clone(&navigation_repo)),
             stop_handler: StopNavigationHandler::new(
                 Arc::clone(&navigation_repo),
+                update_position_handler,
                 event_bus.clone(),
             ),
             get_active_handler: GetActiveSessionHandler::new(Arc::clone(&navigation_repo)),
@@ -70,6 +81,40 @@ impl NavigationHandlers {
         self.update_position_handler.handle(cmd).await
     }
 
+    pub async fn estimate_position(
+        &self,
+        cmd: EstimatePositionCommand,
+    ) -> Result<Option<nav_engine::NavigationState>> {
+        self.update_position_handler.handle_estimate(cmd)
+    }
+
+    pub async fn track_alternative_routes(&self, cmd: TrackAlternativeRoutesCommand) -> Result<()> {
+        self.update_position_handler.handle_alternatives(cmd).await
+    }
+
+    pub async fn set_ride_limits(&self, cmd: SetRideLimitsCommand) -> Result<()> {
+        self.update_position_handler.handle_ride_limits(cmd).await
+    }
+
+    pub async fn request_rejoin_connector(
+        &self,
+        cmd: RequestRejoinConnectorCommand,
+    ) -> Result<Option<nav_ir::Route>> {
+        self.update_position_handler
+            .handle_rejoin_connector(cmd)
+            .await
+    }
+
+    /// Runs until the simulated ride ends, the session stops being active, or
+    /// `stop_simulation` is called. Returns the number of fixes fed.
+    pub async fn simulate(&self, cmd: SimulateSessionCommand) -> Result<usize> {
+        self.simulate_handler.handle(cmd).await
+    }
+
+    pub fn stop_simulation(&self, session_id: uuid::Uuid) -> bool {
+        self.simulate_handler.stop(session_id)
+    }
+
     pub async fn pause(&self, cmd: PauseNavigationCommand) -> Result<()> {
         self.pause_handler.handle(cmd).await
     }
@@ -160,7 +205,8 @@ impl StartNavigationHandler {
             .context("Failed to calculate route")?;
 
         // Create session
-        let session = NavigationSession::new(route.clone(), command.current_position);
+        let session = NavigationSession::new(route.clone(), command.current_position)
+            .with_profile(command.profile);
 
         // Persist session
         self.navigation_repo
@@ -187,18 +233,32 @@ impl StartNavigationHandler {
 
 /// Command handler for UpdatePositionCommand
 pub struct UpdatePositionHandler {
+    route_service: Arc<dyn RouteService>,
     navigation_repo: Arc<dyn NavigationRepository>,
     event_bus: broadcast::Sender<NavigationEvent>,
+    /// Live engines per session, so map-matching state (progress cursor, last fix) carries
+    /// across fixes. Rebuilt from the persisted session on a miss or when the route changes.
+    engines: std::sync::Mutex<HashMap<uuid::Uuid, nav_engine::NavigationEngine>>,
+    /// Sessions still deciding between alternative routes; they use these instead of
+    /// `engines` until one route wins.
+    candidates: std::sync::Mutex<HashMap<uuid::Uuid, nav_engine::CandidateTracker>>,
+    /// Automatic reroute rate limiting per session.
+    reroutes: std::sync::Mutex<HashMap<uuid::Uuid, RerouteThrottle>>,
 }
 
 impl UpdatePositionHandler {
     pub fn new(
+        route_service: Arc<dyn RouteService>,
         navigation_repo: Arc<dyn NavigationRepository>,
         event_bus: broadcast::Sender<NavigationEvent>,
     ) -> Self {
         Self {
+            route_service,
             navigation_repo,
             event_bus,
+            engines: std::sync::Mutex::new(HashMap::new()),
+            candidates: std::sync::Mutex::new(HashMap::new()),
+            reroutes: std::sync::Mutex::new(HashMap::new()),
         }
     }
 }
@@ -219,17 +279,98 @@ impl UpdatePositionHandler {
         session.update_position(command.position);
 
         // Run nav_engine to produce full navigation state
-        let coord = nav_ir::Coordinate::new(command.position.latitude, command.position.longitude);
-        let mut engine = nav_engine::NavigationEngine::new_with_state(
-            session.route.clone(),
-            session.current_step_index,
-            session.distance_traveled_m,
-        );
-        let nav_state = engine.update_position(coord, None);
+        let fix = nav_engine::GpsFix {
+            coordinate: nav_ir::Coordinate::new(
+                command.position.latitude,
+                command.position.longitude,
+            ),
+            accuracy_m: command.accuracy_m,
+            bearing_deg: command.bearing_deg,
+            speed_mps: command.speed_mps,
+            timestamp_ms: command.timestamp_ms,
+        };
+        let mut route_chosen = false;
+        let nav_state = if !session.alternatives.is_empty() {
+            let (nav_state, chosen) = self.track_candidates(&mut session, fix);
+            route_chosen = chosen;
+            nav_state
+        } else {
+            let mut engines = self.engines.lock().unwrap();
+            let engine = engines
+                .entry(session.id)
+                .and_modify(|e| {
+                    if e.route().id != session.route.id {
+                        *e = session.build_engine();
+                    }
+                })
+                .or_insert_with(|| session.build_engine());
+            let nav_state = engine.update_fix(fix);
+
+            // Persist updated step/distance back to session
+            session.record_engine(engine);
+            nav_state
+        };
 
-        // Persist updated step/distance back to session
-        session.current_step_index = engine.current_step();
-        session.distance_traveled_m = engine.distance_traveled_m();
+        // SnapToRoute keeps guiding along the route silently; the others warn once per episode.
+        // Riding the wrong way has its own warning, whatever the behavior.
+        let warn_wrong_way = nav_state.wrong_way.as_ref().is_some_and(|w| w.entered);
+        let warn_off_route = nav_state.off_route.entered
+            && !warn_wrong_way
+            && nav_state.off_route.behavior != nav_ir::OffRouteBehavior::SnapToRoute;
+
+        // Confirmed off a recalculatable route: ask for a new one from here and continue on it.
+        let mut rerouted = false;
+        if nav_state.off_route.is_off_route
+            && nav_state.off_route.behavior == nav_ir::OffRouteBehavior::Recalculate
+        {
+            let now_ms = command
+                .timestamp_ms
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis().max(0) as u64);
+            rerouted = self.reroute(&mut session, command.position, now_ms).await;
+        }
+        // Alerts raised or cleared by this fix, on the old route and then on the new one.
+        let mut alert_changes: Vec<(nav_engine::ConstraintAlert, bool)> = nav_state
+            .raised_alerts
+            .iter()
+            .map(|&a| (a, true))
+            .chain(nav_state.cleared_alerts.iter().map(|&a| (a, false)))
+            .collect();
+        let mut low_range = nav_state.range.clone().filter(|r| r.entered);
+        let mut break_suggestion = nav_state.break_suggestion.clone().filter(|b| b.entered);
+        let nav_state = if rerouted {
+            let mut engines = self.engines.lock().unwrap();
+            let active = nav_state.constraint_alerts.clone();
+            let stops = engines
+                .get(&session.id)
+                .map(|engine| engine.stops().clone())
+                .unwrap_or_default();
+            let engine = engines
+                .entry(session.id)
+                .insert_entry(
+                    session
+                        .build_engine()
+                        .with_constraint_alerts(active)
+                        .with_stops(stops),
+                )
+                .into_mut();
+            let nav_state = engine.update_fix(fix);
+            session.record_engine(engine);
+            alert_changes.extend(nav_state.raised_alerts.iter().map(|&a| (a, true)));
+            alert_changes.extend(nav_state.cleared_alerts.iter().map(|&a| (a, false)));
+            low_range = low_range.or_else(|| nav_state.range.clone().filter(|r| r.entered));
+            break_suggestion = break_suggestion
+                .or_else(|| nav_state.break_suggestion.clone().filter(|b| b.entered));
+            nav_state
+        } else {
+            nav_state
+        };
+
+        // Final arrival finishes the session without the user tapping stop.
+        let completed = nav_state.arrived && session.status == NavigationStatus::Active;
+        if completed {
+            session.complete();
+            self.forget_session(session.id);
+        }
 
         self.navigation_repo.save_session(&session).await?;
 
@@ -239,16 +380,333 @@ impl UpdatePositionHandler {
             position: command.position,
         });
 
-        if nav_state.off_route.is_off_route {
-            if let nav_ir::OffRouteBehavior::Recalculate = nav_state.off_route.behavior {
-                let _ = self.event_bus.send(NavigationEvent::OffRoute {
-                    session_id: session.id,
-                });
-            }
+        if warn_off_route {
+            let _ = self.event_bus.send(NavigationEvent::OffRoute {
+                session_id: session.id,
+            });
+        }
+
+        if warn_wrong_way {
+            let _ = self.event_bus.send(NavigationEvent::WrongWay {
+                session_id: session.id,
+            });
+        }
+
+        for (alert, raised) in alert_changes {
+            let session_id = session.id;
+            let _ = self.event_bus.send(if raised {
+                NavigationEvent::ConstraintAlertRaised { session_id, alert }
+            } else {
+                NavigationEvent::ConstraintAlertCleared { session_id, alert }
+            });
+        }
+
+        if let Some(range) = low_range {
+            let _ = self.event_bus.send(NavigationEvent::LowRange {
+                session_id: session.id,
+                range_left_m: range.range_left_m,
+                next_fuel_index: range.next_fuel.map(|w| w.index),
+            });
+        }
+
+        if let Some(suggestion) = break_suggestion {
+            let _ = self.event_bus.send(NavigationEvent::BreakSuggested {
+                session_id: session.id,
+                riding_s: suggestion.riding_s,
+                next_break_index: suggestion.next_break.map(|w| w.index),
+            });
+        }
+
+        if route_chosen {
+            let _ = self.event_bus.send(NavigationEvent::RouteChosen {
+                session_id: session.id,
+                route_id: session.route.id.0,
+            });
+        }
+
+        if rerouted {
+            let _ = self.event_bus.send(NavigationEvent::Rerouted {
+                session_id: session.id,
+                route_id: session.route.id.0,
+            });
+        }
+
+        for &index in &nav_state.reached_waypoints {
+            let _ = self.event_bus.send(NavigationEvent::WaypointReached {
+                session_id: session.id,
+                index,
+            });
+        }
+
+        if completed {
+            let _ = self.event_bus.send(NavigationEvent::Completed {
+                session_id: session.id,
+                distance_m: session.distance_traveled_m,
+            });
         }
 
         Ok(nav_state)
     }
+
+    /// Feed the fix to every candidate route of a session that has alternatives. Returns the
+    /// leading candidate's state and whether the session settled on a route with this fix.
+    fn track_candidates(
+        &self,
+        session: &mut NavigationSession,
+        fix: nav_engine::GpsFix,
+    ) -> (nav_engine::NavigationState, bool) {
+        let mut trackers = self.candidates.lock().unwrap();
+        let tracker = trackers
+            .entry(session.id)
+            .or_insert_with(|| session.build_candidate_tracker());
+        let update = tracker.update_fix(fix);
+        if !update.committed {
+            // The session keeps its own route until another one wins.
+            let engine = tracker.engine(0).expect("the session route is a candidate");
+            session.record_engine(engine);
+            return (update.state, false);
+        }
+        let engine = trackers
+            .remove(&session.id)
+            .and_then(|tracker| tracker.into_chosen())
+            .expect("a committed tracker has a chosen engine");
+        session.choose_route(update.index);
+        session.record_engine(&engine);
+        self.engines.lock().unwrap().insert(session.id, engine);
+        (update.state, true)
+    }
+
+    /// Request a route from `position` unless throttled; on success it replaces the session's
+    /// route. Returns whether the route was replaced.
+    async fn reroute(
+        &self,
+        session: &mut NavigationSession,
+        position: Position,
+        now_ms: u64,
+    ) -> bool {
+        let ready = self
+            .reroutes
+            .lock()
+            .unwrap()
+            .entry(session.id)
+            .or_default()
+            .ready(now_ms);
+        if !ready {
+            return false;
+        }
+        let result = self
+            .route_service
+            .recalculate_from_position(&session.route, position)
+            .await;
+        let succeeded = result.is_ok();
+        if let Some(throttle) = self.reroutes.lock().unwrap().get_mut(&session.id) {
+            throttle.record(now_ms, succeeded);
+        }
+        match result {
+            Ok(route) => {
+                session.reroute(route);
+                self.candidates.lock().unwrap().remove(&session.id);
+                true
+            }
+            // Keep guiding off the old route; the throttle backs off before the next try.
+            Err(_) => false,
+        }
+    }
+
+    /// Route from the rider's last position to the rejoin point only, for display while off a
+    /// `FixedGeometry` track. The session keeps its route; guidance resumes once the rider is
+    /// matched back onto the track. `None` when the session is not in rejoin mode.
+    pub async fn handle_rejoin_connector(
+        &self,
+        command: RequestRejoinConnectorCommand,
+    ) -> Result<Option<nav_ir::Route>> {
+        let rejoin_point = self
+            .engines
+            .lock()
+            .unwrap()
+            .get(&command.session_id)
+            .and_then(|engine| engine.rejoin().map(|r| r.point));
+        let Some(point) = rejoin_point else {
+            return Ok(None);
+        };
+        let session = self
+            .navigation_repo
+            .load_session(command.session_id)
+            .await?
+            .context("Navigation session not found")?;
+        let route = self
+            .route_service
+            .calculate_route(vec![
+                session.current_position,
+                Position::new(point.latitude, point.longitude)?,
+            ])
+            .await?;
+        Ok(Some(route))
+    }
+
+    /// Offer alternatives to the session's route. Fixes are then matched against all of them
+    /// until the rider clearly follows one; the session switches to it and `RouteChosen` is
+    /// sent. An empty list stops tracking and keeps the current route.
+    pub async fn handle_alternatives(&self, command: TrackAlternativeRoutesCommand) -> Result<()> {
+        let mut session = self
+            .navigation_repo
+            .load_session(command.session_id)
+            .await?
+            .context("Navigation session not found")?;
+        session.offer_alternatives(command.routes);
+        self.candidates.lock().unwrap().remove(&session.id);
+        self.navigation_repo.save_session(&session).await
+    }
+
+    /// Set the session's vehicle range and break interval. The live engine is rebuilt from its
+    /// snapshot with the new limits, keeping the range used and time ridden so far unless
+    /// `range_left_m` says how much is left now.
+    pub async fn handle_ride_limits(&self, command: SetRideLimitsCommand) -> Result<()> {
+        let mut session = self
+            .navigation_repo
+            .load_session(command.session_id)
+            .await?
+            .context("Navigation session not found")?;
+        session.set_ride_limits(command.vehicle_range_m, command.break_after_s);
+        let mut engine = session.build_engine();
+        if let Some(range_left_m) = command.range_left_m {
+            engine = engine.with_range_left(range_left_m);
+        }
+        session.engine_snapshot = Some(engine.snapshot());
+        self.engines.lock().unwrap().insert(session.id, engine);
+        // Alternatives are tracked afresh with the new limits.
+        self.candidates.lock().unwrap().remove(&session.id);
+        self.navigation_repo.save_session(&session).await
+    }
+
+    /// Drop the live engine, candidate tracker and reroute throttle of a session that ended,
+    /// so a session resumed later starts from its persisted state.
+    pub(crate) fn forget_session(&self, session_id: uuid::Uuid) {
+        self.engines.lock().unwrap().remove(&session_id);
+        self.candidates.lock().unwrap().remove(&session_id);
+        self.reroutes.lock().unwrap().remove(&session_id);
+    }
+
+    /// Dead-reckoned state from the session's live engine. Nothing is persisted: the estimate
+    /// is replaced by the next real fix. `None` when there is no live engine or no recent fix.
+    pub fn handle_estimate(
+        &self,
+        command: EstimatePositionCommand,
+    ) -> Result<Option<nav_engine::NavigationState>> {
+        if let Some(tracker) = self.candidates.lock().unwrap().get_mut(&command.session_id) {
+            return Ok(tracker
+                .estimate_position(command.timestamp_ms)
+                .map(|update| update.state));
+        }
+        let mut engines = self.engines.lock().unwrap();
+        Ok(engines
+            .get_mut(&command.session_id)
+            .and_then(|engine| engine.estimate_position(command.timestamp_ms)))
+    }
+}
+
+/// Command handler for SimulateSessionCommand
+///
+/// Feeds `RouteSimulator` fixes through `UpdatePositionHandler` on a timer, so matching,
+/// persistence and the event pipeline run exactly as for real GPS. The simulated rider keeps
+/// to the route the session had when the simulation started, even after a reroute.
+pub struct SimulateSessionHandler {
+    navigation_repo: Arc<dyn NavigationRepository>,
+    update_position_handler: Arc<UpdatePositionHandler>,
+    /// Stop flags of running simulations.
+    running: std::sync::Mutex<HashMap<uuid::Uuid, Arc<std::sync::atomic::AtomicBool>>>,
+}
+
+impl SimulateSessionHandler {
+    pub fn new(
+        navigation_repo: Arc<dyn NavigationRepository>,
+        update_position_handler: Arc<UpdatePositionHandler>,
+    ) -> Self {
+        Self {
+            navigation_repo,
+            update_position_handler,
+            running: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl SimulateSessionHandler {
+    pub async fn handle(&self, command: SimulateSessionCommand) -> Result<usize> {
+        anyhow::ensure!(command.time_scale > 0.0, "time_scale must be positive");
+        let session = self
+            .navigation_repo
+            .load_session(command.session_id)
+            .await?
+            .context("Navigation session not found")?;
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        {
+            let mut running = self.running.lock().unwrap();
+            anyhow::ensure!(
+                !running.contains_key(&session.id),
+                "Session is already being simulated"
+            );
+            running.insert(session.id, Arc::clone(&stop));
+        }
+        let result = self.run(session, &command, &stop).await;
+        self.running.lock().unwrap().remove(&command.session_id);
+        result
+    }
+
+    /// Ask a running simulation to stop after its current fix. Returns whether one was running.
+    pub fn stop(&self, session_id: uuid::Uuid) -> bool {
+        match self.running.lock().unwrap().get(&session_id) {
+            Some(flag) => {
+                flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    async fn run(
+        &self,
+        session: NavigationSession,
+        command: &SimulateSessionCommand,
+        stop: &std::sync::atomic::AtomicBool,
+    ) -> Result<usize> {
+        let simulator =
+            nav_engine::RouteSimulator::new(session.route.clone(), command.simulator.clone());
+        let mut previous_ms = None;
+        let mut fed = 0;
+        for fix in simulator {
+            let timestamp_ms = fix.timestamp_ms.unwrap_or_default();
+            if let Some(previous_ms) = previous_ms {
+                let wait_ms = timestamp_ms.saturating_sub(previous_ms) as f64 / command.time_scale;
+                tokio::time::sleep(std::time::Duration::from_secs_f64(wait_ms / 1000.0)).await;
+            }
+            previous_ms = Some(timestamp_ms);
+
+            let active = self
+                .navigation_repo
+                .load_session(session.id)
+                .await?
+                .is_some_and(|s| s.status == NavigationStatus::Active);
+            if stop.load(std::sync::atomic::Ordering::SeqCst) || !active {
+                break;
+            }
+            let state = self
+                .update_position_handler
+                .handle(UpdatePositionCommand {
+                    session_id: session.id,
+                    position: Position::new(fix.coordinate.latitude, fix.coordinate.longitude)?,
+                    accuracy_m: fix.accuracy_m,
+                    bearing_deg: fix.bearing_deg,
+                    speed_mps: fix.speed_mps,
+                    timestamp_ms: fix.timestamp_ms,
+                })
+                .await?;
+            fed += 1;
+            if state.arrived {
+                break;
+            }
+        }
+        Ok(fed)
+    }
 }
 
 /// Command handler for PauseNavigationCommand
@@ -306,16 +764,20 @@ impl ResumeNavigationHandler {
 /// Command handler for StopNavigationCommand
 pub struct StopNavigationHandler {
     navigation_repo: Arc<dyn NavigationRepository>,
+    /// Holds the per-session live state that a stopped session no longer needs.
+    update_position_handler: Arc<UpdatePositionHandler>,
     event_bus: broadcast::Sender<NavigationEvent>,
 }
 
 impl StopNavigationHandler {
     pub fn new(
         navigation_repo: Arc<dyn NavigationRepository>,
+        update_position_handler: Arc<UpdatePositionHandler>,
         event_bus: broadcast::Sender<NavigationEvent>,
     ) -> Self {
         Self {
             navigation_repo,
+            update_position_handler,
             event_bus,
         }
     }
@@ -344,6 +806,7 @@ impl StopNavigationHandler {
         }
 
         self.navigation_repo.save_session(&session).await?;
+        self.update_position_handler.forget_session(session.id);
 
         Ok(())
     }
@@ -440,6 +903,39 @@ mod tests {
         }
     }
 
+    /// Recalculates a straight ~1.1 km route north from the rider, or fails; counts requests.
+    struct ReroutingService {
+        fail: bool,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ReroutingService {
+        fn new(fail: bool) -> Self {
+            Self {
+                fail,
+                calls: Default::default(),
+            }
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl RouteService for ReroutingService {
+        async fn calculate_route(&self, _: Vec<Position>) -> Result<Route> {
+            Ok(make_route())
+        }
+        async fn recalculate_from_position(&self, _: &Route, from: Position) -> Result<Route> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if self.fail {
+                anyhow::bail!("network error")
+            }
+            Ok(straight_route(from.latitude, from.longitude))
+        }
+    }
+
     struct FixedGeocodingService;
 
     #[async_trait]
@@ -516,6 +1012,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         }
@@ -550,6 +1048,7 @@ mod tests {
             waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
             current_position: pos(40.71, -74.01),
             device_id: None,
+            profile: nav_engine::VehicleProfile::Car,
         };
 
         let session = handler.handle(cmd).await.unwrap();
@@ -572,6 +1071,7 @@ mod tests {
             waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
             current_position: pos(40.71, -74.01),
             device_id: None,
+            profile: nav_engine::VehicleProfile::Car,
         };
         assert!(handler.handle(cmd).await.is_err());
     }
@@ -592,6 +1092,7 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
@@ -636,6 +1137,7 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
@@ -673,11 +1175,12 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
         };
-        StopNavigationHandler::new(nav_repo.clone(), event_bus())
+        StopNavigationHandler::new(nav_repo.clone(), update_handler(&nav_repo), event_bus())
             .handle(StopNavigationCommand {
                 session_id: session.id,
                 completed: true,
@@ -688,6 +1191,93 @@ mod tests {
         assert_eq!(loaded.status, NavigationStatus::Completed);
     }
 
+    fn update_handler(nav_repo: &Arc<InMemoryNavigationRepository>) -> Arc<UpdatePositionHandler> {
+        Arc::new(UpdatePositionHandler::new(
+            Arc::new(FailingRouteService),
+            nav_repo.clone(),
+            event_bus(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn stop_navigation_drops_live_session_state() {
+        let nav_repo = repo();
+        let bus = event_bus();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let updates = update_handler(&nav_repo);
+        updates
+            .handle(UpdatePositionCommand {
+                session_id: session.id,
+                position: pos(40.711, -74.01),
+                accuracy_m: Some(5.0),
+                bearing_deg: None,
+                speed_mps: None,
+                timestamp_ms: Some(1_000),
+            })
+            .await
+            .unwrap();
+        updates
+            .reroutes
+            .lock()
+            .unwrap()
+            .insert(session.id, RerouteThrottle::default());
+        updates
+            .candidates
+            .lock()
+            .unwrap()
+            .insert(session.id, session.build_candidate_tracker());
+        assert!(updates.engines.lock().unwrap().contains_key(&session.id));
+
+        StopNavigationHandler::new(nav_repo.clone(), updates.clone(), bus)
+            .handle(StopNavigationCommand {
+                session_id: session.id,
+                completed: false,
+            })
+            .await
+            .unwrap();
+        assert!(!updates.engines.lock().unwrap().contains_key(&session.id));
+        assert!(!updates.candidates.lock().unwrap().contains_key(&session.id));
+        assert!(!updates.reroutes.lock().unwrap().contains_key(&session.id));
+    }
+
+    #[tokio::test]
+    async fn estimate_dead_reckons_from_live_engine() {
+        let nav_repo = repo();
+        let bus = event_bus();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let handler =
+            UpdatePositionHandler::new(Arc::new(FailingRouteService), nav_repo.clone(), bus);
+        let estimate = |timestamp_ms| {
+            handler.handle_estimate(EstimatePositionCommand {
+                session_id: session.id,
+                timestamp_ms,
+            })
+        };
+        assert!(estimate(1_000).unwrap().is_none(), "no fix yet");
+
+        // 10 m/s north, one fix per second.
+        let mut last = None;
+        for t in 0..10u64 {
+            let state = handler
+                .handle(UpdatePositionCommand {
+                    session_id: session.id,
+                    position: pos(40.71 + t as f64 * 0.00009, -74.01),
+                    accuracy_m: Some(5.0),
+                    bearing_deg: Some(0.0),
+                    speed_mps: Some(10.0),
+                    timestamp_ms: Some(t * 1000),
+                })
+                .await
+                .unwrap();
+            last = Some(state);
+        }
+        let before = last.unwrap().distance_remaining_m;
+        let state = estimate(14_000).unwrap().expect("recent fix");
+        assert!(state.estimated);
+        let advanced = before - state.distance_remaining_m;
+        assert!((advanced - 50.0).abs() < 5.0, "advanced {advanced}");
+    }
+
     #[tokio::test]
     async fn stop_navigation_cancelled() {
         let nav_repo = repo();
@@ -702,11 +1292,12 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
         };
-        StopNavigationHandler::new(nav_repo.clone(), event_bus())
+        StopNavigationHandler::new(nav_repo.clone(), update_handler(&nav_repo), event_bus())
             .handle(StopNavigationCommand {
                 session_id: session.id,
                 completed: false,
@@ -717,6 +1308,27 @@ mod tests {
         assert_eq!(loaded.status, NavigationStatus::Cancelled);
     }
 
+    #[tokio::test]
+    async fn start_navigation_persists_profile() {
+        let nav_repo = repo();
+        let session = StartNavigationHandler::new(
+            Arc::new(FixedRouteService(make_route())),
+            nav_repo.clone(),
+            device_comm(),
+            event_bus(),
+        )
+        .handle(StartNavigationCommand {
+            waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
+            current_position: pos(40.71, -74.01),
+            device_id: None,
+            profile: nav_engine::VehicleProfile::Motorcycle,
+        })
+        .await
+        .unwrap();
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.profile, nav_engine::VehicleProfile::Motorcycle);
+    }
+
     // ── UpdatePositionHandler ────────────────────────────────────────────────
 
     #[tokio::test]
@@ -733,20 +1345,109 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
         };
         let new_pos = pos(40.73, -74.00);
-        UpdatePositionHandler::new(nav_repo.clone(), event_bus())
+        UpdatePositionHandler::new(Arc::new(FailingRouteService), nav_repo.clone(), event_bus())
             .handle(UpdatePositionCommand {
                 session_id: session.id,
                 position: new_pos,
+                accuracy_m: None,
+                bearing_deg: None,
+                speed_mps: None,
+                timestamp_ms: None,
             })
             .await
             .unwrap();
         let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
         assert_eq!(loaded.current_position, new_pos);
+        assert!(
+            loaded.progress.is_some(),
+            "matched progress should be persisted"
+        );
+    }
+
+    /// Straight route 0.01° (~1.1 km) north from `lat`,`lon` with a Stop at the end.
+    fn straight_route(lat: f64, lon: f64) -> Route {
+        let mut route = make_route();
+        let coords = vec![
+            geo_types::Coord { x: lon, y: lat },
+            geo_types::Coord {
+                x: lon,
+                y: lat + 0.01,
+            },
+        ];
+        route.segments[0].geometry.polyline =
+            EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap());
+        route.segments[0].waypoints[0].coordinate = Coordinate::new(lat, lon);
+        route.segments[0].waypoints[1].coordinate = Coordinate::new(lat + 0.01, lon);
+        route.metadata.total_distance_m = None;
+        route
+    }
+
+    /// Start a session on a straight ~1.1 km route north from 40.71,-74.01 with a Stop at the end.
+    async fn start_on_straight_route(
+        nav_repo: Arc<InMemoryNavigationRepository>,
+        bus: broadcast::Sender<NavigationEvent>,
+    ) -> NavigationSession {
+        StartNavigationHandler::new(
+            Arc::new(FixedRouteService(straight_route(40.71, -74.01))),
+            nav_repo,
+            device_comm(),
+            bus,
+        )
+        .handle(StartNavigationCommand {
+            waypoints: vec![pos(40.71, -74.01), pos(40.72, -74.01)],
+            current_position: pos(40.71, -74.01),
+            device_id: None,
+            profile: nav_engine::VehicleProfile::Car,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn arrival_publishes_waypoint_reached_and_completes_session() {
+        let nav_repo = repo();
+        let bus = event_bus();
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+
+        let handler = UpdatePositionHandler::new(
+            Arc::new(FailingRouteService),
+            nav_repo.clone(),
+            bus.clone(),
+        );
+        for lat in [40.715, 40.7199] {
+            handler
+                .handle(UpdatePositionCommand {
+                    session_id: session.id,
+                    position: pos(lat, -74.01),
+                    accuracy_m: None,
+                    bearing_deg: None,
+                    speed_mps: None,
+                    timestamp_ms: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let mut reached = vec![];
+        let mut completed = false;
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::WaypointReached { index, .. } => reached.push(index),
+                NavigationEvent::Completed { .. } => completed = true,
+                _ => {}
+            }
+        }
+        assert_eq!(reached, vec![1]);
+        assert!(completed);
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, NavigationStatus::Completed);
     }
 
     // ── GetActiveSessionHandler ──────────────────────────────────────────────
@@ -772,6 +1473,7 @@ mod tests {
                 waypoints: vec![pos(40.71, -74.01), pos(40.76, -73.99)],
                 current_position: pos(40.71, -74.01),
                 device_id: None,
+                profile: nav_engine::VehicleProfile::Car,
             })
             .await
             .unwrap()
@@ -810,4 +1512,554 @@ mod tests {
             .unwrap();
         assert!(result.contains("London"));
     }
+
+    fn fix_at(session_id: uuid::Uuid, lat: f64, lon: f64, t_s: u64) -> UpdatePositionCommand {
+        UpdatePositionCommand {
+            session_id,
+            position: pos(lat, lon),
+            accuracy_m: Some(5.0),
+            bearing_deg: None,
+            speed_mps: None,
+            timestamp_ms: Some(t_s * 1000),
+        }
+    }
+
+    #[tokio::test]
+    async fn confirmed_off_route_reroutes_and_keeps_distance() {
+        let nav_repo = repo();
+        let bus = event_bus();
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), bus);
+
+        handler
+            .handle(fix_at(session.id, 40.715, -74.01, 0))
+            .await
+            .unwrap();
+        let traveled = nav_repo
+            .load_session(session.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .distance_traveled_m;
+        assert!((traveled - 556.0).abs() < 10.0, "{traveled}");
+
+        // ~170 m east of the route: one stray fix is not enough.
+        for t in 1..=2 {
+            let state = handler
+                .handle(fix_at(session.id, 40.715, -74.008, t))
+                .await
+                .unwrap();
+            assert!(!state.off_route.is_off_route);
+        }
+        assert_eq!(service.calls(), 0);
+        let state = handler
+            .handle(fix_at(session.id, 40.715, -74.008, 3))
+            .await
+            .unwrap();
+        assert_eq!(service.calls(), 1);
+        assert!(!state.off_route.is_off_route, "guiding on the new route");
+
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_ne!(loaded.route.id, session.route.id);
+        assert_eq!(loaded.reroute_offset_m, traveled);
+        assert!((loaded.distance_traveled_m - traveled).abs() < 1.0);
+
+        let (mut off_route, mut rerouted) = (0, vec![]);
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::OffRoute { .. } => off_route += 1,
+                NavigationEvent::Rerouted { route_id, .. } => rerouted.push(route_id),
+                _ => {}
+            }
+        }
+        assert_eq!(off_route, 1);
+        assert_eq!(rerouted, vec![loaded.route.id.0]);
+
+        // Progress on the new route adds to the distance traveled before the reroute.
+        handler
+            .handle(fix_at(session.id, 40.72, -74.008, 60))
+            .await
+            .unwrap();
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert!((loaded.distance_traveled_m - traveled - 556.0).abs() < 10.0);
+    }
+
+    #[tokio::test]
+    async fn failed_reroutes_back_off() {
+        let nav_repo = repo();
+        let session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        let service = Arc::new(ReroutingService::new(true));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), event_bus());
+
+        let mut calls_at = vec![];
+        for t in 0..45 {
+            let before = service.calls();
+            handler
+                .handle(fix_at(session.id, 40.715, -74.008, t))
+                .await
+                .unwrap();
+            if service.calls() > before {
+                calls_at.push(t);
+            }
+        }
+        // Confirmed on the third fix, then 10 s and 20 s backoff.
+        assert_eq!(calls_at, vec![2, 12, 32]);
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.route.id, session.route.id);
+    }
+
+    #[tokio::test]
+    async fn alert_only_warns_without_rerouting() {
+        let nav_repo = repo();
+        let bus = event_bus();
+        let mut events = bus.subscribe();
+        let mut session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        session.route.policies.off_route_behavior = OffRouteBehavior::AlertOnly;
+        nav_repo.save_session(&session).await.unwrap();
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), bus);
+
+        for t in 0..5 {
+            handler
+                .handle(fix_at(session.id, 40.715, -74.008, t))
+                .await
+                .unwrap();
+        }
+        assert_eq!(service.calls(), 0);
+        let mut off_route = 0;
+        while let Ok(event) = events.try_recv() {
+            if let NavigationEvent::OffRoute { .. } = event {
+                off_route += 1;
+            }
+        }
+        assert_eq!(off_route, 1, "one warning per off-route episode");
+    }
+
+    #[tokio::test]
+    async fn constraint_alerts_are_sent_once_on_entry_and_exit() {
+        let mut route = straight_route(40.71, -74.01);
+        let coords: Vec<_> = (0..=10)
+            .map(|i| geo_types::Coord {
+                x: -74.01,
+                y: 40.71 + i as f64 * 0.001,
+            })
+            .collect();
+        let segment = &mut route.segments[0];
+        segment.geometry.polyline =
+            EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap());
+        segment.constraints.avoid_tolls = true;
+        segment.constraints.max_speed_kmh = Some(50);
+        segment.road_spans = vec![RoadSpan {
+            vertex_range: VertexRange { start: 4, end: 6 },
+            attribute: RoadAttribute::Toll,
+        }];
+        let nav_repo = repo();
+        let bus = broadcast::channel(64).0;
+        let mut events = bus.subscribe();
+        let session = StartNavigationHandler::new(
+            Arc::new(FixedRouteService(route.clone())),
+            nav_repo.clone(),
+            device_comm(),
+            bus.clone(),
+        )
+        .handle(StartNavigationCommand {
+            waypoints: vec![pos(40.71, -74.01), pos(40.72, -74.01)],
+            current_position: pos(40.71, -74.01),
+            device_id: None,
+            profile: nav_engine::VehicleProfile::Car,
+        })
+        .await
+        .unwrap();
+        let handler = UpdatePositionHandler::new(Arc::new(FixedRouteService(route)), nav_repo, bus);
+
+        // 65 km/h in a 50 zone for the first fixes, then through the toll stretch at 40.
+        for i in 0..16 {
+            let speed_kmh = if i < 4 { 65.0 } else { 40.0 };
+            handler
+                .handle(UpdatePositionCommand {
+                    speed_mps: Some(speed_kmh / 3.6),
+                    timestamp_ms: None,
+                    ..fix_at(session.id, 40.71 + i as f64 * 0.0005, -74.01, 0)
+                })
+                .await
+                .unwrap();
+        }
+
+        let mut alerts = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::ConstraintAlertRaised { alert, .. } => alerts.push((alert, true)),
+                NavigationEvent::ConstraintAlertCleared { alert, .. } => {
+                    alerts.push((alert, false))
+                }
+                _ => {}
+            }
+        }
+        let speeding = nav_engine::ConstraintAlert::Speeding { max_kmh: 50 };
+        let toll = nav_engine::ConstraintAlert::AvoidToll;
+        assert_eq!(
+            alerts,
+            [
+                (speeding, true),
+                (speeding, false),
+                (toll, true),
+                (toll, false)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn driving_an_alternative_switches_the_session_to_it() {
+        let nav_repo = repo();
+        let bus = broadcast::channel(128).0;
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        // Same start, but turns east after ~330 m.
+        let mut alternative = straight_route(40.71, -74.01);
+        let coords = vec![
+            geo_types::Coord {
+                x: -74.01,
+                y: 40.71,
+            },
+            geo_types::Coord {
+                x: -74.01,
+                y: 40.713,
+            },
+            geo_types::Coord {
+                x: -74.005,
+                y: 40.713,
+            },
+        ];
+        alternative.segments[0].geometry.polyline =
+            EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap());
+        alternative.segments[0].waypoints[1].coordinate = Coordinate::new(40.713, -74.005);
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), bus);
+        handler
+            .handle_alternatives(TrackAlternativeRoutesCommand {
+                session_id: session.id,
+                routes: vec![alternative.clone()],
+            })
+            .await
+            .unwrap();
+
+        let ride = (0..=30)
+            .map(|i| (40.71 + i as f64 * 0.0001, -74.01))
+            .chain((1..=20).map(|i| (40.713, -74.01 + i as f64 * 0.00013)));
+        for (t, (lat, lon)) in ride.enumerate() {
+            let state = handler
+                .handle(UpdatePositionCommand {
+                    speed_mps: Some(11.0),
+                    ..fix_at(session.id, lat, lon, t as u64)
+                })
+                .await
+                .unwrap();
+            assert!(!state.off_route.is_off_route, "fix {t}");
+        }
+
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.route.id, alternative.id);
+        assert!(loaded.alternatives.is_empty());
+        assert_eq!(service.calls(), 0, "no reroute while deciding");
+        let mut chosen = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let NavigationEvent::RouteChosen { route_id, .. } = event {
+                chosen.push(route_id);
+            }
+        }
+        assert_eq!(chosen, [alternative.id.0]);
+    }
+
+    #[tokio::test]
+    async fn restarted_handler_resumes_from_the_engine_snapshot() {
+        // Drifts 60 m off the road for two fixes right before the restart: not yet
+        // confirmed off-route, and a fresh engine would have forgotten it.
+        let ride = |session_id, t: u64| UpdatePositionCommand {
+            speed_mps: Some(11.0),
+            ..fix_at(
+                session_id,
+                40.71 + t as f64 * 0.0001,
+                if t == 8 || t == 9 { -74.0093 } else { -74.01 },
+                t,
+            )
+        };
+        let handler_for = |nav_repo: Arc<InMemoryNavigationRepository>| {
+            UpdatePositionHandler::new(
+                Arc::new(ReroutingService::new(false)),
+                nav_repo,
+                broadcast::channel(64).0,
+            )
+        };
+
+        let nav_repo = repo();
+        let session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        let handler = handler_for(nav_repo);
+        let mut expected = Vec::new();
+        for t in 0..20 {
+            expected.push(handler.handle(ride(session.id, t)).await.unwrap());
+        }
+
+        let nav_repo = repo();
+        let session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        let before = handler_for(nav_repo.clone());
+        for t in 0..10 {
+            before.handle(ride(session.id, t)).await.unwrap();
+        }
+        let after = handler_for(nav_repo);
+        for t in 10..20 {
+            let state = after.handle(ride(session.id, t)).await.unwrap();
+            assert_eq!(state, expected[t as usize], "fix {t}");
+        }
+    }
+
+    #[tokio::test]
+    async fn ride_limits_send_low_range_and_break_events_once() {
+        let nav_repo = repo();
+        let bus = broadcast::channel(64).0;
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let handler = UpdatePositionHandler::new(
+            Arc::new(FixedRouteService(straight_route(40.71, -74.01))),
+            nav_repo.clone(),
+            bus,
+        );
+        // 800 m left in the tank for a 1.1 km route, and a break every 30 s.
+        handler
+            .handle_ride_limits(SetRideLimitsCommand {
+                session_id: session.id,
+                vehicle_range_m: Some(5000.0),
+                range_left_m: Some(800.0),
+                break_after_s: Some(30.0),
+            })
+            .await
+            .unwrap();
+        let saved = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(saved.vehicle_range_m, Some(5000.0));
+        assert_eq!(saved.break_after_s, Some(30.0));
+
+        for i in 0..8 {
+            let state = handler
+                .handle(UpdatePositionCommand {
+                    speed_mps: Some(11.0),
+                    ..fix_at(session.id, 40.71 + i as f64 * 0.001, -74.01, i * 10)
+                })
+                .await
+                .unwrap();
+            assert!(state.range.unwrap().low);
+        }
+
+        let mut low_range = Vec::new();
+        let mut breaks = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::LowRange { range_left_m, .. } => low_range.push(range_left_m),
+                NavigationEvent::BreakSuggested { riding_s, .. } => breaks.push(riding_s),
+                _ => {}
+            }
+        }
+        assert_eq!(low_range, [800.0]);
+        assert_eq!(breaks, [30]);
+    }
+
+    #[tokio::test]
+    async fn riding_the_wrong_way_warns_and_reroutes() {
+        let nav_repo = repo();
+        let bus = broadcast::channel(64).0;
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), bus);
+        let ride = |t: u64, lat: f64, bearing_deg: f64| UpdatePositionCommand {
+            bearing_deg: Some(bearing_deg),
+            speed_mps: Some(11.0),
+            ..fix_at(session.id, lat, -74.01, t)
+        };
+
+        for t in 0..4 {
+            handler
+                .handle(ride(t, 40.715 + t as f64 * 0.0001, 0.0))
+                .await
+                .unwrap();
+        }
+        // Turned around on the route: ~11 m back per fix.
+        let mut wrong_way_at = None;
+        for t in 4..8 {
+            let state = handler
+                .handle(ride(t, 40.7153 - (t - 3) as f64 * 0.0001, 180.0))
+                .await
+                .unwrap();
+            assert!(!state.off_route.exceeds_threshold);
+            if service.calls() > 0 && wrong_way_at.is_none() {
+                wrong_way_at = Some(t);
+            }
+        }
+        assert_eq!(
+            wrong_way_at,
+            Some(6),
+            "confirmed on the third fix heading back"
+        );
+        assert_eq!(service.calls(), 1);
+
+        let (mut wrong_way, mut off_route, mut rerouted) = (0, 0, 0);
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::WrongWay { .. } => wrong_way += 1,
+                NavigationEvent::OffRoute { .. } => off_route += 1,
+                NavigationEvent::Rerouted { .. } => rerouted += 1,
+                _ => {}
+            }
+        }
+        assert_eq!((wrong_way, off_route, rerouted), (1, 0, 1));
+    }
+
+    #[tokio::test]
+    async fn wrong_way_on_alert_only_route_shows_u_turn() {
+        let nav_repo = repo();
+        let mut session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        session.route.policies.off_route_behavior = OffRouteBehavior::AlertOnly;
+        nav_repo.save_session(&session).await.unwrap();
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), event_bus());
+
+        let mut state = None;
+        for (t, lat, bearing_deg) in [
+            (0, 40.715, 0.0),
+            (1, 40.7151, 0.0),
+            (2, 40.7150, 180.0),
+            (3, 40.7149, 180.0),
+            (4, 40.7148, 180.0),
+        ] {
+            let fix = UpdatePositionCommand {
+                bearing_deg: Some(bearing_deg),
+                speed_mps: Some(11.0),
+                ..fix_at(session.id, lat, -74.01, t)
+            };
+            state = Some(handler.handle(fix).await.unwrap());
+        }
+        let state = state.unwrap();
+        let wrong_way = state.wrong_way.expect("wrong way");
+        assert_eq!(
+            wrong_way.instruction.kind,
+            nav_engine::DerivedInstructionKind::UTurn
+        );
+        assert!(state.off_route.is_off_route);
+        assert_eq!(service.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn fixed_geometry_offers_connector_instead_of_reroute() {
+        let nav_repo = repo();
+        let mut session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        session.route.segments[0].intent = SegmentIntent::FixedGeometry;
+        nav_repo.save_session(&session).await.unwrap();
+        let service = Arc::new(ReroutingService::new(false));
+        let handler = UpdatePositionHandler::new(service.clone(), nav_repo.clone(), event_bus());
+        let connector = || {
+            handler.handle_rejoin_connector(RequestRejoinConnectorCommand {
+                session_id: session.id,
+            })
+        };
+
+        handler
+            .handle(fix_at(session.id, 40.712, -74.01, 0))
+            .await
+            .unwrap();
+        assert!(connector().await.unwrap().is_none(), "on the track");
+
+        let mut state = None;
+        for t in 1..=3 {
+            state = Some(
+                handler
+                    .handle(fix_at(session.id, 40.715, -74.008, t))
+                    .await
+                    .unwrap(),
+            );
+        }
+        let rejoin = state.unwrap().rejoin.expect("rejoin mode");
+        assert!((rejoin.point.latitude - 40.715).abs() < 1e-4);
+        assert!(connector().await.unwrap().is_some());
+        assert_eq!(service.calls(), 0, "the curated track is never replaced");
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.route.id, session.route.id);
+    }
+
+    #[tokio::test]
+    async fn simulated_ride_runs_event_pipeline_to_arrival() {
+        let nav_repo = repo();
+        // Room for every event of the ride.
+        let bus = broadcast::channel(64).0;
+        let mut events = bus.subscribe();
+        let session = start_on_straight_route(nav_repo.clone(), bus.clone()).await;
+        let handler = SimulateSessionHandler::new(
+            nav_repo.clone(),
+            Arc::new(UpdatePositionHandler::new(
+                Arc::new(FailingRouteService),
+                nav_repo.clone(),
+                bus,
+            )),
+        );
+
+        let fed = handler
+            .handle(SimulateSessionCommand {
+                session_id: session.id,
+                simulator: nav_engine::SimulatorConfig {
+                    speed: nav_engine::SpeedProfile::Constant { speed_mps: 50.0 },
+                    noise_m: 3.0,
+                    ..Default::default()
+                },
+                time_scale: 1_000.0,
+            })
+            .await
+            .unwrap();
+        assert!((20..=25).contains(&fed), "{fed}");
+
+        let (mut positions, mut completed) = (0, false);
+        while let Ok(event) = events.try_recv() {
+            match event {
+                NavigationEvent::PositionUpdated { .. } => positions += 1,
+                NavigationEvent::Completed { .. } => completed = true,
+                _ => {}
+            }
+        }
+        assert_eq!(positions, fed);
+        assert!(completed);
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, NavigationStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn simulation_can_be_stopped() {
+        let nav_repo = repo();
+        let session = start_on_straight_route(nav_repo.clone(), event_bus()).await;
+        let handler = Arc::new(SimulateSessionHandler::new(
+            nav_repo.clone(),
+            Arc::new(UpdatePositionHandler::new(
+                Arc::new(FailingRouteService),
+                nav_repo.clone(),
+                event_bus(),
+            )),
+        ));
+        assert!(!handler.stop(session.id), "nothing running yet");
+
+        let run = tokio::spawn({
+            let handler = Arc::clone(&handler);
+            async move {
+                handler
+                    .handle(SimulateSessionCommand {
+                        session_id: session.id,
+                        simulator: Default::default(),
+                        time_scale: 20.0,
+                    })
+                    .await
+            }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(120)).await;
+        assert!(handler.stop(session.id));
+        let fed = run.await.unwrap().unwrap();
+        assert!((1..10).contains(&fed), "{fed}");
+        let loaded = nav_repo.load_session(session.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, NavigationStatus::Active);
+    }
 }
@@ -1,5 +1,6 @@
 // Domain Events — typed enum published to the broadcast event bus.
 use crate::shared::value_objects::Position;
+use nav_engine::ConstraintAlert;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -30,4 +31,45 @@ pub enum NavigationEvent {
     OffRoute {
         session_id: Uuid,
     },
+    /// The rider is following the route backwards and should make a U-turn.
+    WrongWay {
+        session_id: Uuid,
+    },
+    /// The rider started breaking a route constraint (speeding, on an avoided toll,
+    /// highway or unpaved stretch). Sent once when the alert is raised.
+    ConstraintAlertRaised {
+        session_id: Uuid,
+        alert: ConstraintAlert,
+    },
+    /// A previously raised constraint alert no longer applies.
+    ConstraintAlertCleared {
+        session_id: Uuid,
+        alert: ConstraintAlert,
+    },
+    /// The fuel range left no longer reaches the next Fuel waypoint (or the destination, with
+    /// none left). Sent once per episode.
+    LowRange {
+        session_id: Uuid,
+        range_left_m: f64,
+        /// Next Fuel waypoint ahead, if any.
+        next_fuel_index: Option<usize>,
+    },
+    /// The rider has been riding long enough to take a break. Sent once per stint.
+    BreakSuggested {
+        session_id: Uuid,
+        riding_s: u64,
+        /// Next Break waypoint ahead, if any.
+        next_break_index: Option<usize>,
+    },
+    /// The session switched to a recalculated route after going off-route.
+    Rerouted {
+        session_id: Uuid,
+        route_id: Uuid,
+    },
+    /// The rider's driving settled which of the offered routes the session follows; the
+    /// session now holds `route_id` as its route.
+    RouteChosen {
+        session_id: Uuid,
+        route_id: Uuid,
+    },
 }
@@ -1,3 +1,4 @@
 pub mod events;
 pub mod ports;
+pub mod reroute;
 pub mod session;
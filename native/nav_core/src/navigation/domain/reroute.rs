@@ -0,0 +1,69 @@
+// Reroute rate limiting — how often an off-route session may ask the routing service for a
+// new route.
+
+/// Minimum time between two reroute requests for one session (ms).
+const MIN_INTERVAL_MS: u64 = 10_000;
+/// Longest wait after repeated failures (ms).
+const MAX_BACKOFF_MS: u64 = 120_000;
+
+/// Rate limiter for automatic reroutes.
+///
+/// Requests are at least `MIN_INTERVAL_MS` apart. Each failed request doubles the wait
+/// (10 s, 20 s, 40 s, … up to 2 min) so a dead routing backend isn't hammered while the rider
+/// stays off-route; a success resets it.
+#[derive(Debug, Clone, Default)]
+pub struct RerouteThrottle {
+    last_attempt_ms: Option<u64>,
+    failures: u32,
+}
+
+impl RerouteThrottle {
+    /// Whether a reroute may be requested at `now_ms`.
+    pub fn ready(&self, now_ms: u64) -> bool {
+        self.last_attempt_ms
+            .is_none_or(|last| now_ms.saturating_sub(last) >= self.wait_ms())
+    }
+
+    /// Record a request made at `now_ms` and whether it produced a route.
+    pub fn record(&mut self, now_ms: u64, succeeded: bool) {
+        self.last_attempt_ms = Some(now_ms);
+        self.failures = if succeeded { 0 } else { self.failures + 1 };
+    }
+
+    fn wait_ms(&self) -> u64 {
+        let backoff = MIN_INTERVAL_MS.saturating_mul(1 << self.failures.saturating_sub(1).min(16));
+        backoff.min(MAX_BACKOFF_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_request_is_allowed() {
+        assert!(RerouteThrottle::default().ready(0));
+    }
+
+    #[test]
+    fn requests_are_spaced_after_success() {
+        let mut t = RerouteThrottle::default();
+        t.record(1_000, true);
+        assert!(!t.ready(5_000));
+        assert!(t.ready(11_000));
+    }
+
+    #[test]
+    fn failures_back_off_exponentially_up_to_cap() {
+        let mut t = RerouteThrottle::default();
+        let mut now = 0;
+        for expected in [10_000, 20_000, 40_000, 80_000, 120_000, 120_000] {
+            t.record(now, false);
+            assert!(!t.ready(now + expected - 1), "{expected}");
+            assert!(t.ready(now + expected), "{expected}");
+            now += expected;
+        }
+        t.record(now, true);
+        assert!(t.ready(now + 10_000));
+    }
+}
@@ -2,6 +2,10 @@
 // Domain Entities - Core business objects with identity
 use crate::shared::value_objects::*;
 use chrono::{DateTime, Utc};
+use nav_engine::{
+    CandidateTracker, DerivedInstructionKind, EngineConfig, EngineSnapshot, InstructionText,
+    NavigationEngine, Phrase, RouteProgress, VehicleProfile,
+};
 use nav_ir::Route as NavIrRoute;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -29,6 +33,40 @@ pub struct NavigationSession {
     /// Cumulative distance traveled in meters, updated by `nav_engine`.
     #[serde(default)]
     pub distance_traveled_m: f64,
+    /// Vehicle profile selecting the `nav_engine` threshold preset for this session.
+    #[serde(default)]
+    pub profile: VehicleProfile,
+    /// Matched position on the route polyline (segment + fraction), restored exactly on resume.
+    #[serde(default)]
+    pub progress: Option<RouteProgress>,
+    /// Distance traveled on routes replaced by reroutes; `distance_traveled_m` includes it.
+    #[serde(default)]
+    pub reroute_offset_m: f64,
+    /// Full `nav_engine` state after the last fix (filter, off-route counters, announced
+    /// prompts, passed waypoints), so a resumed session continues exactly where it stopped.
+    #[serde(default)]
+    pub engine_snapshot: Option<EngineSnapshot>,
+    /// Other routes offered alongside `route`. While any are left, fixes are matched against
+    /// all of them and the session switches to whichever one the rider turns out to drive.
+    #[serde(default)]
+    pub alternatives: Vec<NavIrRoute>,
+    /// Distance on a full tank, for range warnings (meters); `None` disables them.
+    #[serde(default)]
+    pub vehicle_range_m: Option<f64>,
+    /// Riding time before a break is suggested (seconds); `None` keeps the profile default.
+    #[serde(default)]
+    pub break_after_s: Option<f64>,
+    /// The instruction after the current step, recorded from the live engine with each fix;
+    /// `None` until the first fix on the current route.
+    #[serde(default)]
+    pub next_turn: Option<NextTurn>,
+}
+
+/// What `next_turn_text` says: an instruction kind and the street it leads onto.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NextTurn {
+    pub kind: DerivedInstructionKind,
+    pub street_name: Option<String>,
 }
 
 impl NavigationSession {
@@ -43,6 +81,141 @@ impl NavigationSession {
             updated_at: now,
             current_step_index: 0,
             distance_traveled_m: 0.0,
+            profile: VehicleProfile::default(),
+            progress: None,
+            reroute_offset_m: 0.0,
+            engine_snapshot: None,
+            alternatives: Vec::new(),
+            vehicle_range_m: None,
+            break_after_s: None,
+            next_turn: None,
+        }
+    }
+
+    /// Builder-style setter for the vehicle profile.
+    pub fn with_profile(mut self, profile: VehicleProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Engine thresholds: the profile preset with the session's vehicle range and break
+    /// interval.
+    pub fn engine_config(&self) -> EngineConfig {
+        let mut config = EngineConfig::for_profile(self.profile);
+        config.vehicle_range_m = self.vehicle_range_m;
+        if let Some(break_after_s) = self.break_after_s {
+            config.break_after_s = Some(break_after_s);
+        }
+        config
+    }
+
+    /// Build a `NavigationEngine` for this session, restored from the engine snapshot when it
+    /// fits the current route, else from the persisted step, distance and route progress.
+    pub fn build_engine(&self) -> NavigationEngine {
+        let config = self.engine_config();
+        if let Some(snapshot) = &self.engine_snapshot {
+            if let Ok(engine) = NavigationEngine::from_snapshot(
+                self.route.clone(),
+                config.clone(),
+                snapshot.clone(),
+            ) {
+                return engine;
+            }
+        }
+        let engine = NavigationEngine::new_with_state(
+            self.route.clone(),
+            config,
+            self.current_step_index,
+            self.distance_traveled_m - self.reroute_offset_m,
+        )
+        .with_traveled_offset(self.reroute_offset_m);
+        match self.progress {
+            Some(progress) => engine.with_progress(progress),
+            None => engine,
+        }
+    }
+
+    /// Track `route` and the alternatives together: the session's engine as it stands, plus a
+    /// fresh engine per alternative that counts the distance traveled, fuel used and time
+    /// ridden so far.
+    pub fn build_candidate_tracker(&self) -> CandidateTracker {
+        let config = self.engine_config();
+        let current = self.build_engine();
+        let stops = current.stops().clone();
+        let engines = std::iter::once(current)
+            .chain(self.alternatives.iter().map(|route| {
+                NavigationEngine::new(route.clone(), config.clone())
+                    .with_traveled_offset(self.distance_traveled_m)
+                    .with_stops(stops.clone())
+            }))
+            .collect();
+        CandidateTracker::from_engines(engines).expect("the session route is a candidate")
+    }
+
+    /// Copy the live engine's progress into the session after a fix: step, distance, matched
+    /// position, snapshot and the next turn.
+    pub fn record_engine(&mut self, engine: &NavigationEngine) {
+        self.current_step_index = engine.current_step();
+        self.distance_traveled_m = engine.distance_traveled_m();
+        self.progress = engine.progress();
+        self.engine_snapshot = Some(engine.snapshot());
+        let instructions = engine.instructions();
+        self.next_turn = instructions
+            .get(self.current_step_index + 1)
+            .or(instructions.last())
+            .map(|next| NextTurn {
+                kind: next.kind,
+                street_name: next.street_name.clone(),
+            });
+    }
+
+    /// Text for the instruction after the current step (e.g. "Turn left onto Main Street"),
+    /// as shown on the device's route summary. "Continue" until the first fix.
+    pub fn next_turn_text(&self, text: InstructionText) -> String {
+        match &self.next_turn {
+            Some(next) => text.phrase(&Phrase::new(next.kind).street(next.street_name.as_deref())),
+            None => text.phrase(&Phrase::new(DerivedInstructionKind::Continue)),
+        }
+    }
+
+    /// Replace the route after a reroute. Distance traveled so far is kept; progress on the
+    /// new route starts from its beginning.
+    pub fn reroute(&mut self, route: NavIrRoute) {
+        self.route = route;
+        self.alternatives.clear();
+        self.reroute_offset_m = self.distance_traveled_m;
+        self.current_step_index = 0;
+        self.progress = None;
+        self.engine_snapshot = None;
+        self.next_turn = None;
+        self.updated_at = Utc::now();
+    }
+
+    /// Set the vehicle's full range and break interval (see `engine_config`).
+    pub fn set_ride_limits(&mut self, vehicle_range_m: Option<f64>, break_after_s: Option<f64>) {
+        self.vehicle_range_m = vehicle_range_m;
+        self.break_after_s = break_after_s;
+        self.updated_at = Utc::now();
+    }
+
+    /// Offer `routes` as alternatives to the current route; the rider's fixes pick one.
+    pub fn offer_alternatives(&mut self, routes: Vec<NavIrRoute>) {
+        self.alternatives = routes;
+        self.updated_at = Utc::now();
+    }
+
+    /// Settle on candidate `index` of `build_candidate_tracker` (0 keeps the current route).
+    /// Choosing an alternative replaces the route like a reroute.
+    pub fn choose_route(&mut self, index: usize) {
+        match index.checked_sub(1) {
+            Some(i) if i < self.alternatives.len() => {
+                let route = self.alternatives.swap_remove(i);
+                self.reroute(route);
+            }
+            _ => {
+                self.alternatives.clear();
+                self.updated_at = Utc::now();
+            }
         }
     }
 
@@ -227,6 +400,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: nav_ir::SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: nav_ir::RoutePolicies::default(),
         }
@@ -244,6 +419,36 @@ mod tests {
         assert_eq!(s.status, NavigationStatus::Active);
     }
 
+    #[test]
+    fn next_turn_text_is_localized() {
+        let mut s = NavigationSession::new(make_route(), pos(40.71, -74.01));
+        assert_eq!(
+            s.next_turn_text(InstructionText::default()),
+            "Continue straight"
+        );
+        s.record_engine(&s.build_engine());
+        assert_eq!(
+            s.next_turn_text(InstructionText::default()),
+            "You have arrived at your destination"
+        );
+        let nl = InstructionText::new(nav_engine::Locale::Nl, nav_engine::UnitSystem::Metric);
+        assert_eq!(s.next_turn_text(nl), "U bent op uw bestemming aangekomen");
+    }
+
+    #[test]
+    fn reroute_onto_same_route_id_drops_engine_snapshot() {
+        let route = make_route();
+        let mut s = NavigationSession::new(route.clone(), pos(40.71, -74.01));
+        s.record_engine(&s.build_engine());
+        assert!(s.engine_snapshot.is_some());
+        s.distance_traveled_m = 120.0;
+        s.reroute(route);
+        assert!(s.engine_snapshot.is_none());
+        let engine = s.build_engine();
+        assert_eq!(engine.current_step(), 0);
+        assert!((engine.distance_traveled_m() - 120.0).abs() < 1e-9);
+    }
+
     #[test]
     fn session_pause_resume() {
         let mut s = NavigationSession::new(make_route(), pos(40.71, -74.01));
@@ -268,6 +473,14 @@ mod tests {
         assert_eq!(s.current_position, new_pos);
     }
 
+    #[test]
+    fn session_defaults_to_car_profile() {
+        let s = NavigationSession::new(make_route(), pos(40.71, -74.01));
+        assert_eq!(s.profile, VehicleProfile::Car);
+        let s = s.with_profile(VehicleProfile::Bicycle);
+        assert_eq!(s.build_engine().config().profile, VehicleProfile::Bicycle);
+    }
+
     #[test]
     fn session_updated_at_changes_on_mutation() {
         let mut s = NavigationSession::new(make_route(), pos(40.71, -74.01));
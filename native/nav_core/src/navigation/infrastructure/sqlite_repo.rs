@@ -26,13 +26,33 @@ impl NavigationRepository for SqliteNavigationRepository {
     async fn save_session(&self, session: &NavigationSession) -> Result<()> {
         let route_json =
             serde_json::to_string(&session.route).context("Failed to serialize route")?;
+        let engine_snapshot_json = session
+            .engine_snapshot
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed to serialize engine snapshot")?;
+        let alternatives_json = (!session.alternatives.is_empty())
+            .then(|| serde_json::to_string(&session.alternatives))
+            .transpose()
+            .context("Failed to serialize alternative routes")?;
+        let next_turn_json = session
+            .next_turn
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed to serialize next turn")?;
         let status = status_to_str(session.status);
         let conn = self.db.lock().unwrap();
         conn.execute(
             "INSERT INTO navigation_sessions
                  (id, route_json, current_lat, current_lon, status, started_at, updated_at,
-                  current_step_index, distance_traveled_m)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                  current_step_index, distance_traveled_m, vehicle_profile,
+                  progress_segment_index, progress_fraction, reroute_offset_m,
+                  engine_snapshot_json, alternatives_json, vehicle_range_m, break_after_s,
+                  next_turn_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                     ?18)
              ON CONFLICT(id) DO UPDATE SET
                  route_json          = excluded.route_json,
                  current_lat         = excluded.current_lat,
@@ -40,7 +60,16 @@ impl NavigationRepository for SqliteNavigationRepository {
                  status              = excluded.status,
                  updated_at          = excluded.updated_at,
                  current_step_index  = excluded.current_step_index,
-                 distance_traveled_m = excluded.distance_traveled_m",
+                 distance_traveled_m = excluded.distance_traveled_m,
+                 vehicle_profile     = excluded.vehicle_profile,
+                 progress_segment_index = excluded.progress_segment_index,
+                 progress_fraction      = excluded.progress_fraction,
+                 reroute_offset_m       = excluded.reroute_offset_m,
+                 engine_snapshot_json   = excluded.engine_snapshot_json,
+                 alternatives_json      = excluded.alternatives_json,
+                 vehicle_range_m        = excluded.vehicle_range_m,
+                 break_after_s          = excluded.break_after_s,
+                 next_turn_json         = excluded.next_turn_json",
             params![
                 session.id.to_string(),
                 route_json,
@@ -51,6 +80,15 @@ impl NavigationRepository for SqliteNavigationRepository {
                 session.updated_at.timestamp(),
                 session.current_step_index as i64,
                 session.distance_traveled_m,
+                session.profile.as_str(),
+                session.progress.map(|p| p.segment_index as i64),
+                session.progress.map(|p| p.fraction),
+                session.reroute_offset_m,
+                engine_snapshot_json,
+                alternatives_json,
+                session.vehicle_range_m,
+                session.break_after_s,
+                next_turn_json,
             ],
         )
         .context("Failed to save navigation session")?;
@@ -60,9 +98,7 @@ impl NavigationRepository for SqliteNavigationRepository {
     async fn load_session(&self, id: Uuid) -> Result<Option<NavigationSession>> {
         let conn = self.db.lock().unwrap();
         let result = conn.query_row(
-            "SELECT id, route_json, current_lat, current_lon, status, started_at, updated_at,
-                    COALESCE(current_step_index, 0), COALESCE(distance_traveled_m, 0.0)
-             FROM navigation_sessions WHERE id = ?",
+            &format!("SELECT {SESSION_COLUMNS} FROM navigation_sessions WHERE id = ?"),
             [id.to_string()],
             extract_row,
         );
@@ -76,9 +112,9 @@ impl NavigationRepository for SqliteNavigationRepository {
     async fn load_active_session(&self) -> Result<Option<NavigationSession>> {
         let conn = self.db.lock().unwrap();
         let result = conn.query_row(
-            "SELECT id, route_json, current_lat, current_lon, status, started_at, updated_at,
-                    COALESCE(current_step_index, 0), COALESCE(distance_traveled_m, 0.0)
-             FROM navigation_sessions WHERE status = 'Active' LIMIT 1",
+            &format!(
+                "SELECT {SESSION_COLUMNS} FROM navigation_sessions WHERE status = 'Active' LIMIT 1"
+            ),
             [],
             extract_row,
         );
@@ -129,40 +165,73 @@ impl NavigationRepository for SqliteNavigationRepository {
 
 // ── helpers ──────────────────────────────────────────────────────────────────
 
-type RawRow = (String, String, f64, f64, String, i64, i64, i64, f64);
+const SESSION_COLUMNS: &str = "id, route_json, current_lat, current_lon, status, started_at,
+    updated_at, COALESCE(current_step_index, 0), COALESCE(distance_traveled_m, 0.0),
+    COALESCE(vehicle_profile, 'car'), progress_segment_index, progress_fraction,
+    COALESCE(reroute_offset_m, 0.0), engine_snapshot_json, alternatives_json,
+    vehicle_range_m, break_after_s, next_turn_json";
+
+/// Raw column values of one `navigation_sessions` row, in `SESSION_COLUMNS` order.
+struct RawRow {
+    id: String,
+    route_json: String,
+    lat: f64,
+    lon: f64,
+    status: String,
+    started_ts: i64,
+    updated_ts: i64,
+    step_idx: i64,
+    dist_m: f64,
+    profile: String,
+    progress_segment: Option<i64>,
+    progress_fraction: Option<f64>,
+    reroute_offset_m: f64,
+    engine_snapshot_json: Option<String>,
+    alternatives_json: Option<String>,
+    vehicle_range_m: Option<f64>,
+    break_after_s: Option<f64>,
+    next_turn_json: Option<String>,
+}
 
 fn extract_row(row: &rusqlite::Row) -> rusqlite::Result<RawRow> {
-    Ok((
-        row.get(0)?,
-        row.get(1)?,
-        row.get(2)?,
-        row.get(3)?,
-        row.get(4)?,
-        row.get(5)?,
-        row.get(6)?,
-        row.get(7)?,
-        row.get(8)?,
-    ))
+    Ok(RawRow {
+        id: row.get(0)?,
+        route_json: row.get(1)?,
+        lat: row.get(2)?,
+        lon: row.get(3)?,
+        status: row.get(4)?,
+        started_ts: row.get(5)?,
+        updated_ts: row.get(6)?,
+        step_idx: row.get(7)?,
+        dist_m: row.get(8)?,
+        profile: row.get(9)?,
+        progress_segment: row.get(10)?,
+        progress_fraction: row.get(11)?,
+        reroute_offset_m: row.get(12)?,
+        engine_snapshot_json: row.get(13)?,
+        alternatives_json: row.get(14)?,
+        vehicle_range_m: row.get(15)?,
+        break_after_s: row.get(16)?,
+        next_turn_json: row.get(17)?,
+    })
 }
 
-fn deserialize_session(
-    (id_str, route_json, lat, lon, status_str, started_ts, updated_ts, step_idx, dist_m): RawRow,
-) -> Result<NavigationSession> {
-    let id = Uuid::parse_str(&id_str).context("Invalid session UUID")?;
-    let route = serde_json::from_str(&route_json).context("Failed to deserialize route")?;
-    let current_position = Position::new(lat, lon)?;
-    let status = match status_str.as_str() {
+fn deserialize_session(raw: RawRow) -> Result<NavigationSession> {
+    let id = Uuid::parse_str(&raw.id).context("Invalid session UUID")?;
+    let route = serde_json::from_str(&raw.route_json).context("Failed to deserialize route")?;
+    let current_position = Position::new(raw.lat, raw.lon)?;
+    let status = match raw.status.as_str() {
         "Paused" => NavigationStatus::Paused,
         "Completed" => NavigationStatus::Completed,
         "Cancelled" => NavigationStatus::Cancelled,
         _ => NavigationStatus::Active,
     };
     let started_at = chrono::Utc
-        .timestamp_opt(started_ts, 0)
+        .timestamp_opt(raw.started_ts, 0)
         .single()
         .unwrap_or_else(chrono::Utc::now);
     let updated_at = chrono::Utc
-        .timestamp_opt(updated_ts, 0)
+        .timestamp_opt(raw.updated_ts, 0)
         .single()
         .unwrap_or_else(chrono::Utc::now);
 
@@ -173,8 +242,33 @@ fn deserialize_session(
         status,
         started_at,
         updated_at,
-        current_step_index: step_idx.max(0) as usize,
-        distance_traveled_m: dist_m,
+        current_step_index: raw.step_idx.max(0) as usize,
+        distance_traveled_m: raw.dist_m,
+        profile: raw.profile.parse().unwrap_or_default(),
+        progress: match (raw.progress_segment, raw.progress_fraction) {
+            (Some(segment), Some(fraction)) => Some(nav_engine::RouteProgress {
+                segment_index: segment.max(0) as usize,
+                fraction,
+            }),
+            _ => None,
+        },
+        reroute_offset_m: raw.reroute_offset_m,
+        // A snapshot this build can't read only costs the finer state: the session still
+        // resumes from step, distance and progress.
+        engine_snapshot: raw
+            .engine_snapshot_json
+            .and_then(|json| serde_json::from_str(&json).ok()),
+        alternatives: raw
+            .alternatives_json
+            .map(|json| serde_json::from_str(&json))
+            .transpose()
+            .context("Failed to deserialize alternative routes")?
+            .unwrap_or_default(),
+        vehicle_range_m: raw.vehicle_range_m,
+        break_after_s: raw.break_after_s,
+        next_turn: raw
+            .next_turn_json
+            .and_then(|json| serde_json::from_str(&json).ok()),
     })
 }
 
@@ -193,6 +287,7 @@ fn status_to_str(status: NavigationStatus) -> &'static str {
 mod tests {
     use super::*;
     use crate::migrations::{get_all_migrations, MigrationManager};
+    use crate::navigation::domain::session::NextTurn;
     use chrono::Utc;
     use nav_ir::*;
 
@@ -258,6 +353,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         }
@@ -280,6 +377,140 @@ mod tests {
         assert_eq!(loaded.unwrap().id, id);
     }
 
+    #[tokio::test]
+    async fn vehicle_profile_round_trips() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let s =
+            make_session(NavigationStatus::Active).with_profile(nav_engine::VehicleProfile::Foot);
+        let id = s.id;
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(id).await.unwrap().unwrap();
+        assert_eq!(loaded.profile, nav_engine::VehicleProfile::Foot);
+    }
+
+    #[tokio::test]
+    async fn route_progress_round_trips() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        repo.save_session(&s).await.unwrap();
+        assert_eq!(
+            repo.load_session(s.id).await.unwrap().unwrap().progress,
+            None
+        );
+
+        let progress = nav_engine::RouteProgress {
+            segment_index: 3,
+            fraction: 0.4375,
+        };
+        s.progress = Some(progress);
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.progress, Some(progress));
+    }
+
+    #[tokio::test]
+    async fn reroute_offset_round_trips() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        s.reroute_offset_m = 1234.5;
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.reroute_offset_m, 1234.5);
+    }
+
+    #[tokio::test]
+    async fn street_names_round_trip() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        let streets = vec![nav_ir::StreetSpan {
+            vertex_range: nav_ir::VertexRange { start: 0, end: 1 },
+            name: "Broadway".to_string(),
+        }];
+        s.route.segments[0].street_names = streets.clone();
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.route.segments[0].street_names, streets);
+    }
+
+    #[tokio::test]
+    async fn engine_snapshot_round_trips() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        let mut engine = s.build_engine();
+        engine.update_position(nav_ir::Coordinate::new(40.72, -74.0), Some(12.0));
+        let snapshot = engine.snapshot();
+        s.engine_snapshot = Some(snapshot.clone());
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.engine_snapshot, Some(snapshot));
+    }
+
+    #[tokio::test]
+    async fn unreadable_engine_snapshot_is_dropped() {
+        let db = setup_db();
+        let repo = SqliteNavigationRepository::new(db.clone());
+        let s = make_session(NavigationStatus::Active);
+        repo.save_session(&s).await.unwrap();
+        db.lock()
+            .unwrap()
+            .execute(
+                "UPDATE navigation_sessions SET engine_snapshot_json = '{\"version\":99}'",
+                [],
+            )
+            .unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert!(loaded.engine_snapshot.is_none());
+    }
+
+    #[tokio::test]
+    async fn alternative_routes_round_trip() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        repo.save_session(&s).await.unwrap();
+        assert!(repo
+            .load_session(s.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .alternatives
+            .is_empty());
+        let mut alternative = s.route.clone();
+        alternative.id = nav_ir::RouteId::new();
+        s.alternatives = vec![alternative.clone()];
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.alternatives.len(), 1);
+        assert_eq!(loaded.alternatives[0].id, alternative.id);
+    }
+
+    #[tokio::test]
+    async fn ride_limits_round_trip() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.vehicle_range_m, None);
+        assert_eq!(loaded.break_after_s, None);
+        s.set_ride_limits(Some(280_000.0), Some(5400.0));
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.vehicle_range_m, Some(280_000.0));
+        assert_eq!(loaded.break_after_s, Some(5400.0));
+    }
+
+    #[tokio::test]
+    async fn next_turn_round_trip() {
+        let repo = SqliteNavigationRepository::new(setup_db());
+        let mut s = make_session(NavigationStatus::Active);
+        s.next_turn = Some(NextTurn {
+            kind: nav_engine::DerivedInstructionKind::TurnLeft,
+            street_name: Some("Main Street".to_string()),
+        });
+        repo.save_session(&s).await.unwrap();
+        let loaded = repo.load_session(s.id).await.unwrap().unwrap();
+        assert_eq!(loaded.next_turn, s.next_turn);
+    }
+
     #[tokio::test]
     async fn load_unknown_session_returns_none() {
         let repo = SqliteNavigationRepository::new(setup_db());
@@ -106,9 +106,10 @@ impl PlacesHandlers {
         self.routes_repo.get_by_id(q.id)
     }
 
-    /// Parse GPX bytes into a Nav-IR route without persisting (preview flow).
+    /// Parse GPX bytes into a Nav-IR route without persisting (preview flow). Recorded tracks
+    /// are digested into turns and stops (`nav_ir::import_gpx`).
     pub fn parse_route_from_gpx(&self, q: ParseRouteFromGpxQuery) -> Result<nav_ir::Route> {
-        nav_ir::normalize_gpx(&q.bytes).map_err(|e| anyhow::anyhow!("{}", e))
+        nav_ir::import_gpx(&q.bytes).map_err(|e| anyhow::anyhow!("{}", e))
     }
 
     /// Validate and persist a pre-parsed Nav-IR JSON string. Returns the saved row.
@@ -129,12 +130,13 @@ impl PlacesHandlers {
             .context("Saved route not found after insert")
     }
 
-    /// Parse GPX bytes, persist the route, and return the saved row.
+    /// Parse GPX bytes (digesting recorded tracks, as in the preview), persist the route, and
+    /// return the saved row.
     pub fn import_route_from_gpx(
         &self,
         cmd: ImportRouteFromGpxCommand,
     ) -> Result<SavedRouteEntity> {
-        let route = nav_ir::normalize_gpx(&cmd.bytes).map_err(|e| anyhow::anyhow!("{}", e))?;
+        let route = nav_ir::import_gpx(&cmd.bytes).map_err(|e| anyhow::anyhow!("{}", e))?;
         let route_json = serde_json::to_string(&route)?;
         let entity = SavedRouteEntity {
             id: None,
@@ -20,11 +20,79 @@ pub struct NavigationStateDto {
     pub distance_to_next_m: f64,
     pub distance_remaining_m: f64,
     pub eta_seconds: u64,
+    pub eta_low_seconds: u64,
+    pub eta_high_seconds: u64,
     pub is_off_route: bool,
     pub distance_from_route_m: f64,
     pub snapped_lat: f64,
     pub snapped_lon: f64,
     pub constraint_alerts: Vec<String>,
+    pub raised_alerts: Vec<String>,
+    pub speed_limit_kmh: Option<u32>,
+    pub active_segment: u32,
+    pub segment_intent: Option<String>,
+    pub next_waypoint_index: Option<u32>,
+    pub next_waypoint_distance_m: Option<f64>,
+    pub next_waypoint_eta_seconds: Option<u64>,
+    pub waypoints_passed: u32,
+    pub waypoint_etas: Vec<WaypointEtaDto>,
+    pub leg_etas: Vec<LegEtaDto>,
+    pub arrived: bool,
+    pub announcements: Vec<AnnouncementDto>,
+    pub estimated: bool,
+    pub rejoin: Option<RejoinDto>,
+    pub wrong_way: Option<WrongWayDto>,
+    pub range_left_m: Option<f64>,
+    pub low_range: bool,
+    pub next_fuel_index: Option<u32>,
+    pub next_fuel_distance_m: Option<f64>,
+    pub break_due_riding_s: Option<u64>,
+    pub next_break_index: Option<u32>,
+    pub next_break_distance_m: Option<f64>,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct WrongWayDto {
+    pub instruction: DerivedInstructionDto,
+    pub distance_m: f64,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct WaypointEtaDto {
+    pub index: u32,
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct LegEtaDto {
+    pub index: u32,
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct RejoinDto {
+    pub lat: f64,
+    pub lon: f64,
+    pub distance_m: f64,
+    pub bearing_deg: f64,
+    pub direction: Option<String>,
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct AnnouncementDto {
+    pub stage: String,
+    pub step: u32,
+    pub kind: String,
+    pub street_name: Option<String>,
+    pub distance_m: f64,
+    pub then_kind: Option<String>,
 }
 
 #[frb]
@@ -100,6 +168,18 @@ pub struct GeocodingResultDto {
     pub osm_id: Option<i64>,
 }
 
+// ============================================================================
+// Device Communication
+// ============================================================================
+
+/// Protobuf bytes to write to the BLE peripheral `device_id`.
+#[frb]
+#[derive(Debug, Clone)]
+pub struct DeviceMessageDto {
+    pub device_id: String,
+    pub bytes: Vec<u8>,
+}
+
 // ============================================================================
 // Conversions from nav_core DTOs
 // ============================================================================
@@ -123,11 +203,89 @@ impl From<nav_core::api::dto::NavigationStateDto> for NavigationStateDto {
             distance_to_next_m: s.distance_to_next_m,
             distance_remaining_m: s.distance_remaining_m,
             eta_seconds: s.eta_seconds,
+            eta_low_seconds: s.eta_low_seconds,
+            eta_high_seconds: s.eta_high_seconds,
             is_off_route: s.is_off_route,
             distance_from_route_m: s.distance_from_route_m,
             snapped_lat: s.snapped_lat,
             snapped_lon: s.snapped_lon,
             constraint_alerts: s.constraint_alerts,
+            raised_alerts: s.raised_alerts,
+            speed_limit_kmh: s.speed_limit_kmh,
+            active_segment: s.active_segment,
+            segment_intent: s.segment_intent,
+            next_waypoint_index: s.next_waypoint_index,
+            next_waypoint_distance_m: s.next_waypoint_distance_m,
+            next_waypoint_eta_seconds: s.next_waypoint_eta_seconds,
+            waypoints_passed: s.waypoints_passed,
+            waypoint_etas: s.waypoint_etas.into_iter().map(Into::into).collect(),
+            leg_etas: s.leg_etas.into_iter().map(Into::into).collect(),
+            arrived: s.arrived,
+            announcements: s.announcements.into_iter().map(Into::into).collect(),
+            estimated: s.estimated,
+            rejoin: s.rejoin.map(Into::into),
+            wrong_way: s.wrong_way.map(Into::into),
+            range_left_m: s.range_left_m,
+            low_range: s.low_range,
+            next_fuel_index: s.next_fuel_index,
+            next_fuel_distance_m: s.next_fuel_distance_m,
+            break_due_riding_s: s.break_due_riding_s,
+            next_break_index: s.next_break_index,
+            next_break_distance_m: s.next_break_distance_m,
+        }
+    }
+}
+
+impl From<nav_core::api::dto::AnnouncementDto> for AnnouncementDto {
+    fn from(a: nav_core::api::dto::AnnouncementDto) -> Self {
+        Self {
+            stage: a.stage,
+            step: a.step,
+            kind: a.kind,
+            street_name: a.street_name,
+            distance_m: a.distance_m,
+            then_kind: a.then_kind,
+        }
+    }
+}
+
+impl From<nav_core::api::dto::RejoinDto> for RejoinDto {
+    fn from(r: nav_core::api::dto::RejoinDto) -> Self {
+        Self {
+            lat: r.lat,
+            lon: r.lon,
+            distance_m: r.distance_m,
+            bearing_deg: r.bearing_deg,
+            direction: r.direction,
+        }
+    }
+}
+
+impl From<nav_core::api::dto::WaypointEtaDto> for WaypointEtaDto {
+    fn from(w: nav_core::api::dto::WaypointEtaDto) -> Self {
+        Self {
+            index: w.index,
+            distance_m: w.distance_m,
+            eta_seconds: w.eta_seconds,
+        }
+    }
+}
+
+impl From<nav_core::api::dto::LegEtaDto> for LegEtaDto {
+    fn from(l: nav_core::api::dto::LegEtaDto) -> Self {
+        Self {
+            index: l.index,
+            distance_m: l.distance_m,
+            eta_seconds: l.eta_seconds,
+        }
+    }
+}
+
+impl From<nav_core::api::dto::WrongWayDto> for WrongWayDto {
+    fn from(w: nav_core::api::dto::WrongWayDto) -> Self {
+        Self {
+            instruction: w.instruction.into(),
+            distance_m: w.distance_m,
         }
     }
 }
@@ -190,3 +348,12 @@ impl From<nav_core::api::dto::GeocodingResultDto> for GeocodingResultDto {
         }
     }
 }
+
+impl From<nav_core::api::DeviceMessage> for DeviceMessageDto {
+    fn from(m: nav_core::api::DeviceMessage) -> Self {
+        Self {
+            device_id: m.device_id,
+            bytes: m.bytes,
+        }
+    }
+}
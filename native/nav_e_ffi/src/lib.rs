@@ -7,6 +7,7 @@ mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be
 mod ffi_models;
 pub use ffi_models::*;
 
+use crate::frb_generated::StreamSink;
 use anyhow::Result;
 use flutter_rust_bridge::frb;
 use std::collections::HashMap;
@@ -122,6 +123,19 @@ pub fn start_navigation_session(
     Ok(nav_core::api::start_navigation_session(waypoints, current_position)?.into())
 }
 
+/// Start a new navigation session for a vehicle profile ("car", "motorcycle", "bicycle", "foot")
+#[frb]
+pub fn start_navigation_session_with_profile(
+    waypoints: Vec<(f64, f64)>,
+    current_position: (f64, f64),
+    profile: String,
+) -> Result<NavigationSessionDto> {
+    Ok(
+        nav_core::api::start_navigation_session_with_profile(waypoints, current_position, profile)?
+            .into(),
+    )
+}
+
 /// Update current position during navigation. Returns `NavigationStateDto`.
 #[frb]
 pub fn update_navigation_position(
@@ -132,6 +146,63 @@ pub fn update_navigation_position(
     Ok(nav_core::api::update_navigation_position(session_id, latitude, longitude)?.into())
 }
 
+/// Update position with a full GPS fix (accuracy, bearing, speed, timestamp in Unix ms).
+#[frb]
+pub fn update_navigation_fix(
+    session_id: String,
+    latitude: f64,
+    longitude: f64,
+    accuracy_m: Option<f64>,
+    bearing_deg: Option<f64>,
+    speed_mps: Option<f64>,
+    timestamp_ms: Option<u64>,
+) -> Result<NavigationStateDto> {
+    Ok(nav_core::api::update_navigation_fix(
+        session_id,
+        latitude,
+        longitude,
+        accuracy_m,
+        bearing_deg,
+        speed_mps,
+        timestamp_ms,
+    )?
+    .into())
+}
+
+/// Dead-reckoned navigation state while no GPS fix arrives (tunnels).
+/// Returns null when there is no recent fix to extrapolate from.
+#[frb]
+pub fn estimate_navigation_position(
+    session_id: String,
+    timestamp_ms: u64,
+) -> Result<Option<NavigationStateDto>> {
+    Ok(nav_core::api::estimate_navigation_position(session_id, timestamp_ms)?.map(Into::into))
+}
+
+/// Connector route back to the rejoin point while off a fixed-geometry track.
+/// Returns null when the session is not in rejoin mode.
+#[frb]
+pub fn request_rejoin_connector(session_id: String) -> Result<Option<RouteDto>> {
+    Ok(nav_core::api::request_rejoin_connector(session_id)?.map(Into::into))
+}
+
+/// Offer alternative routes (Nav-IR JSON each); the session follows whichever one is driven.
+#[frb]
+pub fn track_alternative_routes(session_id: String, routes_json: Vec<String>) -> Result<()> {
+    nav_core::api::track_alternative_routes(session_id, routes_json)
+}
+
+/// Set the vehicle range and break interval of a session; range and break events follow.
+#[frb]
+pub fn set_ride_limits(
+    session_id: String,
+    vehicle_range_m: Option<f64>,
+    range_left_m: Option<f64>,
+    break_after_s: Option<f64>,
+) -> Result<()> {
+    nav_core::api::set_ride_limits(session_id, vehicle_range_m, range_left_m, break_after_s)
+}
+
 /// Get the latest navigation state for an active session without moving.
 /// Returns `NavigationStateDto` or null if session not found.
 #[frb]
@@ -157,6 +228,24 @@ pub fn resume_navigation(session_id: String) -> Result<()> {
     nav_core::api::resume_navigation(session_id)
 }
 
+/// Drive a session with simulated GPS fixes (demo / testing without riding).
+/// `config_json` is a simulator config (speed profile, noise, dropouts, detours); "" for defaults.
+/// Returns immediately; navigation events flow as for real GPS.
+#[frb]
+pub fn start_navigation_simulation(
+    session_id: String,
+    config_json: String,
+    time_scale: f64,
+) -> Result<()> {
+    nav_core::api::start_navigation_simulation(session_id, config_json, time_scale)
+}
+
+/// Stop a running simulation. Returns false if none was running.
+#[frb]
+pub fn stop_navigation_simulation(session_id: String) -> Result<bool> {
+    nav_core::api::stop_navigation_simulation(session_id)
+}
+
 /// Stop and complete navigation session
 #[frb]
 pub fn stop_navigation(session_id: String) -> Result<()> {
@@ -178,6 +267,26 @@ pub fn get_route_steps(session_id: String) -> Result<Vec<DerivedInstructionDto>>
         .collect())
 }
 
+/// Localized instruction text ("en", "nl", "de", "fr"; "metric" or "imperial")
+#[frb]
+pub fn format_instruction_text(
+    kind: String,
+    street_name: Option<String>,
+    exit_number: Option<u32>,
+    distance_m: Option<f64>,
+    locale: String,
+    units: String,
+) -> Result<String> {
+    nav_core::api::format_instruction_text(
+        kind,
+        street_name,
+        exit_number,
+        distance_m,
+        locale,
+        units,
+    )
+}
+
 // ============================================================================
 // Geocoding API
 // ============================================================================
@@ -337,17 +446,45 @@ pub fn delete_saved_route(id: i64) -> Result<()> {
 
 /// Send route data to a connected device via Bluetooth.
 ///
-/// Serialises the route to protobuf via `nav_protocol` and emits the bytes on the
-/// device message channel. Flutter must be subscribed to `subscribe_device_messages()`
-/// to receive and write them over BLE.
+/// Looks up the device's remote ID and sends the route like `send_route_to_remote_device`.
 #[frb]
 pub fn send_route_to_device(device_id: i64, route_json: String) -> Result<()> {
     nav_core::api::send_route_to_device(device_id, route_json)
 }
 
-// NOTE: subscribe_device_messages() is available in nav_core::api but requires FRB stream
-// wiring to expose to Flutter. See nav_core::api::subscribe_device_messages and
-// DeviceMessage for the shape. Wire as a StreamSink<DeviceMessage> when BLE streaming is needed.
+/// Send route data to the device with BLE remote ID `remote_id`.
+///
+/// The route is encoded for the device's capabilities, signed with its key and emitted as
+/// frames on the device message channel (through a reliable transfer if the device ACKs).
+/// Flutter must be listening to `subscribe_device_messages()` to write them over BLE.
+#[frb]
+pub fn send_route_to_remote_device(remote_id: String, route_json: String) -> Result<()> {
+    nav_core::api::send_route_to_remote_device(remote_id, route_json)
+}
+
+/// Stream every message the device adapter sends to devices (route frames, resent frames,
+/// position updates); Flutter writes each one to the BLE peripheral `device_id`.
+#[frb]
+pub fn subscribe_device_messages(sink: StreamSink<DeviceMessageDto>) -> Result<()> {
+    nav_core::api::forward_device_messages(move |message| sink.add(message.into()).is_ok());
+    Ok(())
+}
+
+// NOTE: subscribe_device_transfers() is available in nav_core::api but requires FRB stream
+// wiring to expose to Flutter. See nav_core::api::subscribe_device_transfers and
+// TransferReport for the shape.
+
+/// Pass bytes received from a device over BLE (ACK/NACK for route transfers)
+#[frb(sync)]
+pub fn receive_device_bytes(device_id: String, bytes: Vec<u8>) -> Result<()> {
+    nav_core::api::receive_device_bytes(device_id, bytes)
+}
+
+/// Resend route frames whose ACK timed out; call periodically during a transfer
+#[frb(sync)]
+pub fn poll_device_transfers() {
+    nav_core::api::poll_device_transfers()
+}
 
 // ============================================================================
 // Devices API
@@ -395,6 +532,19 @@ pub fn device_exists_by_remote_id(remote_id: String) -> Result<bool> {
     nav_core::api::device_exists_by_remote_id(remote_id)
 }
 
+/// Give a device a new route signing key; returns {"key_id", "key" (hex)} for provisioning
+#[frb(sync)]
+pub fn rotate_device_signing_key(id: i64) -> Result<String> {
+    nav_core::api::rotate_device_signing_key(id)
+}
+
+/// Sign a device's routes with its new key once the device has stored it.
+/// Devices that report the key in their capabilities handshake are confirmed automatically.
+#[frb(sync)]
+pub fn confirm_device_signing_key(id: i64, key_id: u32) -> Result<()> {
+    nav_core::api::confirm_device_signing_key(id, key_id)
+}
+
 // ============================================================================
 // Device Communication API
 // ============================================================================
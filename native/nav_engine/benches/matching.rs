@@ -0,0 +1,66 @@
+//! Matching cost on a long tour track: linear scan vs `PolylineIndex`.
+//!
+//! Run with `cargo bench -p nav_engine --bench matching`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use nav_engine::off_route::distance_to_polyline;
+use nav_engine::progress::remaining_distance;
+use nav_engine::PolylineIndex;
+use nav_ir::Coordinate;
+
+/// Meandering track with ~10 m between vertices.
+fn tour_track(n: usize) -> Vec<Coordinate> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64 * 0.0001;
+            Coordinate::new(45.0 + t * 0.6, 6.0 + (t * 3.0).sin() * 0.05 + t * 0.3)
+        })
+        .collect()
+}
+
+fn fixes(vertices: &[Coordinate], count: usize) -> Vec<Coordinate> {
+    let step = vertices.len() / count;
+    (0..count)
+        .map(|k| {
+            let v = vertices[k * step];
+            Coordinate::new(v.latitude + 0.00005, v.longitude - 0.00004)
+        })
+        .collect()
+}
+
+fn bench_matching(c: &mut Criterion) {
+    let mut group = c.benchmark_group("match_and_remaining");
+    for n in [5_000usize, 50_000] {
+        let vertices = tour_track(n);
+        let index = PolylineIndex::build(&vertices);
+        let fixes = fixes(&vertices, 100);
+
+        group.bench_with_input(BenchmarkId::new("linear", n), &fixes, |b, fixes| {
+            b.iter(|| {
+                for &p in fixes {
+                    let (_, v, _) = distance_to_polyline(p, &vertices);
+                    black_box(remaining_distance(&vertices, v));
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("indexed", n), &fixes, |b, fixes| {
+            b.iter(|| {
+                for &p in fixes {
+                    let (seg, ..) = index.nearest_segment(p, &vertices, |_| true).unwrap();
+                    black_box(index.remaining_from_vertex(seg));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_build(c: &mut Criterion) {
+    let vertices = tour_track(50_000);
+    c.bench_function("index_build_50k", |b| {
+        b.iter(|| black_box(PolylineIndex::build(&vertices)))
+    });
+}
+
+criterion_group!(benches, bench_matching, bench_build);
+criterion_main!(benches);
@@ -0,0 +1,252 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::types::{Announcement, AnnouncementStage, DerivedInstruction, DerivedInstructionKind};
+
+/// Coarse road class used to pick announcement lead distances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoadClass {
+    Urban,
+    Rural,
+    Highway,
+}
+
+impl RoadClass {
+    /// Infer from the active segment's speed limit, falling back to the current speed.
+    pub fn infer(max_speed_kmh: Option<u32>, speed_mps: f64) -> Self {
+        let kmh = max_speed_kmh.map(|v| v as f64).unwrap_or(speed_mps * 3.6);
+        if kmh >= 90.0 {
+            RoadClass::Highway
+        } else if kmh >= 55.0 {
+            RoadClass::Rural
+        } else {
+            RoadClass::Urban
+        }
+    }
+
+    /// `(minimum distance m, lead time s)` for a stage; the trigger is the larger of
+    /// the minimum and `speed × lead time`.
+    fn lead(self, stage: AnnouncementStage) -> (f64, f64) {
+        use AnnouncementStage::*;
+        match (self, stage) {
+            (RoadClass::Urban, Early) => (300.0, 30.0),
+            (RoadClass::Urban, Prepare) => (120.0, 12.0),
+            (RoadClass::Urban, Now) => (30.0, 4.0),
+            (RoadClass::Rural, Early) => (1000.0, 45.0),
+            (RoadClass::Rural, Prepare) => (300.0, 15.0),
+            (RoadClass::Rural, Now) => (60.0, 5.0),
+            (RoadClass::Highway, Early) => (2000.0, 60.0),
+            (RoadClass::Highway, Prepare) => (800.0, 20.0),
+            (RoadClass::Highway, Now) => (150.0, 6.0),
+        }
+    }
+}
+
+/// Distance before the maneuver at which `stage` fires.
+pub fn trigger_distance_m(stage: AnnouncementStage, road: RoadClass, speed_mps: f64) -> f64 {
+    let (min_m, lead_s) = road.lead(stage);
+    min_m.max(speed_mps * lead_s)
+}
+
+/// Maneuvers closer together than this are announced as one prompt ("…, then …").
+fn merge_distance_m(speed_mps: f64) -> f64 {
+    50.0_f64.max(speed_mps * 5.0)
+}
+
+/// Decides when to announce upcoming maneuvers.
+///
+/// Each instruction gets at most one prompt per stage, in stage order: once a closer stage
+/// has fired the earlier ones are skipped, and nothing is ever repeated. When the rider
+/// enters a stage's window late (e.g. after a resume), only the closest applicable stage
+/// fires.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnnouncementPlanner {
+    /// Latest stage announced per instruction index.
+    announced: HashMap<usize, AnnouncementStage>,
+}
+
+impl AnnouncementPlanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prompts due for the instruction at `step`, `distance_m` ahead.
+    pub fn plan(
+        &mut self,
+        instructions: &[DerivedInstruction],
+        step: usize,
+        distance_m: f64,
+        speed_mps: f64,
+        road: RoadClass,
+    ) -> Vec<Announcement> {
+        let Some(instr) = instructions.get(step) else {
+            return vec![];
+        };
+        if matches!(
+            instr.kind,
+            DerivedInstructionKind::Depart | DerivedInstructionKind::Continue
+        ) {
+            return vec![];
+        }
+
+        let due = [
+            AnnouncementStage::Now,
+            AnnouncementStage::Prepare,
+            AnnouncementStage::Early,
+        ]
+        .into_iter()
+        .find(|&stage| distance_m <= trigger_distance_m(stage, road, speed_mps));
+        let Some(stage) = due else {
+            return vec![];
+        };
+        if self.announced.get(&step).is_some_and(|&done| done >= stage) {
+            return vec![];
+        }
+        self.announced.insert(step, stage);
+
+        let then = instructions
+            .get(step + 1)
+            .filter(|next| {
+                instr.distance_to_next_m < merge_distance_m(speed_mps)
+                    && !matches!(next.kind, DerivedInstructionKind::Continue)
+            })
+            .map(|next| next.kind);
+        if then.is_some() {
+            // The follow-up was announced together with this one; only its "now" remains.
+            self.announced
+                .entry(step + 1)
+                .or_insert(AnnouncementStage::Prepare);
+        }
+
+        vec![Announcement {
+            stage,
+            step,
+            kind: instr.kind,
+            street_name: instr.street_name.clone(),
+            distance_m,
+            then,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instr(kind: DerivedInstructionKind, distance_to_next_m: f64) -> DerivedInstruction {
+        DerivedInstruction {
+            kind,
+            vertex_index: 0,
+            distance_to_next_m,
+            street_name: None,
+        }
+    }
+
+    fn route() -> Vec<DerivedInstruction> {
+        vec![
+            instr(DerivedInstructionKind::Depart, 3000.0),
+            instr(DerivedInstructionKind::TurnLeft, 2000.0),
+            instr(DerivedInstructionKind::Arrive, 0.0),
+        ]
+    }
+
+    fn stages(
+        planner: &mut AnnouncementPlanner,
+        instructions: &[DerivedInstruction],
+        step: usize,
+        distances: &[f64],
+        speed: f64,
+        road: RoadClass,
+    ) -> Vec<AnnouncementStage> {
+        distances
+            .iter()
+            .flat_map(|&d| planner.plan(instructions, step, d, speed, road))
+            .map(|a| a.stage)
+            .collect()
+    }
+
+    #[test]
+    fn each_stage_fires_once_in_order() {
+        let instructions = route();
+        let mut p = AnnouncementPlanner::new();
+        let got = stages(
+            &mut p,
+            &instructions,
+            1,
+            &[600.0, 290.0, 280.0, 200.0, 110.0, 100.0, 25.0, 10.0, 0.0],
+            8.0,
+            RoadClass::Urban,
+        );
+        use AnnouncementStage::*;
+        assert_eq!(got, vec![Early, Prepare, Now]);
+    }
+
+    #[test]
+    fn late_entry_skips_to_closest_stage() {
+        let instructions = route();
+        let mut p = AnnouncementPlanner::new();
+        let got = stages(
+            &mut p,
+            &instructions,
+            1,
+            &[20.0, 15.0],
+            8.0,
+            RoadClass::Urban,
+        );
+        assert_eq!(got, vec![AnnouncementStage::Now]);
+    }
+
+    #[test]
+    fn lead_distance_scales_with_speed_and_road_class() {
+        let urban_slow = trigger_distance_m(AnnouncementStage::Prepare, RoadClass::Urban, 5.0);
+        let urban_fast = trigger_distance_m(AnnouncementStage::Prepare, RoadClass::Urban, 20.0);
+        let highway = trigger_distance_m(AnnouncementStage::Prepare, RoadClass::Highway, 20.0);
+        assert_eq!(urban_slow, 120.0);
+        assert_eq!(urban_fast, 240.0);
+        assert!(highway > urban_fast);
+        assert_eq!(RoadClass::infer(Some(100), 0.0), RoadClass::Highway);
+        assert_eq!(RoadClass::infer(None, 20.0), RoadClass::Rural);
+    }
+
+    #[test]
+    fn close_maneuvers_are_merged() {
+        let instructions = vec![
+            instr(DerivedInstructionKind::Depart, 500.0),
+            instr(DerivedInstructionKind::TurnLeft, 30.0),
+            instr(DerivedInstructionKind::TurnRight, 400.0),
+            instr(DerivedInstructionKind::Arrive, 0.0),
+        ];
+        let mut p = AnnouncementPlanner::new();
+        let a = p.plan(&instructions, 1, 100.0, 8.0, RoadClass::Urban);
+        assert_eq!(a[0].then, Some(DerivedInstructionKind::TurnRight));
+        let a = p.plan(&instructions, 1, 20.0, 8.0, RoadClass::Urban);
+        assert_eq!(a[0].stage, AnnouncementStage::Now);
+
+        // The follow-up's early/prepare prompts were covered by the merged one.
+        assert!(p
+            .plan(&instructions, 2, 80.0, 8.0, RoadClass::Urban)
+            .is_empty());
+        let now = p.plan(&instructions, 2, 10.0, 8.0, RoadClass::Urban);
+        assert_eq!(now[0].stage, AnnouncementStage::Now);
+    }
+
+    #[test]
+    fn continue_and_depart_are_silent() {
+        let instructions = vec![
+            instr(DerivedInstructionKind::Depart, 100.0),
+            instr(DerivedInstructionKind::Continue, 100.0),
+            instr(DerivedInstructionKind::Arrive, 0.0),
+        ];
+        let mut p = AnnouncementPlanner::new();
+        assert!(p
+            .plan(&instructions, 0, 0.0, 8.0, RoadClass::Urban)
+            .is_empty());
+        assert!(p
+            .plan(&instructions, 1, 0.0, 8.0, RoadClass::Urban)
+            .is_empty());
+        assert_eq!(
+            p.plan(&instructions, 2, 5.0, 8.0, RoadClass::Urban).len(),
+            1
+        );
+    }
+}
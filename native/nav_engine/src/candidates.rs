@@ -0,0 +1,307 @@
+use std::collections::VecDeque;
+
+use nav_ir::Route;
+
+use crate::config::EngineConfig;
+use crate::engine::NavigationEngine;
+use crate::types::{GpsFix, NavigationState};
+
+/// State of the candidate the tracker currently follows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateState {
+    /// Index of the leading candidate, or of the chosen one once committed.
+    pub index: usize,
+    /// Navigation state on that candidate.
+    pub state: NavigationState,
+    /// The tracker committed to `index` on this update.
+    pub committed: bool,
+}
+
+/// Tracking against several candidate routes at once, until the rider's fixes show which one
+/// they are driving.
+///
+/// Every fix goes to one engine per candidate. Each fix costs a candidate its distance from
+/// that route, capped at twice the off-route threshold, plus the cap again while riding it
+/// the wrong way. Over the last `candidate_window_fixes` fixes, the candidate with the lowest
+/// mean cost leads. It is chosen once it is within the off-route threshold and every other
+/// candidate is at least `candidate_commit_margin_m` worse; from then on only its engine runs.
+/// Dead-reckoned states are not scored. While the candidates share a road their costs tie and
+/// nothing is decided; ties go to the earlier candidate.
+pub struct CandidateTracker {
+    engines: Vec<NavigationEngine>,
+    /// Recent fix costs, one window per engine.
+    costs: Vec<VecDeque<f64>>,
+    chosen: Option<usize>,
+}
+
+impl CandidateTracker {
+    /// Fresh engines for `routes`, all with `config`.
+    pub fn new(routes: Vec<Route>, config: EngineConfig) -> Result<Self, String> {
+        Self::from_engines(
+            routes
+                .into_iter()
+                .map(|route| NavigationEngine::new(route, config.clone()))
+                .collect(),
+        )
+    }
+
+    /// Track prepared engines, e.g. the session's current one plus fresh ones for
+    /// alternatives.
+    pub fn from_engines(engines: Vec<NavigationEngine>) -> Result<Self, String> {
+        if engines.is_empty() {
+            return Err("no candidate routes to track".to_string());
+        }
+        Ok(Self {
+            costs: vec![VecDeque::new(); engines.len()],
+            engines,
+            chosen: None,
+        })
+    }
+
+    /// Feed a fix to every candidate still in the running.
+    pub fn update_fix(&mut self, fix: GpsFix) -> CandidateState {
+        if let Some(index) = self.chosen {
+            return CandidateState {
+                index,
+                state: self.engines[index].update_fix(fix),
+                committed: false,
+            };
+        }
+        let states: Vec<NavigationState> = self
+            .engines
+            .iter_mut()
+            .map(|engine| engine.update_fix(fix))
+            .collect();
+        if !states.iter().any(|s| s.estimated) {
+            for ((state, engine), costs) in states.iter().zip(&self.engines).zip(&mut self.costs) {
+                let config = engine.config();
+                costs.push_back(fix_cost(state, config));
+                while costs.len() > config.candidate_window_fixes.max(1) {
+                    costs.pop_front();
+                }
+            }
+        }
+        let index = self.leader();
+        let committed = self.decided(index);
+        if committed {
+            self.chosen = Some(index);
+        }
+        CandidateState {
+            index,
+            state: states
+                .into_iter()
+                .nth(index)
+                .expect("leader is a candidate"),
+            committed,
+        }
+    }
+
+    /// Dead-reckon every candidate still in the running; the leader's estimate.
+    pub fn estimate_position(&mut self, timestamp_ms: u64) -> Option<CandidateState> {
+        let index = self.chosen.unwrap_or_else(|| self.leader());
+        let mut leading = None;
+        for (i, engine) in self.engines.iter_mut().enumerate() {
+            if self.chosen.is_some_and(|c| c != i) {
+                continue;
+            }
+            let state = engine.estimate_position(timestamp_ms);
+            if i == index {
+                leading = state;
+            }
+        }
+        Some(CandidateState {
+            index,
+            state: leading?,
+            committed: false,
+        })
+    }
+
+    /// Candidate with the lowest mean cost so far (the first before any fix).
+    pub fn leader(&self) -> usize {
+        let means: Vec<f64> = self.costs.iter().map(mean).collect();
+        (0..means.len())
+            .min_by(|&a, &b| means[a].total_cmp(&means[b]))
+            .unwrap_or(0)
+    }
+
+    /// The candidate committed to, if any.
+    pub fn chosen(&self) -> Option<usize> {
+        self.chosen
+    }
+
+    /// Engine of candidate `index`.
+    pub fn engine(&self, index: usize) -> Option<&NavigationEngine> {
+        self.engines.get(index)
+    }
+
+    /// The chosen candidate's engine, to keep navigating on alone; `None` before a choice.
+    pub fn into_chosen(mut self) -> Option<NavigationEngine> {
+        let index = self.chosen?;
+        Some(self.engines.swap_remove(index))
+    }
+
+    /// Whether `index` has clearly won: a full window, on its route, and ahead of every other
+    /// candidate by the margin.
+    fn decided(&self, index: usize) -> bool {
+        let config = self.engines[index].config();
+        let costs = &self.costs[index];
+        if costs.len() < config.candidate_window_fixes.max(1) {
+            return false;
+        }
+        let best = mean(costs);
+        best <= config.off_route_threshold_m
+            && self
+                .costs
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != index)
+                .all(|(_, other)| mean(other) - best >= config.candidate_commit_margin_m)
+    }
+}
+
+/// How badly one fix matches a candidate (meters).
+fn fix_cost(state: &NavigationState, config: &EngineConfig) -> f64 {
+    let cap_m = config.off_route_threshold_m * 2.0;
+    let wrong_way_m = if state.wrong_way.is_some() {
+        cap_m
+    } else {
+        0.0
+    };
+    state.off_route.distance_from_route_m.min(cap_m) + wrong_way_m
+}
+
+fn mean(costs: &VecDeque<f64>) -> f64 {
+    if costs.is_empty() {
+        return 0.0;
+    }
+    costs.iter().sum::<f64>() / costs.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use nav_ir::*;
+
+    /// North from (52.0, 5.0) for 0.003° (~330 m), then 0.003° of longitude towards `east`.
+    fn fork(east: f64) -> Route {
+        let coords = vec![
+            geo_types::Coord { x: 5.0, y: 52.0 },
+            geo_types::Coord { x: 5.0, y: 52.003 },
+            geo_types::Coord {
+                x: 5.0 + east * 0.003,
+                y: 52.003,
+            },
+        ];
+        let now = Utc::now();
+        Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: String::new(),
+                description: None,
+                created_at: now,
+                updated_at: now,
+                total_distance_m: None,
+                estimated_duration_s: None,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap()),
+                    source: GeometrySource::SnappedToGraph,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: 52.0,
+                        min_lon: 4.997,
+                        max_lat: 52.003,
+                        max_lon: 5.003,
+                    },
+                },
+                waypoints: vec![],
+                legs: vec![],
+                instructions: vec![],
+                constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
+            }],
+            policies: RoutePolicies::default(),
+        }
+    }
+
+    fn fix(lat: f64, lon: f64, t: u64) -> GpsFix {
+        GpsFix {
+            accuracy_m: Some(5.0),
+            speed_mps: Some(10.0),
+            timestamp_ms: Some(t * 1000),
+            ..GpsFix::new(Coordinate::new(lat, lon))
+        }
+    }
+
+    /// Up the shared stretch, then `east` along the fork; one fix every ~11 m.
+    fn ride(east: f64) -> Vec<GpsFix> {
+        let north = (0..=27).map(|i| (52.0 + i as f64 * 0.0001, 5.0));
+        let along = (1..=20).map(|i| (52.003, 5.0 + east * i as f64 * 0.00015));
+        north
+            .chain(along)
+            .enumerate()
+            .map(|(t, (lat, lon))| fix(lat, lon, t as u64))
+            .collect()
+    }
+
+    #[test]
+    fn commits_to_the_branch_taken_once_it_clearly_wins() {
+        let mut tracker =
+            CandidateTracker::new(vec![fork(1.0), fork(-1.0)], EngineConfig::default()).unwrap();
+        let mut committed_at = None;
+        for (i, fix) in ride(-1.0).into_iter().enumerate() {
+            let update = tracker.update_fix(fix);
+            if i < 28 {
+                assert_eq!(update.index, 0, "tie on the shared stretch");
+                assert!(!update.committed);
+            }
+            if update.committed {
+                assert_eq!(update.index, 1);
+                committed_at = Some(i);
+            }
+        }
+        let committed_at = committed_at.expect("a candidate is chosen");
+        assert!(committed_at > 28, "not before the fork");
+        assert_eq!(tracker.chosen(), Some(1));
+        let route_id = tracker.engine(1).unwrap().route().id;
+        assert_eq!(tracker.into_chosen().unwrap().route().id, route_id);
+    }
+
+    #[test]
+    fn nothing_is_chosen_while_the_candidates_overlap_or_all_miss() {
+        let config = EngineConfig::default();
+        let mut tracker = CandidateTracker::new(vec![fork(1.0), fork(-1.0)], config).unwrap();
+        for fix in ride(1.0).into_iter().take(28) {
+            assert!(!tracker.update_fix(fix).committed);
+        }
+        // Straight on past the fork, away from both branches.
+        for t in 28..48 {
+            let update = tracker.update_fix(fix(52.003 + (t - 27) as f64 * 0.0001, 5.0, t));
+            assert!(!update.committed);
+        }
+        assert_eq!(tracker.chosen(), None);
+        assert!(tracker.into_chosen().is_none());
+        assert!(CandidateTracker::new(vec![], EngineConfig::default()).is_err());
+    }
+
+    #[test]
+    fn gaps_are_bridged_on_the_leader_without_scoring() {
+        let mut tracker =
+            CandidateTracker::new(vec![fork(1.0), fork(-1.0)], EngineConfig::default()).unwrap();
+        for fix in ride(1.0).into_iter().take(10) {
+            tracker.update_fix(fix);
+        }
+        let estimate = tracker.estimate_position(10_000).unwrap();
+        assert_eq!(estimate.index, 0);
+        assert!(estimate.state.estimated);
+        assert!(tracker.costs.iter().all(|c| c.len() == 8));
+    }
+}
@@ -0,0 +1,216 @@
+use nav_ir::SnappingMode;
+use serde::{Deserialize, Serialize};
+
+/// Vehicle profile used to pick an `EngineConfig` preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VehicleProfile {
+    #[default]
+    Car,
+    Motorcycle,
+    Bicycle,
+    Foot,
+}
+
+impl VehicleProfile {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VehicleProfile::Car => "car",
+            VehicleProfile::Motorcycle => "motorcycle",
+            VehicleProfile::Bicycle => "bicycle",
+            VehicleProfile::Foot => "foot",
+        }
+    }
+}
+
+impl std::str::FromStr for VehicleProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "car" => Ok(VehicleProfile::Car),
+            "motorcycle" => Ok(VehicleProfile::Motorcycle),
+            "bicycle" => Ok(VehicleProfile::Bicycle),
+            "foot" => Ok(VehicleProfile::Foot),
+            other => Err(format!("unknown vehicle profile: {}", other)),
+        }
+    }
+}
+
+/// Tunable thresholds for `NavigationEngine`.
+///
+/// Build from a preset with [`EngineConfig::for_profile`]; the engine then adjusts it for the
+/// route's `SnappingMode` via [`EngineConfig::with_snapping_mode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineConfig {
+    pub profile: VehicleProfile,
+    /// Distance from the polyline beyond which the rider is considered off-route (meters).
+    pub off_route_threshold_m: f64,
+    /// Minimum bearing delta for a polyline vertex to generate a turn instruction (degrees).
+    pub min_turn_degrees: f64,
+    /// Instructions closer together than this are merged, keeping the sharper turn (meters).
+    pub min_instruction_distance_m: f64,
+    /// Heading change at a vertex is measured between the stretches this far before and
+    /// after it (meters).
+    pub turn_window_m: f64,
+    /// Speed assumed for ETA when neither GPS speed nor route duration is available (m/s).
+    pub default_speed_mps: f64,
+    /// Report the fix projected onto the route as `snapped_position`; `false` passes it through.
+    pub snap_to_route: bool,
+    /// Forward distance from the progress cursor searched before the rest of the route (meters).
+    pub match_window_m: f64,
+    /// Segments whose bearing differs from the GPS heading by more than this are not matched
+    /// (degrees).
+    pub heading_tolerance_degrees: f64,
+    /// Fixes reporting a worse accuracy are ignored and the position is dead-reckoned
+    /// instead (meters).
+    pub max_fix_accuracy_m: f64,
+    /// Consecutive fixes beyond the off-route threshold before off-route is confirmed.
+    pub off_route_confirm_fixes: u32,
+    /// Time beyond the off-route threshold after which off-route is confirmed, even with fewer
+    /// fixes (seconds).
+    pub off_route_confirm_s: f64,
+    /// Heading at least this far from the matched segment's bearing counts as riding against
+    /// the route (degrees).
+    pub wrong_way_heading_degrees: f64,
+    /// Consecutive fixes against the route before wrong-way is confirmed.
+    pub wrong_way_confirm_fixes: u32,
+    /// Along-route distance lost while heading against the route before wrong-way is
+    /// confirmed (meters).
+    pub wrong_way_min_distance_m: f64,
+    /// Time constant of the smoothed pace behind the ETA (seconds); longer is steadier.
+    pub eta_pace_time_constant_s: f64,
+    /// How far over the speed limit the rider may go before a speeding alert (km/h).
+    pub speeding_tolerance_kmh: f64,
+    /// Fixes over which candidate routes are compared before one can be chosen.
+    pub candidate_window_fixes: usize,
+    /// Mean distance by which the best candidate route must beat every other one before it is
+    /// chosen (meters).
+    pub candidate_commit_margin_m: f64,
+    /// Distance on a full tank (or battery); `None` disables range warnings (meters).
+    pub vehicle_range_m: Option<f64>,
+    /// Riding time after which a break is suggested; `None` disables break suggestions
+    /// (seconds).
+    pub break_after_s: Option<f64>,
+    /// Standing still this long counts as a break (seconds).
+    pub break_min_stop_s: f64,
+}
+
+impl EngineConfig {
+    /// Preset thresholds for a vehicle profile (with `SnappingMode::Relaxed` tolerances).
+    pub fn for_profile(profile: VehicleProfile) -> Self {
+        let (
+            off_route_threshold_m,
+            min_turn_degrees,
+            min_instruction_distance_m,
+            speed_kmh,
+            match_window_m,
+            max_fix_accuracy_m,
+            turn_window_m,
+            break_after_h,
+        ) = match profile {
+            VehicleProfile::Car => (50.0, 25.0, 30.0, 40.0, 250.0, 50.0, 20.0, Some(2.0)),
+            // Twisty roads: ignore small bends, keep prompts further apart.
+            VehicleProfile::Motorcycle => (50.0, 30.0, 40.0, 50.0, 250.0, 50.0, 25.0, Some(2.0)),
+            VehicleProfile::Bicycle => (30.0, 25.0, 20.0, 15.0, 120.0, 35.0, 15.0, None),
+            VehicleProfile::Foot => (20.0, 30.0, 10.0, 5.0, 60.0, 25.0, 10.0, None),
+        };
+        Self {
+            profile,
+            off_route_threshold_m,
+            min_turn_degrees,
+            min_instruction_distance_m,
+            turn_window_m,
+            default_speed_mps: speed_kmh / 3.6,
+            snap_to_route: true,
+            match_window_m,
+            heading_tolerance_degrees: 90.0,
+            max_fix_accuracy_m,
+            off_route_confirm_fixes: 3,
+            off_route_confirm_s: 5.0,
+            wrong_way_heading_degrees: 135.0,
+            wrong_way_confirm_fixes: 3,
+            wrong_way_min_distance_m: off_route_threshold_m * 0.6,
+            eta_pace_time_constant_s: 120.0,
+            speeding_tolerance_kmh: 5.0,
+            candidate_window_fixes: 8,
+            candidate_commit_margin_m: off_route_threshold_m * 0.4,
+            vehicle_range_m: None,
+            break_after_s: break_after_h.map(|h| h * 3600.0),
+            break_min_stop_s: 600.0,
+        }
+    }
+
+    /// Adjust tolerances for the route's snapping policy.
+    ///
+    /// - `Strict`: off-route fires at 60% of the profile threshold.
+    /// - `Relaxed`: profile thresholds as-is.
+    /// - `Off`: positions are not snapped and off-route tolerance is doubled.
+    pub fn with_snapping_mode(mut self, mode: SnappingMode) -> Self {
+        match mode {
+            SnappingMode::Strict => {
+                self.off_route_threshold_m *= 0.6;
+            }
+            SnappingMode::Relaxed => {}
+            SnappingMode::Off => {
+                self.off_route_threshold_m *= 2.0;
+                self.snap_to_route = false;
+            }
+        }
+        self
+    }
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self::for_profile(VehicleProfile::Car)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn car_preset_matches_legacy_constants() {
+        let c = EngineConfig::default();
+        assert_eq!(c.off_route_threshold_m, 50.0);
+        assert_eq!(c.min_turn_degrees, 25.0);
+        assert_eq!(c.min_instruction_distance_m, 30.0);
+        assert!((c.default_speed_mps - 11.111).abs() < 0.001);
+    }
+
+    #[test]
+    fn slower_profiles_have_tighter_off_route_threshold() {
+        let car = EngineConfig::for_profile(VehicleProfile::Car);
+        let bike = EngineConfig::for_profile(VehicleProfile::Bicycle);
+        let foot = EngineConfig::for_profile(VehicleProfile::Foot);
+        assert!(bike.off_route_threshold_m < car.off_route_threshold_m);
+        assert!(foot.off_route_threshold_m < bike.off_route_threshold_m);
+        assert!(foot.default_speed_mps < bike.default_speed_mps);
+    }
+
+    #[test]
+    fn snapping_mode_scales_tolerance() {
+        let base = EngineConfig::default();
+        let strict = base.clone().with_snapping_mode(SnappingMode::Strict);
+        let relaxed = base.clone().with_snapping_mode(SnappingMode::Relaxed);
+        let off = base.clone().with_snapping_mode(SnappingMode::Off);
+        assert!(strict.off_route_threshold_m < relaxed.off_route_threshold_m);
+        assert!(off.off_route_threshold_m > relaxed.off_route_threshold_m);
+        assert!(relaxed.snap_to_route);
+        assert!(!off.snap_to_route);
+    }
+
+    #[test]
+    fn profile_string_round_trip() {
+        for p in [
+            VehicleProfile::Car,
+            VehicleProfile::Motorcycle,
+            VehicleProfile::Bicycle,
+            VehicleProfile::Foot,
+        ] {
+            assert_eq!(p.as_str().parse::<VehicleProfile>(), Ok(p));
+        }
+        assert!("hovercraft".parse::<VehicleProfile>().is_err());
+    }
+}
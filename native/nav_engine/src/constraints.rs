@@ -0,0 +1,350 @@
+use nav_ir::{RoadAttribute, Route};
+
+use crate::config::EngineConfig;
+use crate::index::PolylineIndex;
+use crate::types::ConstraintAlert;
+
+/// A span alert stays active this far past either end of its span (meters), so position
+/// jitter at the boundary doesn't raise it over and over.
+const SPAN_HYSTERESIS_M: f64 = 10.0;
+const MPS_TO_KMH: f64 = 3.6;
+
+/// A road span in chained along-route meters.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    start_m: f64,
+    end_m: f64,
+    attribute: RoadAttribute,
+}
+
+impl Span {
+    fn contains(&self, along_m: f64, margin_m: f64) -> bool {
+        along_m >= self.start_m - margin_m && along_m <= self.end_m + margin_m
+    }
+}
+
+/// Live constraint monitoring.
+///
+/// Checks every position against the route's road spans and speed limits and keeps the set
+/// of active [`ConstraintAlert`]s. Toll, highway and unpaved spans only raise an alert on a
+/// segment that avoids them. Speeding compares the GPS speed with the innermost
+/// `SpeedLimit` span, else the segment's `max_speed_kmh`; it is raised above the limit plus
+/// `speeding_tolerance_kmh` and cleared once back at the limit. Each update reports which
+/// alerts were raised and which were cleared, so callers can react once per episode.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintMonitor {
+    spans: Vec<Span>,
+    /// `max_speed_kmh` of every route segment.
+    segment_limits: Vec<Option<u32>>,
+    speed_limit_kmh: Option<u32>,
+    active: Vec<ConstraintAlert>,
+    raised: Vec<ConstraintAlert>,
+    cleared: Vec<ConstraintAlert>,
+}
+
+impl ConstraintMonitor {
+    /// Spans of `route` chained as in `NavigationEngine::segment_offsets`.
+    pub fn build(route: &Route, segment_offsets: &[usize], index: &PolylineIndex) -> Self {
+        let mut spans = Vec::new();
+        for (seg, &offset) in route.segments.iter().zip(segment_offsets) {
+            let c = &seg.constraints;
+            for span in &seg.road_spans {
+                let avoided = match span.attribute {
+                    RoadAttribute::Toll => c.avoid_tolls,
+                    RoadAttribute::Highway => c.avoid_highways,
+                    RoadAttribute::Unpaved => c.avoid_unpaved,
+                    RoadAttribute::SpeedLimit { .. } => true,
+                };
+                if avoided {
+                    spans.push(Span {
+                        start_m: index
+                            .distance_at_vertex(offset + span.vertex_range.start as usize),
+                        end_m: index.distance_at_vertex(offset + span.vertex_range.end as usize),
+                        attribute: span.attribute,
+                    });
+                }
+            }
+        }
+        Self {
+            spans,
+            segment_limits: route
+                .segments
+                .iter()
+                .map(|s| s.constraints.max_speed_kmh)
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Evaluate one position `along_m` on route segment `route_segment`. Off the route
+    /// (`on_route == false`) nothing applies and every alert clears.
+    pub fn update(
+        &mut self,
+        along_m: f64,
+        route_segment: usize,
+        speed_mps: Option<f64>,
+        on_route: bool,
+        config: &EngineConfig,
+    ) -> &[ConstraintAlert] {
+        let mut next = Vec::new();
+        self.speed_limit_kmh = None;
+        if on_route {
+            self.speed_limit_kmh = self.limit_at(along_m, route_segment);
+            if let Some(max_kmh) = self.speed_limit_kmh {
+                let speeding = self
+                    .active
+                    .iter()
+                    .any(|a| matches!(a, ConstraintAlert::Speeding { .. }));
+                let threshold_kmh = if speeding {
+                    max_kmh as f64
+                } else {
+                    max_kmh as f64 + config.speeding_tolerance_kmh
+                };
+                // Without a speed reading, keep whatever was there.
+                if speed_mps.map_or(speeding, |v| v * MPS_TO_KMH > threshold_kmh) {
+                    next.push(ConstraintAlert::Speeding { max_kmh });
+                }
+            }
+            for span in &self.spans {
+                let alert = match span.attribute {
+                    RoadAttribute::Toll => ConstraintAlert::AvoidToll,
+                    RoadAttribute::Highway => ConstraintAlert::AvoidHighway,
+                    RoadAttribute::Unpaved => ConstraintAlert::AvoidUnpaved,
+                    RoadAttribute::SpeedLimit { .. } => continue,
+                };
+                let margin_m = if self.active.contains(&alert) {
+                    SPAN_HYSTERESIS_M
+                } else {
+                    0.0
+                };
+                if span.contains(along_m, margin_m) && !next.contains(&alert) {
+                    next.push(alert);
+                }
+            }
+        }
+        self.raised = next
+            .iter()
+            .filter(|a| !self.active.contains(a))
+            .copied()
+            .collect();
+        self.cleared = self
+            .active
+            .iter()
+            .filter(|a| !next.contains(a))
+            .copied()
+            .collect();
+        self.active = next;
+        &self.active
+    }
+
+    /// Start from `active` instead of no alerts, e.g. on an engine built for a reroute or
+    /// restored from a snapshot.
+    pub fn restore(&mut self, active: Vec<ConstraintAlert>, speed_limit_kmh: Option<u32>) {
+        self.active = active;
+        self.speed_limit_kmh = speed_limit_kmh;
+    }
+
+    /// Alerts in force after the last update.
+    pub fn active(&self) -> &[ConstraintAlert] {
+        &self.active
+    }
+
+    /// Alerts the last update raised.
+    pub fn raised(&self) -> &[ConstraintAlert] {
+        &self.raised
+    }
+
+    /// Alerts the last update cleared.
+    pub fn cleared(&self) -> &[ConstraintAlert] {
+        &self.cleared
+    }
+
+    /// Speed limit at the last position, if known.
+    pub fn speed_limit_kmh(&self) -> Option<u32> {
+        self.speed_limit_kmh
+    }
+
+    /// Shortest `SpeedLimit` span containing `along_m`, else the segment limit.
+    fn limit_at(&self, along_m: f64, route_segment: usize) -> Option<u32> {
+        self.spans
+            .iter()
+            .filter(|s| s.contains(along_m, 0.0))
+            .filter_map(|s| match s.attribute {
+                RoadAttribute::SpeedLimit { max_kmh } => Some((s.end_m - s.start_m, max_kmh)),
+                _ => None,
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, max_kmh)| max_kmh)
+            .or_else(|| self.segment_limits.get(route_segment).copied().flatten())
+            .filter(|&kmh| kmh > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use nav_ir::*;
+
+    /// One northbound segment of 11 vertices ~111 m apart, with `spans` and `constraints`.
+    fn monitor(
+        constraints: SegmentConstraints,
+        spans: &[(u32, u32, RoadAttribute)],
+    ) -> ConstraintMonitor {
+        let coords: Vec<geo_types::Coord<f64>> = (0..=10)
+            .map(|i| geo_types::Coord {
+                x: 0.0,
+                y: i as f64 * 0.001,
+            })
+            .collect();
+        let now = Utc::now();
+        let route = Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: String::new(),
+                description: None,
+                created_at: now,
+                updated_at: now,
+                total_distance_m: None,
+                estimated_duration_s: None,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap()),
+                    source: GeometrySource::SnappedToGraph,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: 0.0,
+                        min_lon: 0.0,
+                        max_lat: 0.01,
+                        max_lon: 0.0,
+                    },
+                },
+                waypoints: vec![],
+                legs: vec![],
+                instructions: vec![],
+                constraints,
+                street_names: vec![],
+                road_spans: spans
+                    .iter()
+                    .map(|&(start, end, attribute)| RoadSpan {
+                        vertex_range: VertexRange { start, end },
+                        attribute,
+                    })
+                    .collect(),
+            }],
+            policies: RoutePolicies::default(),
+        };
+        let vertices: Vec<Coordinate> = (0..=10)
+            .map(|i| Coordinate::new(i as f64 * 0.001, 0.0))
+            .collect();
+        ConstraintMonitor::build(&route, &[0], &PolylineIndex::build(&vertices))
+    }
+
+    #[test]
+    fn speeding_needs_tolerance_to_raise_and_clears_at_the_limit() {
+        let config = EngineConfig::default();
+        let mut m = monitor(
+            SegmentConstraints {
+                max_speed_kmh: Some(50),
+                ..SegmentConstraints::default()
+            },
+            &[],
+        );
+        let speeding = [ConstraintAlert::Speeding { max_kmh: 50 }];
+        assert!(m
+            .update(100.0, 0, Some(53.0 / 3.6), true, &config)
+            .is_empty());
+        assert_eq!(m.speed_limit_kmh(), Some(50));
+        assert_eq!(
+            m.update(110.0, 0, Some(60.0 / 3.6), true, &config),
+            speeding
+        );
+        assert_eq!(m.raised(), speeding);
+        assert_eq!(
+            m.update(120.0, 0, Some(53.0 / 3.6), true, &config),
+            speeding
+        );
+        assert!(m.raised().is_empty(), "raised once per episode");
+        assert_eq!(m.update(130.0, 0, None, true, &config), speeding);
+        assert!(m
+            .update(140.0, 0, Some(49.0 / 3.6), true, &config)
+            .is_empty());
+        assert_eq!(m.cleared(), speeding);
+    }
+
+    #[test]
+    fn speed_limit_span_overrides_the_segment_limit() {
+        let config = EngineConfig::default();
+        let mut m = monitor(
+            SegmentConstraints {
+                max_speed_kmh: Some(80),
+                ..SegmentConstraints::default()
+            },
+            &[(3, 6, RoadAttribute::SpeedLimit { max_kmh: 30 })],
+        );
+        let speed = Some(60.0 / 3.6);
+        assert!(m.update(200.0, 0, speed, true, &config).is_empty());
+        assert_eq!(
+            m.update(450.0, 0, speed, true, &config),
+            [ConstraintAlert::Speeding { max_kmh: 30 }]
+        );
+        assert!(m.update(800.0, 0, speed, true, &config).is_empty());
+        assert_eq!(m.speed_limit_kmh(), Some(80));
+    }
+
+    #[test]
+    fn avoided_spans_alert_on_entry_and_clear_on_exit() {
+        let config = EngineConfig::default();
+        let mut m = monitor(
+            SegmentConstraints {
+                avoid_tolls: true,
+                avoid_unpaved: true,
+                ..SegmentConstraints::default()
+            },
+            &[
+                (2, 4, RoadAttribute::Toll),
+                (5, 7, RoadAttribute::Highway),
+                (8, 9, RoadAttribute::Unpaved),
+            ],
+        );
+        let mut raised = Vec::new();
+        let mut cleared = Vec::new();
+        for along in (0..=1100).step_by(20) {
+            m.update(along as f64, 0, Some(10.0), true, &config);
+            raised.extend_from_slice(m.raised());
+            cleared.extend_from_slice(m.cleared());
+        }
+        // The segment doesn't avoid highways, so that span stays quiet.
+        let expected = [ConstraintAlert::AvoidToll, ConstraintAlert::AvoidUnpaved];
+        assert_eq!(raised, expected);
+        assert_eq!(cleared, expected);
+    }
+
+    #[test]
+    fn boundary_jitter_does_not_flap_and_leaving_the_route_clears() {
+        let config = EngineConfig::default();
+        let mut m = monitor(
+            SegmentConstraints {
+                avoid_highways: true,
+                ..SegmentConstraints::default()
+            },
+            &[(2, 4, RoadAttribute::Highway)],
+        );
+        let start_m = 2.0 * 111.2;
+        let mut raised = 0;
+        for along in [start_m + 1.0, start_m - 3.0, start_m + 2.0, start_m - 4.0] {
+            m.update(along, 0, None, true, &config);
+            raised += m.raised().len();
+        }
+        assert_eq!(raised, 1);
+        assert!(m.update(start_m + 50.0, 0, None, false, &config).is_empty());
+        assert_eq!(m.cleared(), [ConstraintAlert::AvoidHighway]);
+        assert_eq!(m.speed_limit_kmh(), None);
+    }
+}
@@ -1,10 +1,8 @@
-use nav_ir::{Coordinate, GeometryRefKind, Instruction, InstructionKind};
+use nav_ir::{Coordinate, GeometryRef, GeometryRefKind, Instruction, InstructionKind, StreetSpan};
 
+use crate::config::EngineConfig;
 use crate::types::{DerivedInstruction, DerivedInstructionKind};
 
-const MIN_TURN_DEGREES: f64 = 25.0;
-const MIN_INSTRUCTION_DISTANCE_M: f64 = 30.0;
-
 /// Haversine distance in meters between two coordinates.
 pub fn haversine_distance(a: Coordinate, b: Coordinate) -> f64 {
     const R: f64 = 6_371_000.0;
@@ -12,8 +10,7 @@ pub fn haversine_distance(a: Coordinate, b: Coordinate) -> f64 {
     let lat2 = b.latitude.to_radians();
     let dlat = (b.latitude - a.latitude).to_radians();
     let dlon = (b.longitude - a.longitude).to_radians();
-    let h =
-        (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
     R * 2.0 * h.sqrt().atan2((1.0 - h).sqrt())
 }
 
@@ -28,7 +25,7 @@ pub fn bearing(from: Coordinate, to: Coordinate) -> f64 {
 }
 
 /// Normalise a bearing delta to [-180, 180].
-fn normalize_delta(delta: f64) -> f64 {
+pub(crate) fn normalize_delta(delta: f64) -> f64 {
     let d = delta % 360.0;
     if d > 180.0 {
         d - 360.0
@@ -39,12 +36,12 @@ fn normalize_delta(delta: f64) -> f64 {
     }
 }
 
-fn kind_from_delta(delta: f64) -> DerivedInstructionKind {
+pub(crate) fn kind_from_delta(delta: f64, min_turn_degrees: f64) -> DerivedInstructionKind {
     match delta {
         d if d > 120.0 => DerivedInstructionKind::SharpRight,
         d if d >= 45.0 => DerivedInstructionKind::TurnRight,
-        d if d >= MIN_TURN_DEGREES => DerivedInstructionKind::SlightRight,
-        d if d > -MIN_TURN_DEGREES => DerivedInstructionKind::Continue,
+        d if d >= min_turn_degrees => DerivedInstructionKind::SlightRight,
+        d if d > -min_turn_degrees => DerivedInstructionKind::Continue,
         d if d > -45.0 => DerivedInstructionKind::SlightLeft,
         d if d > -120.0 => DerivedInstructionKind::TurnLeft,
         _ => DerivedInstructionKind::SharpLeft,
@@ -68,18 +65,166 @@ fn turn_severity(kind: DerivedInstructionKind) -> u8 {
         DerivedInstructionKind::TurnLeft | DerivedInstructionKind::TurnRight => 3,
         DerivedInstructionKind::SlightLeft | DerivedInstructionKind::SlightRight => 2,
         DerivedInstructionKind::Continue => 1,
-        DerivedInstructionKind::Depart | DerivedInstructionKind::Arrive => 5,
+        DerivedInstructionKind::Depart
+        | DerivedInstructionKind::Arrive
+        | DerivedInstructionKind::SegmentTransition
+        | DerivedInstructionKind::UTurn => 5,
+    }
+}
+
+/// Vertex an instruction's geometry ref points at. `SegmentFraction` refs (e.g. from
+/// `nav_ir::digest_track`) snap to the nearer end of their segment.
+fn ref_vertex_index(gr: &GeometryRef) -> Option<usize> {
+    match gr.kind {
+        GeometryRefKind::VertexIndex => gr.vertex_index.map(|vi| vi as usize),
+        GeometryRefKind::SegmentFraction => {
+            let start = gr.seg_start_index? as usize;
+            Some(if gr.fraction? < 0.5 { start } else { start + 1 })
+        }
     }
 }
 
+/// A turn at a junction happens within this share of `turn_window_m` around its vertex.
+const JUNCTION_WINDOW_SHARE: f64 = 0.25;
+/// Share of the bend within `turn_window_m` that must happen within the junction window;
+/// less is a curve.
+const MIN_JUNCTION_SHARE: f64 = 0.3;
+/// Turning is also summed over this multiple of `turn_window_m`, to tell an isolated turn
+/// from one kink of a coarsely drawn curve.
+const CURVE_WINDOW_FACTOR: f64 = 4.0;
+/// Share of the turning over the wide window that must happen within `turn_window_m`.
+const MIN_TURN_SHARE: f64 = 0.5;
+
+/// One route segment's slice of the chained polyline, for `derive_route_instructions`.
+pub struct SegmentInstructions<'a> {
+    /// Index of the segment's first vertex in the chained polyline.
+    pub vertex_offset: usize,
+    /// The segment's own instructions; geometry refs are relative to the segment.
+    pub instructions: &'a [Instruction],
+    /// The segment's street names; vertex ranges are relative to the segment.
+    pub street_names: &'a [StreetSpan],
+}
+
+/// Chained polyline with cumulative distances, for measuring heading over distance.
+struct Polyline<'a> {
+    vertices: &'a [Coordinate],
+    cum: Vec<f64>,
+    /// Bearing change at each vertex (0 at the ends and next to zero-length edges).
+    turns: Vec<f64>,
+}
+
+impl<'a> Polyline<'a> {
+    fn new(vertices: &'a [Coordinate]) -> Self {
+        let mut cum = vec![0.0f64; vertices.len()];
+        for i in 1..vertices.len() {
+            cum[i] = cum[i - 1] + haversine_distance(vertices[i - 1], vertices[i]);
+        }
+        let mut turns = vec![0.0f64; vertices.len()];
+        for i in 1..vertices.len().saturating_sub(1) {
+            if cum[i] - cum[i - 1] > 0.1 && cum[i + 1] - cum[i] > 0.1 {
+                turns[i] = normalize_delta(
+                    bearing(vertices[i], vertices[i + 1]) - bearing(vertices[i - 1], vertices[i]),
+                );
+            }
+        }
+        Self {
+            vertices,
+            cum,
+            turns,
+        }
+    }
+
+    /// Point `along_m` along the polyline, clamped to its ends.
+    fn point_at(&self, along_m: f64) -> Coordinate {
+        let i = self.cum.partition_point(|&d| d <= along_m);
+        if i == 0 {
+            return self.vertices[0];
+        }
+        if i >= self.vertices.len() {
+            return self.vertices[self.vertices.len() - 1];
+        }
+        let (a, b) = (self.vertices[i - 1], self.vertices[i]);
+        let length = self.cum[i] - self.cum[i - 1];
+        let t = if length > 0.0 {
+            (along_m - self.cum[i - 1]) / length
+        } else {
+            0.0
+        };
+        Coordinate::new(
+            a.latitude + (b.latitude - a.latitude) * t,
+            a.longitude + (b.longitude - a.longitude) * t,
+        )
+    }
+
+    /// Sum of the bearing changes at every vertex within `window_m` of vertex `i`.
+    fn turning(&self, i: usize, window_m: f64) -> f64 {
+        let near = |j: &usize| (self.cum[*j] - self.cum[i]).abs() <= window_m;
+        let first = (0..=i).rev().take_while(near).last().unwrap_or(i);
+        let last = (i..self.cum.len()).take_while(near).last().unwrap_or(i);
+        self.turns[first..=last].iter().sum()
+    }
+
+    /// Heading change from the `window_m` leading up to vertex `first` to the `window_m`
+    /// following vertex `last` (degrees, positive to the right).
+    fn heading_change(&self, first: usize, last: usize, window_m: f64) -> f64 {
+        let before = self.point_at(self.cum[first] - window_m);
+        let after = self.point_at(self.cum[last] + window_m);
+        let (from, to) = (self.vertices[first], self.vertices[last]);
+        if haversine_distance(before, from) < 0.5 || haversine_distance(to, after) < 0.5 {
+            return 0.0;
+        }
+        normalize_delta(bearing(to, after) - bearing(before, from))
+    }
+}
+
+/// Street of every polyline edge (`vertices[i] → vertices[i + 1]`), where known.
+fn edge_streets<'a>(edges: usize, segments: &[SegmentInstructions<'a>]) -> Vec<Option<&'a str>> {
+    let mut streets = vec![None; edges];
+    for seg in segments {
+        for span in seg.street_names {
+            let start = seg.vertex_offset + span.vertex_range.start as usize;
+            let end = (seg.vertex_offset + span.vertex_range.end as usize).min(edges);
+            for street in streets.iter_mut().take(end).skip(start) {
+                *street = Some(span.name.as_str());
+            }
+        }
+    }
+    streets
+}
+
 /// Derive turn instructions from polyline vertices.
 ///
-/// Pre-existing `nav_ir::Instruction` items matched by vertex index are used directly;
-/// any remaining vertices with |bearing delta| ≥ 25° generate a `DerivedInstruction`.
-/// Always prepends `Depart` and appends `Arrive`.
+/// Pre-existing `nav_ir::Instruction` items matched by geometry ref are used directly.
+/// Elsewhere, a turn is derived where the heading changes by at least
+/// `config.min_turn_degrees` within `config.turn_window_m` on either side of a vertex, and
+/// most of the bend happens there: a road that keeps curving is followed silently. Nearby
+/// turns merge into one maneuver, and a street name change without a turn becomes a
+/// `Continue`. Always prepends `Depart` and appends `Arrive`.
 pub fn derive_instructions(
     vertices: &[Coordinate],
     existing: &[Instruction],
+    config: &EngineConfig,
+) -> Vec<DerivedInstruction> {
+    derive_route_instructions(
+        vertices,
+        &[SegmentInstructions {
+            vertex_offset: 0,
+            instructions: existing,
+            street_names: &[],
+        }],
+        config,
+    )
+}
+
+/// Derive instructions for a multi-segment route chained into one polyline.
+///
+/// Like `derive_instructions`, with each segment's instruction refs and street spans shifted
+/// by its `vertex_offset`. A `SegmentTransition` is emitted where each later segment starts;
+/// segment `Depart`/`Arrive` instructions inside the route are dropped.
+pub fn derive_route_instructions(
+    vertices: &[Coordinate],
+    segments: &[SegmentInstructions],
+    config: &EngineConfig,
 ) -> Vec<DerivedInstruction> {
     if vertices.is_empty() {
         return vec![];
@@ -88,17 +233,26 @@ pub fn derive_instructions(
     // Build lookup: vertex_index → existing instruction (geometry_ref only)
     let mut existing_at: std::collections::HashMap<usize, &Instruction> =
         std::collections::HashMap::new();
-    for inst in existing {
-        if let Some(ref gr) = inst.geometry_ref {
-            if gr.kind == GeometryRefKind::VertexIndex {
-                if let Some(vi) = gr.vertex_index {
-                    existing_at.insert(vi as usize, inst);
-                }
+    for seg in segments {
+        for inst in seg.instructions {
+            if matches!(inst.kind, InstructionKind::Depart | InstructionKind::Arrive) {
+                continue;
+            }
+            if let Some(vi) = inst.geometry_ref.as_ref().and_then(ref_vertex_index) {
+                existing_at.insert(seg.vertex_offset + vi, inst);
             }
         }
     }
+    let transitions: std::collections::HashSet<usize> = segments
+        .iter()
+        .skip(1)
+        .map(|seg| seg.vertex_offset)
+        .collect();
 
     let n = vertices.len();
+    let line = Polyline::new(vertices);
+    let streets = edge_streets(n.saturating_sub(1), segments);
+    let street_after = |i: usize| streets.get(i).copied().flatten().map(str::to_string);
     let mut raw: Vec<DerivedInstruction> = Vec::new();
 
     // Depart at vertex 0
@@ -106,36 +260,62 @@ pub fn derive_instructions(
         kind: DerivedInstructionKind::Depart,
         vertex_index: 0,
         distance_to_next_m: 0.0,
-        street_name: None,
+        street_name: street_after(0),
     });
 
-    // Interior vertices
+    // Interior vertices. Bends sharp enough to be turns are gathered into runs of nearby
+    // vertices, each run becoming one maneuver.
+    let merge_m = config.min_instruction_distance_m.max(config.turn_window_m);
+    let mut run: Vec<(usize, f64)> = Vec::new();
     for i in 1..n.saturating_sub(1) {
+        if run
+            .last()
+            .is_some_and(|&(last, _)| line.cum[i] - line.cum[last] >= merge_m)
+        {
+            raw.extend(merge_turns(&run, &line, config, &street_after));
+            run.clear();
+        }
+        if transitions.contains(&i) {
+            raw.push(DerivedInstruction {
+                kind: DerivedInstructionKind::SegmentTransition,
+                vertex_index: i,
+                distance_to_next_m: 0.0,
+                street_name: None,
+            });
+            continue;
+        }
         if let Some(inst) = existing_at.get(&i) {
             raw.push(DerivedInstruction {
                 kind: nav_ir_kind_to_derived(inst.kind),
                 vertex_index: i,
                 distance_to_next_m: 0.0,
-                street_name: inst.street_name.clone(),
+                street_name: inst.street_name.clone().or_else(|| street_after(i)),
             });
             continue;
         }
 
-        let b1 = bearing(vertices[i - 1], vertices[i]);
-        let b2 = bearing(vertices[i], vertices[i + 1]);
-        let delta = normalize_delta(b2 - b1);
-
-        if delta.abs() < MIN_TURN_DEGREES {
+        let delta = line.heading_change(i, i, config.turn_window_m);
+        let junction = line.heading_change(i, i, config.turn_window_m * JUNCTION_WINDOW_SHARE);
+        let wide = line.turning(i, config.turn_window_m * CURVE_WINDOW_FACTOR);
+        if delta.abs() >= config.min_turn_degrees
+            && junction.abs() >= MIN_JUNCTION_SHARE * delta.abs()
+            && delta.abs() >= MIN_TURN_SHARE * wide.abs()
+        {
+            run.push((i, delta));
             continue;
         }
-
-        raw.push(DerivedInstruction {
-            kind: kind_from_delta(delta),
-            vertex_index: i,
-            distance_to_next_m: 0.0,
-            street_name: None,
-        });
+        let (before, after) = (streets[i - 1], streets[i]);
+        if before.is_some() && after.is_some() && before != after && run.is_empty() {
+            raw.push(DerivedInstruction {
+                kind: DerivedInstructionKind::Continue,
+                vertex_index: i,
+                distance_to_next_m: 0.0,
+                street_name: street_after(i),
+            });
+        }
     }
+    raw.extend(merge_turns(&run, &line, config, &street_after));
+    raw.sort_by_key(|inst| inst.vertex_index);
 
     // Arrive at last vertex
     raw.push(DerivedInstruction {
@@ -145,14 +325,38 @@ pub fn derive_instructions(
         street_name: None,
     });
 
-    fill_and_filter(raw, vertices)
+    fill_and_filter(raw, vertices, config.min_instruction_distance_m)
+}
+
+/// One maneuver for a run of nearby turn vertices: the heading change from before the first
+/// to after the last, at the sharpest vertex, onto the street after the last. A run whose
+/// bends cancel out (a jog left and right) is no maneuver at all.
+fn merge_turns(
+    run: &[(usize, f64)],
+    line: &Polyline,
+    config: &EngineConfig,
+    street_after: &dyn Fn(usize) -> Option<String>,
+) -> Option<DerivedInstruction> {
+    let (&(first, _), &(last, _)) = (run.first()?, run.last()?);
+    let delta = line.heading_change(first, last, config.turn_window_m);
+    if delta.abs() < config.min_turn_degrees {
+        return None;
+    }
+    let &(vertex_index, _) = run.iter().max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+    Some(DerivedInstruction {
+        kind: kind_from_delta(delta, config.min_turn_degrees),
+        vertex_index,
+        distance_to_next_m: 0.0,
+        street_name: street_after(last),
+    })
 }
 
 /// Assign `distance_to_next_m` from vertex distances, then filter instructions
-/// that are closer than `MIN_INSTRUCTION_DISTANCE_M` to each other (keep larger turn).
+/// that are closer than `min_distance_m` to each other (keep larger turn).
 fn fill_and_filter(
     instructions: Vec<DerivedInstruction>,
     vertices: &[Coordinate],
+    min_distance_m: f64,
 ) -> Vec<DerivedInstruction> {
     if instructions.len() <= 2 {
         return instructions;
@@ -185,14 +389,8 @@ fn fill_and_filter(
     let mut i = 0;
     while i < result.len() {
         let curr = &result[i];
-        let is_boundary = matches!(
-            curr.kind,
-            DerivedInstructionKind::Depart | DerivedInstructionKind::Arrive
-        );
-        if !is_boundary
-            && curr.distance_to_next_m < MIN_INSTRUCTION_DISTANCE_M
-            && i + 1 < result.len()
-        {
+        let is_boundary = turn_severity(curr.kind) == 5;
+        if !is_boundary && curr.distance_to_next_m < min_distance_m && i + 1 < result.len() {
             let next = &result[i + 1];
             // Keep whichever has higher severity
             if turn_severity(curr.kind) >= turn_severity(next.kind) {
@@ -221,7 +419,7 @@ mod tests {
     fn straight_road_yields_only_depart_arrive() {
         // Three collinear points going north — no turn
         let vertices = vec![coord(0.0, 0.0), coord(1.0, 0.0), coord(2.0, 0.0)];
-        let instructions = derive_instructions(&vertices, &[]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
         assert_eq!(instructions.len(), 2);
         assert_eq!(instructions[0].kind, DerivedInstructionKind::Depart);
         assert_eq!(instructions[1].kind, DerivedInstructionKind::Arrive);
@@ -231,7 +429,7 @@ mod tests {
     fn right_turn_yields_depart_turn_right_arrive() {
         // North then east — 90° right turn at vertex 1
         let vertices = vec![coord(0.0, 0.0), coord(1.0, 0.0), coord(1.0, 1.0)];
-        let instructions = derive_instructions(&vertices, &[]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
         assert_eq!(instructions.len(), 3);
         assert_eq!(instructions[0].kind, DerivedInstructionKind::Depart);
         assert_eq!(instructions[1].kind, DerivedInstructionKind::TurnRight);
@@ -243,10 +441,15 @@ mod tests {
     fn left_turn_yields_turn_left() {
         // North then west — left turn at vertex 1
         let vertices = vec![coord(0.0, 0.0), coord(1.0, 0.0), coord(1.0, -1.0)];
-        let instructions = derive_instructions(&vertices, &[]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
         let turn = instructions
             .iter()
-            .find(|i| !matches!(i.kind, DerivedInstructionKind::Depart | DerivedInstructionKind::Arrive))
+            .find(|i| {
+                !matches!(
+                    i.kind,
+                    DerivedInstructionKind::Depart | DerivedInstructionKind::Arrive
+                )
+            })
             .expect("should have a turn");
         assert!(matches!(
             turn.kind,
@@ -254,10 +457,231 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn segment_fraction_ref_places_existing_instruction() {
+        use nav_ir::InstructionId;
+        // Gentle bend at vertex 2 (below the turn threshold), announced by the route itself.
+        let vertices = vec![
+            coord(0.0, 0.0),
+            coord(1.0, 0.0),
+            coord(2.0, 0.1),
+            coord(3.0, 0.2),
+        ];
+        let existing = vec![Instruction {
+            id: InstructionId::new(),
+            coordinate: None,
+            geometry_ref: Some(GeometryRef {
+                kind: GeometryRefKind::SegmentFraction,
+                vertex_index: None,
+                seg_start_index: Some(1),
+                fraction: Some(0.9),
+            }),
+            kind: InstructionKind::TurnLeft,
+            distance_to_next_m: None,
+            street_name: Some("Dijkweg".into()),
+        }];
+        let instructions = derive_instructions(&vertices, &existing, &EngineConfig::default());
+        let turn = &instructions[1];
+        assert_eq!(turn.kind, DerivedInstructionKind::TurnLeft);
+        assert_eq!(turn.vertex_index, 2);
+        assert_eq!(turn.street_name.as_deref(), Some("Dijkweg"));
+    }
+
+    #[test]
+    fn segment_transition_at_each_segment_start() {
+        use nav_ir::InstructionId;
+        // Two segments chained at vertex 2; the second has its own turn at local vertex 1.
+        let vertices = vec![
+            coord(0.0, 0.0),
+            coord(0.01, 0.0),
+            coord(0.02, 0.0),
+            coord(0.03, 0.0),
+            coord(0.04, 0.0),
+        ];
+        let second = vec![
+            Instruction {
+                id: InstructionId::new(),
+                coordinate: None,
+                geometry_ref: Some(GeometryRef {
+                    kind: GeometryRefKind::VertexIndex,
+                    vertex_index: Some(0),
+                    seg_start_index: None,
+                    fraction: None,
+                }),
+                kind: InstructionKind::Depart,
+                distance_to_next_m: None,
+                street_name: None,
+            },
+            Instruction {
+                id: InstructionId::new(),
+                coordinate: None,
+                geometry_ref: Some(GeometryRef {
+                    kind: GeometryRefKind::VertexIndex,
+                    vertex_index: Some(1),
+                    seg_start_index: None,
+                    fraction: None,
+                }),
+                kind: InstructionKind::TurnRight,
+                distance_to_next_m: None,
+                street_name: Some("Kade".into()),
+            },
+        ];
+        let segments = [
+            SegmentInstructions {
+                vertex_offset: 0,
+                instructions: &[],
+                street_names: &[],
+            },
+            SegmentInstructions {
+                vertex_offset: 2,
+                instructions: &second,
+                street_names: &[],
+            },
+        ];
+        let instructions =
+            derive_route_instructions(&vertices, &segments, &EngineConfig::default());
+        let kinds: Vec<_> = instructions
+            .iter()
+            .map(|i| (i.kind, i.vertex_index))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (DerivedInstructionKind::Depart, 0),
+                (DerivedInstructionKind::SegmentTransition, 2),
+                (DerivedInstructionKind::TurnRight, 3),
+                (DerivedInstructionKind::Arrive, 4),
+            ]
+        );
+        assert_eq!(instructions[2].street_name.as_deref(), Some("Kade"));
+    }
+
+    /// Built polyline in a fixed direction: (heading change in degrees, length in meters).
+    fn path(legs: &[(f64, f64)]) -> Vec<Coordinate> {
+        let mut heading: f64 = 0.0;
+        let mut points = vec![coord(52.0, 5.0)];
+        for &(turn, length_m) in legs {
+            heading += turn;
+            let last = *points.last().unwrap();
+            let (east, north) = heading.to_radians().sin_cos();
+            points.push(coord(
+                last.latitude + north * length_m / 111_320.0,
+                last.longitude + east * length_m / (111_320.0 * last.latitude.to_radians().cos()),
+            ));
+        }
+        points
+    }
+
+    fn kinds(instructions: &[DerivedInstruction]) -> Vec<DerivedInstructionKind> {
+        instructions.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn corner_drawn_with_two_vertices_is_one_turn() {
+        use DerivedInstructionKind::*;
+        let vertices = path(&[(0.0, 200.0), (45.0, 6.0), (45.0, 200.0)]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
+        assert_eq!(kinds(&instructions), [Depart, TurnRight, Arrive]);
+    }
+
+    #[test]
+    fn jog_that_cancels_out_is_no_maneuver() {
+        use DerivedInstructionKind::*;
+        let vertices = path(&[(0.0, 200.0), (-45.0, 12.0), (45.0, 200.0)]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
+        assert_eq!(kinds(&instructions), [Depart, Arrive]);
+    }
+
+    #[test]
+    fn street_name_change_without_a_turn_is_a_continue() {
+        use DerivedInstructionKind::*;
+        let vertices = path(&[(0.0, 200.0), (5.0, 200.0), (90.0, 200.0)]);
+        let span = |start, end, name: &str| StreetSpan {
+            vertex_range: nav_ir::VertexRange { start, end },
+            name: name.into(),
+        };
+        let streets = [
+            span(0, 1, "Dorpsstraat"),
+            span(1, 2, "Kerkweg"),
+            span(2, 3, "Molenlaan"),
+        ];
+        let instructions = derive_route_instructions(
+            &vertices,
+            &[SegmentInstructions {
+                vertex_offset: 0,
+                instructions: &[],
+                street_names: &streets,
+            }],
+            &EngineConfig::default(),
+        );
+        let named: Vec<_> = instructions
+            .iter()
+            .map(|i| (i.kind, i.street_name.as_deref()))
+            .collect();
+        assert_eq!(
+            named,
+            [
+                (Depart, Some("Dorpsstraat")),
+                (Continue, Some("Kerkweg")),
+                (TurnRight, Some("Molenlaan")),
+                (Arrive, None),
+            ]
+        );
+    }
+
+    /// Derives every road in `tests/roads/*.json` (`vertices` as `[lat, lon]`, optional
+    /// `street_names` and `profile`) and checks the maneuvers against `expected`, each
+    /// "kind" or "kind street".
+    #[test]
+    fn curvy_road_fixtures_yield_only_real_maneuvers() {
+        #[derive(serde::Deserialize)]
+        struct Road {
+            vertices: Vec<(f64, f64)>,
+            #[serde(default)]
+            street_names: Vec<StreetSpan>,
+            #[serde(default)]
+            profile: crate::config::VehicleProfile,
+            expected: Vec<String>,
+        }
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/roads");
+        let mut paths: Vec<_> = std::fs::read_dir(&root)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        paths.sort();
+        assert!(!paths.is_empty());
+        for path in paths {
+            let road: Road =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            let vertices: Vec<_> = road
+                .vertices
+                .iter()
+                .map(|&(lat, lon)| coord(lat, lon))
+                .collect();
+            let instructions = derive_route_instructions(
+                &vertices,
+                &[SegmentInstructions {
+                    vertex_offset: 0,
+                    instructions: &[],
+                    street_names: &road.street_names,
+                }],
+                &EngineConfig::for_profile(road.profile),
+            );
+            let actual: Vec<String> = instructions
+                .iter()
+                .map(|i| match &i.street_name {
+                    Some(street) => format!("{} {street}", i.kind.as_str()),
+                    None => i.kind.as_str().to_string(),
+                })
+                .collect();
+            assert_eq!(actual, road.expected, "{}", path.display());
+        }
+    }
+
     #[test]
     fn distance_to_next_is_filled() {
         let vertices = vec![coord(0.0, 0.0), coord(1.0, 0.0), coord(1.0, 1.0)];
-        let instructions = derive_instructions(&vertices, &[]);
+        let instructions = derive_instructions(&vertices, &[], &EngineConfig::default());
         // Depart distance_to_next should be > 0
         assert!(instructions[0].distance_to_next_m > 0.0);
     }
@@ -1,54 +1,278 @@
-use nav_ir::{Coordinate, Route};
+use nav_ir::{Coordinate, OffRouteBehavior, Route, RouteSegment, SegmentIntent, WaypointKind};
 use polyline::decode_polyline;
 
-use crate::derive_instructions::{derive_instructions, haversine_distance};
-use crate::off_route::{distance_to_polyline, OFF_ROUTE_THRESHOLD_M};
-use crate::progress::{estimate_eta, remaining_distance};
+use crate::announcements::{AnnouncementPlanner, RoadClass};
+use crate::config::EngineConfig;
+use crate::constraints::ConstraintMonitor;
+use crate::derive_instructions::{
+    bearing, derive_route_instructions, haversine_distance, SegmentInstructions,
+};
+use crate::eta::EtaModel;
+use crate::filter::RouteFilter;
+use crate::index::PolylineIndex;
+use crate::matcher::MapMatcher;
+use crate::off_route::OffRouteDetector;
+use crate::rejoin::best_rejoin;
+use crate::snapshot::EngineSnapshot;
+use crate::stops::StopAdvisor;
 use crate::types::{
-    ConstraintAlert, DerivedInstruction, DerivedInstructionKind, NavigationState, OffRouteStatus,
+    BreakSuggestion, ConstraintAlert, DerivedInstruction, DerivedInstructionKind, EtaInterval,
+    GpsFix, LegEta, NavigationState, OffRouteStatus, RangeStatus, RejoinGuidance, RouteProgress,
+    WaypointProgress, WrongWayStatus,
 };
+use crate::waypoints::{destination_radius_m, WaypointTracker};
+use crate::wrong_way::WrongWayDetector;
+
+/// Absorbs float error when the projection clamps exactly onto an instruction vertex.
+const STEP_EPSILON_M: f64 = 0.01;
+/// Below this speed the fix's own bearing is ignored for matching (m/s).
+const MIN_BEARING_SPEED_MPS: f64 = 1.5;
 
 /// Runtime turn-by-turn navigation engine.
 ///
-/// Created from a `nav_ir::Route`. Call `update_position()` on each GPS fix
+/// Created from a `nav_ir::Route` and an `EngineConfig`. Call `update_position()` on each GPS fix
 /// to get a fresh `NavigationState`.
 pub struct NavigationEngine {
     route: Route,
+    config: EngineConfig,
     polyline_vertices: Vec<Coordinate>,
+    /// First chained-polyline vertex of each route segment.
+    segment_offsets: Vec<usize>,
+    index: PolylineIndex,
     instructions: Vec<DerivedInstruction>,
     total_distance_m: f64,
     current_step: usize,
     distance_traveled_m: f64,
+    /// Distance traveled on earlier routes of the same session (reroutes).
+    traveled_offset_m: f64,
+    /// Along-route distance of the last fix within the off-route threshold.
+    last_on_route_m: f64,
+    /// Last matched position on the polyline; `None` until the first fix (or restore).
+    progress: Option<RouteProgress>,
+    matcher: MapMatcher,
+    waypoints: WaypointTracker,
+    announcer: AnnouncementPlanner,
+    filter: RouteFilter,
+    eta: EtaModel,
+    /// Along-route distance of the end of every leg, flattened across segments.
+    leg_ends_m: Vec<f64>,
+    off_route: OffRouteDetector,
+    wrong_way: WrongWayDetector,
+    constraints: ConstraintMonitor,
+    stops: StopAdvisor,
+    /// Rejoin guidance of the last fix.
+    rejoin: Option<RejoinGuidance>,
 }
 
 impl NavigationEngine {
     /// Build a fresh engine from a route (starting at step 0).
-    pub fn new(route: Route) -> Self {
-        Self::new_with_state(route, 0, 0.0)
+    ///
+    /// `config` is adjusted for the route's `SnappingMode` before use.
+    pub fn new(route: Route, config: EngineConfig) -> Self {
+        Self::new_with_state(route, config, 0, 0.0)
     }
 
     /// Build an engine and restore prior step / distance state (for session resume).
-    pub fn new_with_state(route: Route, current_step: usize, distance_traveled_m: f64) -> Self {
-        let polyline_vertices = decode_route_vertices(&route);
-        let existing = route
+    pub fn new_with_state(
+        route: Route,
+        config: EngineConfig,
+        current_step: usize,
+        distance_traveled_m: f64,
+    ) -> Self {
+        let config = config.with_snapping_mode(route.policies.snapping_mode);
+        let (polyline_vertices, segment_offsets) = chain_segments(&route);
+        let per_segment: Vec<SegmentInstructions> = route
             .segments
-            .first()
-            .map(|s| s.instructions.as_slice())
-            .unwrap_or(&[]);
-        let instructions = derive_instructions(&polyline_vertices, existing);
-        let total_distance_m = route.metadata.total_distance_m.unwrap_or_else(|| {
-            (0..polyline_vertices.len().saturating_sub(1))
-                .map(|i| haversine_distance(polyline_vertices[i], polyline_vertices[i + 1]))
-                .sum()
-        });
+            .iter()
+            .zip(&segment_offsets)
+            .map(|(seg, &vertex_offset)| SegmentInstructions {
+                vertex_offset,
+                instructions: &seg.instructions,
+                street_names: &seg.street_names,
+            })
+            .collect();
+        let instructions = derive_route_instructions(&polyline_vertices, &per_segment, &config);
+        let index = PolylineIndex::build(&polyline_vertices);
+        let total_distance_m = route
+            .metadata
+            .total_distance_m
+            .unwrap_or_else(|| index.total_m());
+        let eta = EtaModel::build(
+            &route,
+            &polyline_vertices,
+            &segment_offsets,
+            &index,
+            total_distance_m,
+            &config,
+        );
+        let leg_ends_m = route
+            .segments
+            .iter()
+            .zip(&segment_offsets)
+            .flat_map(|(seg, &offset)| {
+                seg.legs
+                    .iter()
+                    .map(move |leg| offset + leg.vertex_range.end as usize)
+            })
+            .map(|vertex| index.distance_at_vertex(vertex))
+            .collect();
+        let constraints = ConstraintMonitor::build(&route, &segment_offsets, &index);
+        let mut waypoints =
+            WaypointTracker::build(&route, &polyline_vertices, &segment_offsets, &index);
+        waypoints.skip_to(distance_traveled_m);
         let clamped_step = current_step.min(instructions.len().saturating_sub(1));
+        // Resume matching from the current instruction; a fresh session matches globally.
+        let matcher = match instructions.get(clamped_step) {
+            Some(instr) if clamped_step > 0 => MapMatcher::resume_at(instr.vertex_index),
+            _ => MapMatcher::new(),
+        };
         Self {
             route,
+            config,
             polyline_vertices,
+            segment_offsets,
+            index,
             instructions,
             total_distance_m,
             current_step: clamped_step,
             distance_traveled_m,
+            traveled_offset_m: 0.0,
+            last_on_route_m: 0.0,
+            progress: None,
+            matcher,
+            waypoints,
+            announcer: AnnouncementPlanner::new(),
+            filter: RouteFilter::new(),
+            eta,
+            leg_ends_m,
+            off_route: OffRouteDetector::new(),
+            wrong_way: WrongWayDetector::new(),
+            constraints,
+            stops: StopAdvisor::new(),
+            rejoin: None,
+        }
+    }
+
+    /// Restore the exact matched position saved from `progress()` (session resume).
+    pub fn with_progress(mut self, progress: RouteProgress) -> Self {
+        let last_segment = self.polyline_vertices.len().saturating_sub(2);
+        let progress = RouteProgress {
+            segment_index: progress.segment_index.min(last_segment),
+            fraction: progress.fraction.clamp(0.0, 1.0),
+        };
+        self.matcher = MapMatcher::resume_at(progress.segment_index);
+        self.progress = Some(progress);
+        self.waypoints = WaypointTracker::build(
+            &self.route,
+            &self.polyline_vertices,
+            &self.segment_offsets,
+            &self.index,
+        );
+        self.last_on_route_m = self
+            .index
+            .distance_along(progress.segment_index, progress.fraction);
+        self.waypoints.skip_to(self.last_on_route_m);
+        self
+    }
+
+    /// Count `offset_m` of earlier routes into `distance_traveled_m` (after a reroute).
+    ///
+    /// `distance_traveled_m` passed to `new_with_state` is then the distance on this route
+    /// only.
+    pub fn with_traveled_offset(mut self, offset_m: f64) -> Self {
+        self.traveled_offset_m = offset_m.max(0.0);
+        self.distance_traveled_m += self.traveled_offset_m;
+        self
+    }
+
+    /// Carry the alerts active on the previous route over (after a reroute), so the next
+    /// update only raises and clears what actually changed.
+    pub fn with_constraint_alerts(mut self, active: Vec<ConstraintAlert>) -> Self {
+        self.constraints.restore(active, None);
+        self
+    }
+
+    /// Start with `range_m` left instead of a full tank.
+    pub fn with_range_left(mut self, range_m: f64) -> Self {
+        self.stops.set_range_left(range_m);
+        self
+    }
+
+    /// Carry fuel range and riding time over from the previous route (after a reroute).
+    pub fn with_stops(mut self, stops: StopAdvisor) -> Self {
+        self.stops = stops;
+        self
+    }
+
+    /// Rebuild an engine for `route` and continue exactly where `snapshot` was taken.
+    ///
+    /// Fails when the snapshot has another format version, was taken on another route or
+    /// doesn't fit this route's geometry; callers then fall back to `new_with_state`.
+    pub fn from_snapshot(
+        route: Route,
+        config: EngineConfig,
+        snapshot: EngineSnapshot,
+    ) -> Result<Self, String> {
+        if snapshot.version != EngineSnapshot::VERSION {
+            return Err(format!(
+                "unsupported engine snapshot version: {} (supported: {})",
+                snapshot.version,
+                EngineSnapshot::VERSION
+            ));
+        }
+        if snapshot.route_id != route.id {
+            return Err("engine snapshot was taken on another route".to_string());
+        }
+        let mut engine = Self::new(route, config);
+        let segments = engine.polyline_vertices.len().saturating_sub(1);
+        if snapshot.current_step >= engine.instructions.len().max(1)
+            || snapshot
+                .progress
+                .is_some_and(|p| p.segment_index >= segments.max(1))
+        {
+            return Err("engine snapshot does not fit the route geometry".to_string());
+        }
+        engine.current_step = snapshot.current_step;
+        engine.distance_traveled_m = snapshot.distance_traveled_m;
+        engine.traveled_offset_m = snapshot.traveled_offset_m;
+        engine.last_on_route_m = snapshot.last_on_route_m;
+        engine.progress = snapshot.progress;
+        engine.matcher = snapshot.matcher;
+        engine.waypoints.set_passed(snapshot.waypoints_passed);
+        engine.announcer = snapshot.announcer;
+        engine.filter = snapshot.filter;
+        engine.eta.restore(snapshot.eta);
+        engine.off_route = snapshot.off_route;
+        engine.wrong_way = snapshot.wrong_way;
+        engine
+            .constraints
+            .restore(snapshot.constraint_alerts, snapshot.speed_limit_kmh);
+        engine.stops = snapshot.stops;
+        engine.rejoin = snapshot.rejoin;
+        Ok(engine)
+    }
+
+    /// Everything learned from the fixes so far, for persisting with the session.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            version: EngineSnapshot::VERSION,
+            route_id: self.route.id,
+            current_step: self.current_step,
+            distance_traveled_m: self.distance_traveled_m,
+            traveled_offset_m: self.traveled_offset_m,
+            last_on_route_m: self.last_on_route_m,
+            progress: self.progress,
+            matcher: self.matcher.clone(),
+            waypoints_passed: self.waypoints.passed(),
+            announcer: self.announcer.clone(),
+            filter: self.filter.clone(),
+            eta: self.eta.state(),
+            off_route: self.off_route.clone(),
+            wrong_way: self.wrong_way.clone(),
+            constraint_alerts: self.constraints.active().to_vec(),
+            speed_limit_kmh: self.constraints.speed_limit_kmh(),
+            stops: self.stops.clone(),
+            rejoin: self.rejoin.clone(),
         }
     }
 
@@ -56,29 +280,238 @@ impl NavigationEngine {
     ///
     /// `speed_mps`: optional GPS speed for ETA calculation.
     pub fn update_position(&mut self, pos: Coordinate, speed_mps: Option<f64>) -> NavigationState {
+        self.update_fix(GpsFix {
+            speed_mps,
+            ..GpsFix::new(pos)
+        })
+    }
+
+    /// Process a full GPS fix and return the current navigation state.
+    ///
+    /// Timed fixes are smoothed along the route, weighted by their accuracy. A fix less
+    /// accurate than `max_fix_accuracy_m` is replaced by dead reckoning while the last
+    /// estimate is recent enough; the state is then flagged `estimated`.
+    pub fn update_fix(&mut self, fix: GpsFix) -> NavigationState {
         if self.polyline_vertices.is_empty() {
-            return self.fallback_state(pos);
+            return self.fallback_state(fix.coordinate);
+        }
+        let inaccurate = fix
+            .accuracy_m
+            .is_some_and(|a| a > self.config.max_fix_accuracy_m);
+        if inaccurate {
+            if let Some(state) = fix.timestamp_ms.and_then(|ts| self.estimate_position(ts)) {
+                return state;
+            }
+        }
+
+        // GPS course is noise at walking pace and below.
+        let heading_hint = fix
+            .bearing_deg
+            .filter(|_| fix.speed_mps.is_some_and(|v| v >= MIN_BEARING_SPEED_MPS));
+        let matched = self.matcher.match_position_with_heading(
+            fix.coordinate,
+            heading_hint,
+            &self.polyline_vertices,
+            &self.index,
+            &self.config,
+        );
+        let exceeds = matched.distance_m > self.config.off_route_threshold_m;
+        self.off_route.update(
+            matched.distance_m,
+            fix.accuracy_m,
+            fix.timestamp_ms,
+            &self.config,
+        );
+        let measured_m = self
+            .index
+            .distance_along(matched.segment_index, matched.fraction);
+        let segment_end = self.polyline_vertices.get(matched.segment_index + 1);
+        match segment_end {
+            Some(&end) if !exceeds && !self.off_route.is_confirmed() => {
+                let start = self.polyline_vertices[matched.segment_index];
+                self.wrong_way.update(
+                    matched.heading_deg,
+                    bearing(start, end),
+                    measured_m,
+                    &self.config,
+                );
+            }
+            _ => self.wrong_way.reset(),
         }
+        let wrong_way = self.wrong_way.is_confirmed();
+        let (progress, snapped) = if exceeds || wrong_way || fix.timestamp_ms.is_none() {
+            // Off the route the projection is not a position estimate, and the filter only
+            // models forward motion; don't smooth either.
+            self.filter.reset();
+            let progress = RouteProgress {
+                segment_index: matched.segment_index,
+                fraction: matched.fraction,
+            };
+            (progress, matched.snapped)
+        } else {
+            let along_m =
+                self.filter
+                    .update(measured_m, fix.accuracy_m, fix.speed_mps, fix.timestamp_ms);
+            let progress = self.progress_at(along_m);
+            (progress, self.point_at(progress))
+        };
+        if exceeds || wrong_way || self.off_route.is_confirmed() {
+            self.eta.forget_position();
+        } else {
+            let along_m = self
+                .index
+                .distance_along(progress.segment_index, progress.fraction);
+            self.eta
+                .observe(along_m, fix.speed_mps, fix.timestamp_ms, &self.config);
+        }
+        let mut state = self.state_at(&fix, progress, snapped, matched.distance_m, false);
+        if self.off_route.is_confirmed()
+            && state.off_route.behavior != OffRouteBehavior::SnapToRoute
+        {
+            state.rejoin = self.find_rejoin(fix.coordinate, matched.heading_deg);
+        }
+        self.rejoin = state.rejoin.clone();
+        state
+    }
+
+    /// Rejoin guidance while off a `FixedGeometry` segment, whose track a reroute would discard.
+    /// Points at the nearest stretch of that segment ahead of where the rider left it.
+    fn find_rejoin(&self, pos: Coordinate, heading_deg: Option<f64>) -> Option<RejoinGuidance> {
+        let (left_at, _) = self.index.progress_at_distance(self.last_on_route_m);
+        let route_segment = self.segment_at(left_at);
+        if self.route.segments.get(route_segment)?.intent != SegmentIntent::FixedGeometry {
+            return None;
+        }
+        let last_vertex = self
+            .segment_offsets
+            .get(route_segment + 1)
+            .copied()
+            .unwrap_or(self.polyline_vertices.len() - 1);
+        best_rejoin(
+            pos,
+            heading_deg,
+            self.last_on_route_m,
+            last_vertex.saturating_sub(1),
+            &self.polyline_vertices,
+            &self.index,
+            &self.config,
+        )
+    }
 
-        let (dist_from_route, nearest_vertex, snapped) =
-            distance_to_polyline(pos, &self.polyline_vertices);
+    /// Dead-reckon along the route to `timestamp_ms` when no usable fix arrived (tunnels,
+    /// urban canyons). `None` when there is no estimate from the last
+    /// [`MAX_DEAD_RECKONING_S`](crate::filter::MAX_DEAD_RECKONING_S) seconds.
+    pub fn estimate_position(&mut self, timestamp_ms: u64) -> Option<NavigationState> {
+        let along_m = self
+            .filter
+            .extrapolate(timestamp_ms)?
+            .min(self.index.total_m());
+        let progress = self.progress_at(along_m);
+        let pos = self.point_at(progress);
+        let estimate = GpsFix {
+            speed_mps: self.filter.speed_mps(),
+            timestamp_ms: Some(timestamp_ms),
+            ..GpsFix::new(pos)
+        };
+        Some(self.state_at(&estimate, progress, pos, 0.0, true))
+    }
+
+    /// State at `progress` for `fix`, which is the dead-reckoned position when `estimated`.
+    fn state_at(
+        &mut self,
+        fix: &GpsFix,
+        progress: RouteProgress,
+        snapped: Coordinate,
+        dist_from_route: f64,
+        estimated: bool,
+    ) -> NavigationState {
+        let pos = fix.coordinate;
+        let speed_mps = fix.speed_mps;
+        self.progress = Some(progress);
+        // Measure from the projected point, not the nearest vertex.
+        let along_m = self
+            .index
+            .distance_along(progress.segment_index, progress.fraction);
+
+        if dist_from_route <= self.config.off_route_threshold_m {
+            self.last_on_route_m = along_m;
+        }
 
         // Advance step: keep advancing while the next instruction's vertex is behind us
         while self.current_step + 1 < self.instructions.len()
-            && nearest_vertex >= self.instructions[self.current_step + 1].vertex_index
+            && along_m + STEP_EPSILON_M
+                >= self
+                    .index
+                    .distance_at_vertex(self.instructions[self.current_step + 1].vertex_index)
         {
             self.current_step += 1;
         }
 
-        let remaining_m = remaining_distance(&self.polyline_vertices, nearest_vertex);
-        self.distance_traveled_m = (self.total_distance_m - remaining_m).max(0.0);
+        let active_segment = self.segment_at(progress.segment_index);
+        let segment = self.route.segments.get(active_segment);
 
-        let eta = estimate_eta(
-            remaining_m,
+        let remaining_m = (self.index.total_m() - along_m).max(0.0);
+        self.distance_traveled_m =
+            self.traveled_offset_m + (self.total_distance_m - remaining_m).max(0.0);
+
+        let total_m = self.index.total_m();
+        let eta = self.eta.eta_s(along_m, total_m);
+        let eta_interval = self.eta.interval(along_m, total_m);
+
+        let reached = self.waypoints.update(pos, along_m);
+        let reached_waypoints: Vec<usize> = reached.iter().map(|wp| wp.index).collect();
+        let upcoming_waypoints: Vec<WaypointProgress> = self.waypoints.waypoints()
+            [self.waypoints.passed()..]
+            .iter()
+            .map(|wp| WaypointProgress {
+                index: wp.index,
+                kind: wp.kind,
+                name: wp.name.clone(),
+                distance_m: (wp.along_m - along_m).max(0.0),
+                eta_seconds: self.eta.eta_s(along_m, wp.along_m),
+            })
+            .collect();
+        let leg_etas = self
+            .leg_ends_m
+            .iter()
+            .enumerate()
+            .filter(|&(_, &end_m)| end_m > along_m)
+            .map(|(index, &end_m)| LegEta {
+                index,
+                distance_m: end_m - along_m,
+                eta_seconds: self.eta.eta_s(along_m, end_m),
+            })
+            .collect();
+        let next_of = |kind| {
+            upcoming_waypoints
+                .iter()
+                .find(|wp: &&WaypointProgress| wp.kind == kind)
+                .cloned()
+        };
+        let next_fuel = next_of(WaypointKind::Fuel);
+        let next_break = next_of(WaypointKind::Break);
+        let reached_kinds: Vec<WaypointKind> = reached.iter().map(|wp| wp.kind).collect();
+        self.stops.update(
+            self.distance_traveled_m,
+            fix.timestamp_ms,
             speed_mps,
-            self.route.metadata.estimated_duration_s,
-            self.total_distance_m,
+            &reached_kinds,
+            next_fuel.as_ref().map_or(remaining_m, |wp| wp.distance_m),
+            &self.config,
         );
+        let range = self.stops.range_left_m().map(|range_left_m| RangeStatus {
+            range_left_m,
+            next_fuel,
+            low: self.stops.low_range(),
+            entered: self.stops.low_range_entered(),
+        });
+        let break_suggestion = self.stops.break_due().then(|| BreakSuggestion {
+            riding_s: self.stops.riding_s() as u64,
+            next_break,
+            entered: self.stops.break_entered(),
+        });
+        let arrived = self.waypoints.finished()
+            && remaining_m <= destination_radius_m(&self.waypoints, self.index.total_m());
 
         let current_instruction = self
             .instructions
@@ -88,8 +521,39 @@ impl NavigationEngine {
 
         let next_instruction = self.instructions.get(self.current_step + 1).cloned();
 
-        let distance_to_next_m =
-            distance_to_next(&self.polyline_vertices, nearest_vertex, &next_instruction, remaining_m);
+        let distance_to_next_m = match &next_instruction {
+            Some(next) => (self.index.distance_at_vertex(next.vertex_index) - along_m).max(0.0),
+            None => remaining_m,
+        };
+
+        let left_route = self.off_route.is_confirmed();
+        let wrong_way = self.wrong_way.is_confirmed();
+        // Dead reckoning keeps the alerts of the last fix: nothing reports its transitions.
+        if !estimated {
+            let on_road = !left_route && dist_from_route <= self.config.off_route_threshold_m;
+            self.constraints
+                .update(along_m, active_segment, speed_mps, on_road, &self.config);
+        }
+        // Riding the route backwards needs a new route (or a U-turn) just like leaving it.
+        let is_off_route = left_route || wrong_way;
+        let behavior = off_route_behavior(self.route.policies.off_route_behavior, segment);
+        // SnapToRoute keeps guiding along the route as if the rider were on it. Otherwise
+        // there are no maneuver prompts while off the route; they would point the wrong way.
+        let on_track = !left_route || behavior == OffRouteBehavior::SnapToRoute;
+        let guiding = on_track && !wrong_way;
+        let announcements = if !guiding || next_instruction.is_none() {
+            vec![]
+        } else {
+            let speed = speed_mps.unwrap_or(self.config.default_speed_mps);
+            let road = RoadClass::infer(segment.and_then(|s| s.constraints.max_speed_kmh), speed);
+            self.announcer.plan(
+                &self.instructions,
+                self.current_step + 1,
+                distance_to_next_m,
+                speed,
+                road,
+            )
+        };
 
         NavigationState {
             current_step: self.current_step,
@@ -98,16 +562,72 @@ impl NavigationEngine {
             distance_to_next_m,
             distance_remaining_m: remaining_m,
             eta_seconds: eta,
+            eta_interval,
             off_route: OffRouteStatus {
-                is_off_route: dist_from_route > OFF_ROUTE_THRESHOLD_M,
+                is_off_route,
+                exceeds_threshold: dist_from_route > self.config.off_route_threshold_m,
+                entered: !estimated && (self.off_route.entered() || self.wrong_way.entered()),
                 distance_from_route_m: dist_from_route,
-                behavior: self.route.policies.off_route_behavior,
+                behavior,
+            },
+            constraint_alerts: self.constraints.active().to_vec(),
+            raised_alerts: if estimated {
+                vec![]
+            } else {
+                self.constraints.raised().to_vec()
+            },
+            cleared_alerts: if estimated {
+                vec![]
+            } else {
+                self.constraints.cleared().to_vec()
+            },
+            speed_limit_kmh: self.constraints.speed_limit_kmh(),
+            active_segment,
+            segment_intent: segment.map(|s| s.intent),
+            next_waypoint: upcoming_waypoints.first().cloned(),
+            upcoming_waypoints,
+            leg_etas,
+            waypoints_passed: self.waypoints.passed(),
+            reached_waypoints,
+            arrived,
+            announcements,
+            estimated,
+            rejoin: None,
+            wrong_way: wrong_way.then(|| WrongWayStatus {
+                instruction: DerivedInstruction {
+                    kind: DerivedInstructionKind::UTurn,
+                    vertex_index: progress.segment_index,
+                    distance_to_next_m: 0.0,
+                    street_name: None,
+                },
+                distance_m: self.wrong_way.backtracked_m(),
+                entered: !estimated && self.wrong_way.entered(),
+            }),
+            range,
+            break_suggestion,
+            snapped_position: if self.config.snap_to_route && on_track {
+                snapped
+            } else {
+                pos
             },
-            constraint_alerts: build_alerts(&self.route),
-            snapped_position: snapped,
         }
     }
 
+    /// First chained-polyline vertex of each route segment (parallel to `route().segments`).
+    pub fn segment_offsets(&self) -> &[usize] {
+        &self.segment_offsets
+    }
+
+    /// Route this engine navigates.
+    pub fn route(&self) -> &Route {
+        &self.route
+    }
+
+    /// Effective configuration (profile preset adjusted for the route's snapping mode).
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
     pub fn current_step(&self) -> usize {
         self.current_step
     }
@@ -116,6 +636,40 @@ impl NavigationEngine {
         self.distance_traveled_m
     }
 
+    /// Chained polyline of all route segments.
+    pub(crate) fn polyline_vertices(&self) -> &[Coordinate] {
+        &self.polyline_vertices
+    }
+
+    pub(crate) fn index(&self) -> &PolylineIndex {
+        &self.index
+    }
+
+    /// Route segment containing polyline segment `polyline_segment`.
+    pub(crate) fn route_segment_at(&self, polyline_segment: usize) -> usize {
+        self.segment_at(polyline_segment)
+    }
+
+    /// Rejoin guidance from the last fix; `None` unless in rejoin mode.
+    pub fn rejoin(&self) -> Option<&RejoinGuidance> {
+        self.rejoin.as_ref()
+    }
+
+    /// Fuel range and riding time after the last update.
+    pub fn stops(&self) -> &StopAdvisor {
+        &self.stops
+    }
+
+    /// Constraint alerts in force after the last update.
+    pub fn constraint_alerts(&self) -> &[ConstraintAlert] {
+        self.constraints.active()
+    }
+
+    /// Matched position on the polyline, for persisting with the session.
+    pub fn progress(&self) -> Option<RouteProgress> {
+        self.progress
+    }
+
     /// Return all derived instructions for this route (for turn-feed display).
     pub fn instructions(&self) -> &[DerivedInstruction] {
         &self.instructions
@@ -129,41 +683,104 @@ impl NavigationEngine {
             distance_to_next_m: 0.0,
             distance_remaining_m: 0.0,
             eta_seconds: 0,
+            eta_interval: EtaInterval::default(),
             off_route: OffRouteStatus {
                 is_off_route: false,
+                exceeds_threshold: false,
+                entered: false,
                 distance_from_route_m: 0.0,
                 behavior: self.route.policies.off_route_behavior,
             },
             constraint_alerts: vec![],
+            raised_alerts: vec![],
+            cleared_alerts: vec![],
+            speed_limit_kmh: None,
             snapped_position: pos,
+            active_segment: 0,
+            segment_intent: self.route.segments.first().map(|s| s.intent),
+            next_waypoint: None,
+            upcoming_waypoints: vec![],
+            leg_etas: vec![],
+            waypoints_passed: 0,
+            reached_waypoints: vec![],
+            arrived: false,
+            announcements: vec![],
+            estimated: false,
+            rejoin: None,
+            wrong_way: None,
+            range: None,
+            break_suggestion: None,
         }
     }
-}
 
-fn decode_route_vertices(route: &Route) -> Vec<Coordinate> {
-    route
-        .segments
-        .first()
-        .and_then(|seg| decode_polyline(&seg.geometry.polyline.0, 5).ok())
-        .map(|line| line.coords().map(|c| Coordinate::new(c.y, c.x)).collect())
-        .unwrap_or_default()
-}
+    fn progress_at(&self, along_m: f64) -> RouteProgress {
+        let (segment_index, fraction) = self.index.progress_at_distance(along_m);
+        RouteProgress {
+            segment_index,
+            fraction,
+        }
+    }
 
-fn build_alerts(route: &Route) -> Vec<ConstraintAlert> {
-    let Some(seg) = route.segments.first() else {
-        return vec![];
-    };
-    let mut alerts = Vec::new();
-    if let Some(max) = seg.constraints.max_speed_kmh {
-        alerts.push(ConstraintAlert::SpeedLimit { max_kmh: max });
+    /// Point on the polyline at `progress` (linear between vertices).
+    fn point_at(&self, progress: RouteProgress) -> Coordinate {
+        let a = self.polyline_vertices[progress.segment_index];
+        let b = self
+            .polyline_vertices
+            .get(progress.segment_index + 1)
+            .copied()
+            .unwrap_or(a);
+        let t = progress.fraction;
+        Coordinate::new(
+            a.latitude + (b.latitude - a.latitude) * t,
+            a.longitude + (b.longitude - a.longitude) * t,
+        )
     }
-    if seg.constraints.avoid_highways {
-        alerts.push(ConstraintAlert::AvoidHighway);
+
+    /// Route segment containing polyline segment `polyline_segment`.
+    fn segment_at(&self, polyline_segment: usize) -> usize {
+        self.segment_offsets
+            .partition_point(|&o| o <= polyline_segment)
+            .saturating_sub(1)
+    }
+}
+
+/// Consecutive segments whose endpoints are closer than this share the junction vertex.
+const JUNCTION_MERGE_M: f64 = 1.0;
+
+/// Decode every segment and chain them into one polyline.
+///
+/// Returns the chained vertices and, per segment, the index of its first vertex.
+fn chain_segments(route: &Route) -> (Vec<Coordinate>, Vec<usize>) {
+    let mut vertices: Vec<Coordinate> = Vec::new();
+    let mut offsets = Vec::with_capacity(route.segments.len());
+    for seg in &route.segments {
+        let decoded: Vec<Coordinate> = decode_polyline(&seg.geometry.polyline.0, 5)
+            .map(|line| line.coords().map(|c| Coordinate::new(c.y, c.x)).collect())
+            .unwrap_or_default();
+        let joins = matches!(
+            (vertices.last(), decoded.first()),
+            (Some(&last), Some(&first)) if haversine_distance(last, first) < JUNCTION_MERGE_M
+        );
+        if joins || decoded.is_empty() {
+            offsets.push(vertices.len().saturating_sub(1));
+            vertices.extend(decoded.into_iter().skip(1));
+        } else {
+            offsets.push(vertices.len());
+            vertices.extend(decoded);
+        }
     }
-    if seg.constraints.avoid_tolls {
-        alerts.push(ConstraintAlert::AvoidToll);
+    (vertices, offsets)
+}
+
+/// Route policy adjusted for the active segment: geometry that must not be recalculated
+/// (`FixedGeometry`, or `allow_reroute == false`) downgrades `Recalculate` to `AlertOnly`.
+fn off_route_behavior(policy: OffRouteBehavior, seg: Option<&RouteSegment>) -> OffRouteBehavior {
+    let no_reroute = seg
+        .is_some_and(|s| s.intent == SegmentIntent::FixedGeometry || !s.constraints.allow_reroute);
+    match policy {
+        OffRouteBehavior::Recalculate if no_reroute => OffRouteBehavior::AlertOnly,
+        other => other,
     }
-    alerts
 }
 
 fn arrive_stub(vertex_index: usize) -> DerivedInstruction {
@@ -175,26 +792,10 @@ fn arrive_stub(vertex_index: usize) -> DerivedInstruction {
     }
 }
 
-fn distance_to_next(
-    vertices: &[Coordinate],
-    from_vertex: usize,
-    next: &Option<DerivedInstruction>,
-    remaining_m: f64,
-) -> f64 {
-    let Some(next) = next else {
-        return remaining_m;
-    };
-    if next.vertex_index <= from_vertex {
-        return 0.0;
-    }
-    let rem_here = remaining_distance(vertices, from_vertex);
-    let rem_at_next = remaining_distance(vertices, next.vertex_index);
-    (rem_here - rem_at_next).max(0.0)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::AnnouncementStage;
     use chrono::Utc;
     use nav_ir::*;
 
@@ -253,6 +854,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         }
@@ -272,7 +875,7 @@ mod tests {
         // Straight route: 0° → 1° → 2° north (no turns, just Depart + Arrive)
         let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
         let route = make_route_with_vertices(&poly);
-        let mut engine = NavigationEngine::new(route);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
 
         // Start at beginning
         let s0 = engine.update_position(Coordinate::new(0.0, 0.0), None);
@@ -289,19 +892,151 @@ mod tests {
         // North-going polyline; position is far east (≫ 50 m off)
         let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0)]);
         let route = make_route_with_vertices(&poly);
-        let mut engine = NavigationEngine::new(route);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
 
         // 0.01 degrees east at equator ≈ 1.1 km off route
-        let state = engine.update_position(Coordinate::new(0.5, 0.01), None);
+        let pos = Coordinate::new(0.5, 0.01);
+        let first = engine.update_position(pos, None);
+        assert!(first.off_route.exceeds_threshold);
+        assert!(
+            !first.off_route.is_off_route,
+            "one fix is not enough to confirm"
+        );
+        engine.update_position(pos, None);
+        let state = engine.update_position(pos, None);
         assert!(state.off_route.is_off_route, "expected off-route");
-        assert!(state.off_route.distance_from_route_m > OFF_ROUTE_THRESHOLD_M);
+        assert!(state.off_route.entered);
+        assert!(state.off_route.distance_from_route_m > engine.config().off_route_threshold_m);
+        assert!(!engine.update_position(pos, None).off_route.entered);
+    }
+
+    #[test]
+    fn off_route_behaviors_differ_once_confirmed() {
+        let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0)]);
+        let pos = Coordinate::new(0.5, 0.01);
+        let confirmed = |behavior| {
+            let mut route = make_route_with_vertices(&poly);
+            route.policies.off_route_behavior = behavior;
+            let mut engine = NavigationEngine::new(route, EngineConfig::default());
+            engine.update_position(pos, None);
+            engine.update_position(pos, None);
+            engine.update_position(pos, None)
+        };
+
+        // SnapToRoute keeps presenting the rider on the route.
+        let snap = confirmed(OffRouteBehavior::SnapToRoute);
+        assert!(snap.off_route.is_off_route);
+        assert!(snap.snapped_position.longitude.abs() < 1e-6);
+
+        // AlertOnly shows where the rider really is.
+        let alert = confirmed(OffRouteBehavior::AlertOnly);
+        assert!(alert.off_route.is_off_route);
+        assert!((alert.snapped_position.longitude - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fixed_geometry_detour_gets_rejoin_guidance() {
+        // North-going track, ~1.1 km.
+        let poly = encode_points(&[(0.0, 0.0), (0.005, 0.0), (0.01, 0.0)]);
+        let mut route = make_route_with_vertices(&poly);
+        route.segments[0].intent = SegmentIntent::FixedGeometry;
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+        engine.update_position(Coordinate::new(0.002, 0.0), None);
+
+        // Detour ~200 m east, riding north past the exit point.
+        let mut state = engine.update_position(Coordinate::new(0.003, 0.0018), None);
+        assert!(state.rejoin.is_none(), "not confirmed yet");
+        for lat in [0.004, 0.005, 0.006] {
+            state = engine.update_position(Coordinate::new(lat, 0.0018), None);
+        }
+        assert!(state.off_route.is_off_route);
+        assert_eq!(state.off_route.behavior, OffRouteBehavior::AlertOnly);
+        let rejoin = state.rejoin.expect("rejoin mode");
+        assert!((rejoin.point.latitude - 0.006).abs() < 1e-5);
+        assert!(
+            (rejoin.distance_m - 200.0).abs() < 5.0,
+            "{}",
+            rejoin.distance_m
+        );
+        assert_eq!(rejoin.direction, Some(DerivedInstructionKind::TurnLeft));
+
+        // Back on the track further ahead: normal guidance resumes there.
+        let mut back = engine.update_position(Coordinate::new(0.008, 0.0), None);
+        for _ in 0..3 {
+            back = engine.update_position(Coordinate::new(0.008, 0.0), None);
+        }
+        assert!(!back.off_route.is_off_route);
+        assert!(back.rejoin.is_none());
+        assert!(
+            back.distance_remaining_m < 250.0,
+            "{}",
+            back.distance_remaining_m
+        );
+    }
+
+    #[test]
+    fn riding_backwards_asks_for_a_u_turn() {
+        let poly = encode_points(&[(0.0, 0.0), (0.005, 0.0), (0.01, 0.0)]);
+        let mut engine =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        let mut t_ms = 0;
+        let mut ride = |engine: &mut NavigationEngine, lat: f64, bearing_deg: f64| {
+            t_ms += 1_000;
+            engine.update_fix(GpsFix {
+                bearing_deg: Some(bearing_deg),
+                speed_mps: Some(11.0),
+                timestamp_ms: Some(t_ms),
+                ..GpsFix::new(Coordinate::new(lat, 0.0))
+            })
+        };
+        for i in 0..=10 {
+            let state = ride(&mut engine, 0.002 + i as f64 * 0.0001, 0.0);
+            assert!(state.wrong_way.is_none());
+        }
+
+        // Turned around: ~11 m back per fix.
+        let states: Vec<NavigationState> = (1..=5)
+            .map(|i| ride(&mut engine, 0.003 - i as f64 * 0.0001, 180.0))
+            .collect();
+        assert!(states[1].wrong_way.is_none(), "two fixes are not sustained");
+        let entered: Vec<_> = states.iter().map(|s| s.off_route.entered).collect();
+        assert_eq!(entered, [false, false, true, false, false]);
+        let last = states.last().unwrap();
+        let wrong_way = last.wrong_way.as_ref().expect("wrong way");
+        assert_eq!(wrong_way.instruction.kind, DerivedInstructionKind::UTurn);
+        assert!(wrong_way.distance_m > 50.0, "{}", wrong_way.distance_m);
+        assert!(last.off_route.is_off_route, "feeds the reroute decision");
+        assert!(!last.off_route.exceeds_threshold);
+        assert!(last.announcements.is_empty());
+        assert!(
+            (last.snapped_position.latitude - 0.0025).abs() < 1e-6,
+            "still matched where the rider is, not smoothed forward"
+        );
+
+        // U-turn made: back to normal guidance.
+        let state = ride(&mut engine, 0.0026, 0.0);
+        assert!(state.wrong_way.is_none());
+        assert!(!state.off_route.is_off_route);
+    }
+
+    #[test]
+    fn traveled_offset_carries_over_reroute() {
+        let poly = encode_points(&[(0.0, 0.0), (0.01, 0.0)]);
+        let mut route = make_route_with_vertices(&poly);
+        route.metadata.total_distance_m = None;
+        let mut engine =
+            NavigationEngine::new(route, EngineConfig::default()).with_traveled_offset(5_000.0);
+        assert_eq!(engine.distance_traveled_m(), 5_000.0);
+        engine.update_position(Coordinate::new(0.005, 0.0), None);
+        let traveled = engine.distance_traveled_m();
+        assert!((traveled - 5_556.0).abs() < 5.0, "{traveled}");
     }
 
     #[test]
     fn on_route_not_flagged_when_close_to_polyline() {
         let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0)]);
         let route = make_route_with_vertices(&poly);
-        let mut engine = NavigationEngine::new(route);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
 
         // Very close to the polyline
         let state = engine.update_position(Coordinate::new(0.5, 0.0001), None);
@@ -312,17 +1047,413 @@ mod tests {
     fn new_with_state_restores_step() {
         let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
         let route = make_route_with_vertices(&poly);
-        let engine = NavigationEngine::new_with_state(route, 1, 111_000.0);
+        let engine = NavigationEngine::new_with_state(route, EngineConfig::default(), 1, 111_000.0);
         assert_eq!(engine.current_step(), 1);
         assert!((engine.distance_traveled_m() - 111_000.0).abs() < 1.0);
     }
 
+    #[test]
+    fn strict_snapping_flags_off_route_earlier() {
+        // ~40 m east of a north-going polyline: inside Relaxed (50 m), outside Strict (30 m).
+        let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0)]);
+        let pos = Coordinate::new(0.5, 0.00036);
+
+        let mut relaxed =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        assert!(
+            !relaxed
+                .update_position(pos, None)
+                .off_route
+                .exceeds_threshold
+        );
+
+        let mut route = make_route_with_vertices(&poly);
+        route.policies.snapping_mode = SnappingMode::Strict;
+        let mut strict = NavigationEngine::new(route, EngineConfig::default());
+        assert!(
+            strict
+                .update_position(pos, None)
+                .off_route
+                .exceeds_threshold
+        );
+    }
+
+    #[test]
+    fn snapping_off_reports_raw_position() {
+        let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0)]);
+        let mut route = make_route_with_vertices(&poly);
+        route.policies.snapping_mode = SnappingMode::Off;
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+        let pos = Coordinate::new(0.5, 0.0002);
+        let state = engine.update_position(pos, None);
+        assert_eq!(state.snapped_position, pos);
+    }
+
+    #[test]
+    fn foot_profile_uses_walking_speed_for_eta() {
+        let poly = encode_points(&[(0.0, 0.0), (0.01, 0.0)]);
+        let mut route = make_route_with_vertices(&poly);
+        route.metadata.total_distance_m = None;
+        route.metadata.estimated_duration_s = None;
+        let foot = EngineConfig::for_profile(crate::VehicleProfile::Foot);
+        let mut engine = NavigationEngine::new(route, foot);
+        // ~1112 m at 5 km/h ≈ 800 s
+        let state = engine.update_position(Coordinate::new(0.0, 0.0), None);
+        assert!(
+            (state.eta_seconds as i64 - 800).abs() < 10,
+            "eta {}",
+            state.eta_seconds
+        );
+    }
+
+    #[test]
+    fn distance_to_turn_has_no_midpoint_jump() {
+        // One long segment north, then a right turn.
+        let poly = encode_points(&[(0.0, 0.0), (0.01, 0.0), (0.01, 0.01)]);
+        let route = make_route_with_vertices(&poly);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+        let before = engine.update_position(Coordinate::new(0.0049, 0.0), None);
+        let after = engine.update_position(Coordinate::new(0.0051, 0.0), None);
+        // 0.0002° ≈ 22 m of travel, not half a segment.
+        let drop = before.distance_to_next_m - after.distance_to_next_m;
+        assert!((drop - 22.2).abs() < 1.0, "drop {drop}");
+        let remaining_drop = before.distance_remaining_m - after.distance_remaining_m;
+        assert!((remaining_drop - 22.2).abs() < 1.0);
+    }
+
+    #[test]
+    fn progress_round_trips_through_with_progress() {
+        let poly = encode_points(&[(0.0, 0.0), (0.01, 0.0), (0.01, 0.01)]);
+        let mut engine =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        assert_eq!(engine.progress(), None);
+        engine.update_position(Coordinate::new(0.0075, 0.0), None);
+        let saved = engine.progress().unwrap();
+        assert_eq!(saved.segment_index, 0);
+        assert!((saved.fraction - 0.75).abs() < 1e-3);
+
+        let resumed = NavigationEngine::new_with_state(
+            make_route_with_vertices(&poly),
+            EngineConfig::default(),
+            engine.current_step(),
+            engine.distance_traveled_m(),
+        )
+        .with_progress(saved);
+        assert_eq!(resumed.progress(), Some(saved));
+    }
+
+    /// Append a segment with its own polyline, intent and constraints.
+    fn push_segment(
+        route: &mut Route,
+        pts: &[(f64, f64)],
+        intent: SegmentIntent,
+        constraints: SegmentConstraints,
+    ) {
+        let mut seg = route.segments[0].clone();
+        seg.id = SegmentId::new();
+        seg.geometry.polyline = EncodedPolyline(encode_points(pts));
+        seg.intent = intent;
+        seg.constraints = constraints;
+        route.segments.push(seg);
+    }
+
+    #[test]
+    fn multi_segment_route_is_navigated_end_to_end() {
+        let mut route = make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.01, 0.0)]));
+        route.metadata.total_distance_m = None;
+        push_segment(
+            &mut route,
+            &[(0.01, 0.0), (0.02, 0.0)],
+            SegmentIntent::FixedGeometry,
+            SegmentConstraints {
+                max_speed_kmh: Some(30),
+                ..SegmentConstraints::default()
+            },
+        );
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+        assert_eq!(engine.segment_offsets(), &[0, 1]);
+        let kinds: Vec<_> = engine.instructions().iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DerivedInstructionKind::Depart,
+                DerivedInstructionKind::SegmentTransition,
+                DerivedInstructionKind::Arrive
+            ]
+        );
+
+        let first = engine.update_position(Coordinate::new(0.005, 0.0), None);
+        assert_eq!(first.active_segment, 0);
+        assert_eq!(first.segment_intent, Some(SegmentIntent::Recalculatable));
+        assert!(first.constraint_alerts.is_empty());
+        assert!((first.distance_remaining_m - 1668.0).abs() < 5.0);
+
+        let second = engine.update_position(Coordinate::new(0.015, 0.0), Some(40.0 / 3.6));
+        assert_eq!(second.active_segment, 1);
+        assert_eq!(second.segment_intent, Some(SegmentIntent::FixedGeometry));
+        assert_eq!(second.speed_limit_kmh, Some(30));
+        assert_eq!(
+            second.constraint_alerts,
+            [ConstraintAlert::Speeding { max_kmh: 30 }]
+        );
+        assert_eq!(
+            second.current_instruction.kind,
+            DerivedInstructionKind::SegmentTransition
+        );
+        // Fixed geometry cannot be recalculated.
+        assert_eq!(second.off_route.behavior, OffRouteBehavior::AlertOnly);
+    }
+
+    #[test]
+    fn avoided_toll_span_alerts_once_on_entry_and_exit() {
+        let mut route = make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.01, 0.0)]));
+        let pts: Vec<(f64, f64)> = (0..=10).map(|i| (0.01 + i as f64 * 0.001, 0.0)).collect();
+        push_segment(
+            &mut route,
+            &pts,
+            SegmentIntent::Recalculatable,
+            SegmentConstraints {
+                avoid_tolls: true,
+                ..SegmentConstraints::default()
+            },
+        );
+        route.segments[1].road_spans = vec![RoadSpan {
+            vertex_range: VertexRange { start: 4, end: 6 },
+            attribute: RoadAttribute::Toll,
+        }];
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+        let mut raised = Vec::new();
+        let mut cleared = Vec::new();
+        for i in 0..=40 {
+            let state = engine.update_position(Coordinate::new(i as f64 * 0.0005, 0.0), None);
+            let on_toll = (0.014..=0.016).contains(&(i as f64 * 0.0005));
+            assert_eq!(
+                state
+                    .constraint_alerts
+                    .contains(&ConstraintAlert::AvoidToll),
+                on_toll,
+                "fix {i}"
+            );
+            raised.extend(state.raised_alerts.iter().map(|&a| (i, a)));
+            cleared.extend(state.cleared_alerts.iter().map(|&a| (i, a)));
+        }
+        assert_eq!(raised, [(28, ConstraintAlert::AvoidToll)]);
+        assert_eq!(cleared, [(33, ConstraintAlert::AvoidToll)]);
+    }
+
+    #[test]
+    fn disjoint_segments_are_bridged() {
+        let mut route = make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.01, 0.0)]));
+        push_segment(
+            &mut route,
+            &[(0.011, 0.0), (0.02, 0.0)],
+            SegmentIntent::Recalculatable,
+            SegmentConstraints::default(),
+        );
+        let engine = NavigationEngine::new(route, EngineConfig::default());
+        assert_eq!(engine.segment_offsets(), &[0, 2]);
+    }
+
+    #[test]
+    fn arrival_at_final_stop() {
+        let mut route = make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.01, 0.0)]));
+        route.segments[0].waypoints[1].coordinate = Coordinate::new(0.01, 0.0);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+
+        let mid = engine.update_position(Coordinate::new(0.005, 0.0), None);
+        let next = mid.next_waypoint.expect("stop ahead");
+        assert_eq!((next.index, next.kind), (1, WaypointKind::Stop));
+        assert!((next.distance_m - 556.0).abs() < 2.0);
+        assert!(!mid.arrived);
+
+        let end = engine.update_position(Coordinate::new(0.00995, 0.0), None);
+        assert_eq!(end.reached_waypoints, vec![1]);
+        assert_eq!(end.waypoints_passed, 1);
+        assert!(end.next_waypoint.is_none());
+        assert!(end.arrived);
+    }
+
+    #[test]
+    fn etas_per_waypoint_and_leg_follow_leg_durations() {
+        let mut route =
+            make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.01, 0.0), (0.02, 0.0)]));
+        route.metadata.total_distance_m = None;
+        route.metadata.estimated_duration_s = None;
+        let seg = &mut route.segments[0];
+        seg.waypoints[1].coordinate = Coordinate::new(0.02, 0.0);
+        let mut via = seg.waypoints[1].clone();
+        via.id = WaypointId::new();
+        via.kind = WaypointKind::Via;
+        via.coordinate = Coordinate::new(0.01, 0.0);
+        seg.waypoints.insert(1, via);
+        seg.legs = (0..2)
+            .map(|i| Leg {
+                id: LegId::new(),
+                from: seg.waypoints[i].id,
+                to: seg.waypoints[i + 1].id,
+                vertex_range: VertexRange {
+                    start: i as u32,
+                    end: i as u32 + 1,
+                },
+                distance_m: None,
+                duration_s: Some(120 * (i as u64 + 1)),
+            })
+            .collect();
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
+
+        let state = engine.update_position(Coordinate::new(0.005, 0.0), None);
+        let waypoint_etas: Vec<_> = state
+            .upcoming_waypoints
+            .iter()
+            .map(|w| (w.index, w.eta_seconds))
+            .collect();
+        assert_eq!(waypoint_etas, vec![(1, 60), (2, 300)]);
+        assert_eq!(state.next_waypoint.map(|w| w.index), Some(1));
+        let leg_etas: Vec<_> = state
+            .leg_etas
+            .iter()
+            .map(|l| (l.index, l.eta_seconds))
+            .collect();
+        assert_eq!(leg_etas, vec![(0, 60), (1, 300)]);
+        assert_eq!(state.eta_seconds, 300);
+        assert!(state.eta_interval.low_seconds < 300 && state.eta_interval.high_seconds > 300);
+
+        let state = engine.update_position(Coordinate::new(0.015, 0.0), None);
+        assert_eq!(state.leg_etas.len(), 1);
+        assert_eq!(state.upcoming_waypoints.len(), 1);
+    }
+
+    #[test]
+    fn low_range_and_due_break_point_to_the_next_stops() {
+        let mut route = make_route_with_vertices(&encode_points(&[(0.0, 0.0), (0.03, 0.0)]));
+        let seg = &mut route.segments[0];
+        seg.waypoints[1].coordinate = Coordinate::new(0.03, 0.0);
+        for (lat, kind) in [(0.02, WaypointKind::Fuel), (0.01, WaypointKind::Break)] {
+            let mut stop = seg.waypoints[1].clone();
+            stop.id = WaypointId::new();
+            stop.kind = kind;
+            stop.coordinate = Coordinate::new(lat, 0.0);
+            seg.waypoints.insert(1, stop);
+        }
+        let config = EngineConfig {
+            vehicle_range_m: Some(3000.0),
+            break_after_s: Some(60.0),
+            ..EngineConfig::default()
+        };
+        let mut engine = NavigationEngine::new(route, config).with_range_left(2000.0);
+
+        let mut low_entered = Vec::new();
+        let mut break_entered = Vec::new();
+        for i in 0..=30u64 {
+            let state = engine.update_fix(GpsFix {
+                speed_mps: Some(11.0),
+                timestamp_ms: Some(i * 10_000),
+                ..GpsFix::new(Coordinate::new(i as f64 * 0.001, 0.0))
+            });
+            let range = state.range.expect("vehicle range is configured");
+            if range.entered {
+                low_entered.push(i);
+            }
+            if i < 20 {
+                assert!(range.low, "pump at 2.2 km is out of a 2 km range, fix {i}");
+                assert_eq!(range.next_fuel.map(|w| w.index), Some(2));
+            } else {
+                assert!(!range.low, "refuelled, fix {i}");
+                assert!(range.next_fuel.is_none());
+            }
+            if let Some(suggestion) = state.break_suggestion {
+                if suggestion.entered {
+                    break_entered.push(i);
+                }
+                let next_break = suggestion.next_break.map(|w| w.index);
+                assert_eq!(next_break, (i < 10).then_some(1), "fix {i}");
+            }
+        }
+        assert_eq!(low_entered, [0]);
+        assert_eq!(break_entered, [6]);
+        // A full tank at the pump, less the last 1.1 km.
+        assert!((engine.stops().range_left_m().unwrap() - 1888.0).abs() < 50.0);
+    }
+
+    #[test]
+    fn turn_is_announced_once_per_stage() {
+        let poly = encode_points(&[(0.0, 0.0), (0.01, 0.0), (0.01, 0.01)]);
+        let mut engine =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        let mut prompts = Vec::new();
+        // Ride north towards the right turn at ~1112 m, 10 m per fix.
+        for i in 0..=111 {
+            let lat = i as f64 * 0.00009;
+            let state = engine.update_position(Coordinate::new(lat, 0.0), Some(10.0));
+            prompts.extend(state.announcements);
+        }
+        let stages: Vec<_> = prompts.iter().map(|a| a.stage).collect();
+        assert_eq!(
+            stages,
+            vec![
+                AnnouncementStage::Early,
+                AnnouncementStage::Prepare,
+                AnnouncementStage::Now
+            ]
+        );
+        assert!(prompts
+            .iter()
+            .all(|a| a.step == 1 && a.kind == DerivedInstructionKind::TurnRight));
+    }
+
+    /// Timed fix riding north along lon 0 at 10 m/s.
+    fn timed_fix(t_s: u64, lateral_deg: f64, accuracy_m: f64) -> GpsFix {
+        GpsFix {
+            coordinate: Coordinate::new(t_s as f64 * 0.00009, lateral_deg),
+            accuracy_m: Some(accuracy_m),
+            bearing_deg: Some(0.0),
+            speed_mps: Some(10.0),
+            timestamp_ms: Some(t_s * 1000),
+        }
+    }
+
+    #[test]
+    fn dead_reckons_through_signal_gap() {
+        let poly = encode_points(&[(0.0, 0.0), (0.05, 0.0)]);
+        let mut engine =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        let mut last = None;
+        for t in 0..20 {
+            last = Some(engine.update_fix(timed_fix(t, 0.0, 5.0)));
+        }
+        let before = last.unwrap().distance_remaining_m;
+
+        // Tunnel: no fixes for 10 s.
+        let est = engine.estimate_position(29_000).expect("recent estimate");
+        assert!(est.estimated);
+        assert!(!est.off_route.is_off_route);
+        let advanced = before - est.distance_remaining_m;
+        assert!((advanced - 100.0).abs() < 5.0, "advanced {advanced}");
+
+        assert!(engine.estimate_position(19_000 + 31_000).is_none());
+    }
+
+    #[test]
+    fn inaccurate_fix_is_replaced_by_estimate() {
+        let poly = encode_points(&[(0.0, 0.0), (0.05, 0.0)]);
+        let mut engine =
+            NavigationEngine::new(make_route_with_vertices(&poly), EngineConfig::default());
+        for t in 0..10 {
+            engine.update_fix(timed_fix(t, 0.0, 5.0));
+        }
+        // Multipath fix ~110 m east with a 120 m accuracy radius.
+        let state = engine.update_fix(timed_fix(10, 0.001, 120.0));
+        assert!(state.estimated);
+        assert!(!state.off_route.is_off_route);
+        assert!(state.snapped_position.longitude.abs() < 1e-9);
+    }
+
     #[test]
     fn right_turn_route_has_turn_instruction() {
         // North then east — right turn at vertex 1
         let poly = encode_points(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
         let route = make_route_with_vertices(&poly);
-        let mut engine = NavigationEngine::new(route);
+        let mut engine = NavigationEngine::new(route, EngineConfig::default());
         // Start: should have a next_instruction (the turn)
         let state = engine.update_position(Coordinate::new(0.0, 0.0), None);
         assert!(
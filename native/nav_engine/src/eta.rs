@@ -0,0 +1,431 @@
+use nav_ir::{Coordinate, Route};
+use serde::{Deserialize, Serialize};
+
+use crate::config::EngineConfig;
+use crate::index::PolylineIndex;
+use crate::types::EtaInterval;
+
+/// Observed pace relative to the baseline is kept within these bounds.
+const MIN_PACE: f64 = 0.33;
+const MAX_PACE: f64 = 3.0;
+/// Single samples are clamped to this range before smoothing (GPS glitches, matcher jumps).
+const MIN_SAMPLE: f64 = 0.25;
+const MAX_SAMPLE: f64 = 4.0;
+/// Below this speed, or this much progress between fixes, the rider is stopped and the pace
+/// is left alone (m/s, meters).
+const STOPPED_SPEED_MPS: f64 = 0.5;
+const STOPPED_PROGRESS_M: f64 = 1.0;
+/// Untimed fixes are assumed to be this far apart when smoothing (seconds).
+const UNTIMED_FIX_S: f64 = 1.0;
+/// Spread of the pace before anything was observed; shrinks as samples come in.
+const PRIOR_SPREAD: f64 = 0.3;
+/// Spread never drops below this share of the pace: traffic ahead is unknown.
+const MIN_SPREAD: f64 = 0.05;
+/// Two-sided z-score of the reported interval (80%).
+const INTERVAL_Z: f64 = 1.28;
+/// Assumed share of the speed limit actually ridden.
+const SPEED_LIMIT_SHARE: f64 = 0.85;
+
+/// Stateful ETA model.
+///
+/// The baseline is an expected time for every stretch of the chained polyline, taken from the
+/// most specific source available: the router's per-leg durations, then the route duration
+/// spread over the distance no leg covers, then the segment's speed limit, then the profile's
+/// default speed. Observed progress is compared against that baseline and folded into an
+/// exponentially smoothed pace factor (time constant `eta_pace_time_constant_s`), so a red
+/// light or a short sprint barely moves the ETA. Stopped fixes don't update the pace, so the
+/// ETA holds instead of running off to infinity.
+#[derive(Debug, Clone)]
+pub struct EtaModel {
+    /// Along-route distance of every polyline vertex.
+    along_m: Vec<f64>,
+    /// Baseline seconds from the route start to every polyline vertex.
+    cum_s: Vec<f64>,
+    pace: f64,
+    /// Smoothed squared deviation of samples from the pace.
+    pace_var: f64,
+    samples: u32,
+    last: Option<Observation>,
+}
+
+/// What an [`EtaModel`] has observed; the baseline is rebuilt from the route.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtaState {
+    pace: f64,
+    pace_var: f64,
+    samples: u32,
+    last: Option<Observation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Observation {
+    along_m: f64,
+    timestamp_ms: Option<u64>,
+}
+
+impl EtaModel {
+    /// Baseline for `route` chained into `vertices` (`segment_offsets` as in
+    /// `NavigationEngine::segment_offsets`). `total_distance_m` is the length the route
+    /// duration refers to.
+    pub fn build(
+        route: &Route,
+        vertices: &[Coordinate],
+        segment_offsets: &[usize],
+        index: &PolylineIndex,
+        total_distance_m: f64,
+        config: &EngineConfig,
+    ) -> Self {
+        let vertex_count = vertices.len();
+        let along_m: Vec<f64> = (0..vertex_count)
+            .map(|v| index.distance_at_vertex(v))
+            .collect();
+        let stretches = vertex_count.saturating_sub(1);
+        // Seconds per meter of each polyline stretch, where a leg says so.
+        let mut leg_pace: Vec<Option<f64>> = vec![None; stretches];
+        let mut leg_s = 0.0;
+        let mut leg_m = 0.0;
+        for (seg, &offset) in route.segments.iter().zip(segment_offsets) {
+            for leg in &seg.legs {
+                let first = offset + leg.vertex_range.start as usize;
+                let last = (offset + leg.vertex_range.end as usize).min(stretches);
+                let length_m = along_m.get(last).copied().unwrap_or(0.0)
+                    - along_m.get(first).copied().unwrap_or(0.0);
+                let (Some(duration_s), true) = (leg.duration_s, length_m > 0.0) else {
+                    continue;
+                };
+                leg_s += duration_s as f64;
+                leg_m += length_m;
+                for pace in leg_pace.iter_mut().take(last).skip(first) {
+                    *pace = Some(duration_s as f64 / length_m);
+                }
+            }
+        }
+        // What the route duration leaves for the distance no leg covers.
+        let route_pace = route.metadata.estimated_duration_s.and_then(|d| {
+            let scale = index.total_m() / total_distance_m.max(1.0);
+            let rest_m = index.total_m() - leg_m;
+            let rest_s = d as f64 * scale - leg_s;
+            (rest_m > 0.0 && rest_s > 0.0).then(|| rest_s / rest_m)
+        });
+        let default_pace = 1.0 / config.default_speed_mps;
+
+        let mut segment = 0;
+        let mut cum_s = Vec::with_capacity(vertex_count);
+        cum_s.push(0.0);
+        for i in 0..stretches {
+            while segment_offsets
+                .get(segment + 1)
+                .is_some_and(|&next| next <= i)
+            {
+                segment += 1;
+            }
+            let limit_pace = route
+                .segments
+                .get(segment)
+                .and_then(|s| s.constraints.max_speed_kmh)
+                .filter(|&kmh| kmh > 0)
+                .map(|kmh| 3.6 / (kmh as f64 * SPEED_LIMIT_SHARE));
+            let pace = leg_pace[i]
+                .or(route_pace)
+                .or(limit_pace)
+                .unwrap_or(default_pace);
+            cum_s.push(cum_s[i] + (along_m[i + 1] - along_m[i]) * pace);
+        }
+        Self {
+            along_m,
+            cum_s,
+            pace: 1.0,
+            pace_var: 0.0,
+            samples: 0,
+            last: None,
+        }
+    }
+
+    /// Fold an on-route position into the observed pace.
+    pub fn observe(
+        &mut self,
+        along_m: f64,
+        speed_mps: Option<f64>,
+        timestamp_ms: Option<u64>,
+        config: &EngineConfig,
+    ) {
+        let last = self.last.replace(Observation {
+            along_m,
+            timestamp_ms,
+        });
+        if speed_mps.is_some_and(|v| v < STOPPED_SPEED_MPS) {
+            return;
+        }
+        let timed =
+            last.and_then(|l| Some((l.along_m, timestamp_ms?.checked_sub(l.timestamp_ms?)?)));
+        let (sample, dt_s) = match timed {
+            Some((from_m, dt_ms)) if dt_ms > 0 => {
+                let progress_m = along_m - from_m;
+                let baseline_s = self.baseline_s(from_m, along_m);
+                if progress_m < STOPPED_PROGRESS_M || baseline_s <= 0.0 {
+                    return;
+                }
+                let dt_s = dt_ms as f64 / 1000.0;
+                (dt_s / baseline_s, dt_s)
+            }
+            // No timing: compare the reported speed with the baseline speed here.
+            _ => {
+                let Some(speed) = speed_mps else {
+                    return;
+                };
+                let baseline_pace = self.pace_at(along_m);
+                if baseline_pace <= 0.0 {
+                    return;
+                }
+                (1.0 / (baseline_pace * speed), UNTIMED_FIX_S)
+            }
+        };
+        let sample = sample.clamp(MIN_SAMPLE, MAX_SAMPLE);
+        let alpha = 1.0 - (-dt_s / config.eta_pace_time_constant_s).exp();
+        let deviation = sample - self.pace;
+        self.pace = (self.pace + alpha * deviation).clamp(MIN_PACE, MAX_PACE);
+        self.pace_var = (1.0 - alpha) * (self.pace_var + alpha * deviation * deviation);
+        self.samples = self.samples.saturating_add(1);
+    }
+
+    /// Forget the last position (off the route, or a new route) but keep the learned pace.
+    pub fn forget_position(&mut self) {
+        self.last = None;
+    }
+
+    /// Observed state, for an engine snapshot.
+    pub fn state(&self) -> EtaState {
+        EtaState {
+            pace: self.pace,
+            pace_var: self.pace_var,
+            samples: self.samples,
+            last: self.last,
+        }
+    }
+
+    /// Continue from a state taken with [`state`](Self::state).
+    pub fn restore(&mut self, state: EtaState) {
+        self.pace = state.pace;
+        self.pace_var = state.pace_var;
+        self.samples = state.samples;
+        self.last = state.last;
+    }
+
+    /// Observed pace relative to the baseline (`1.0` = as planned, `1.2` = 20% slower).
+    pub fn pace(&self) -> f64 {
+        self.pace
+    }
+
+    /// Baseline seconds between two along-route distances.
+    pub fn baseline_s(&self, from_m: f64, to_m: f64) -> f64 {
+        (self.time_at(to_m) - self.time_at(from_m)).max(0.0)
+    }
+
+    /// Expected seconds from `from_m` to `to_m` at the observed pace.
+    pub fn eta_s(&self, from_m: f64, to_m: f64) -> u64 {
+        (self.baseline_s(from_m, to_m) * self.pace).round() as u64
+    }
+
+    /// Likely range of the time from `from_m` to `to_m`.
+    pub fn interval(&self, from_m: f64, to_m: f64) -> EtaInterval {
+        let baseline = self.baseline_s(from_m, to_m);
+        let prior = PRIOR_SPREAD * PRIOR_SPREAD / (1.0 + self.samples as f64);
+        let spread = (self.pace_var + prior).sqrt().max(MIN_SPREAD * self.pace) * INTERVAL_Z;
+        EtaInterval {
+            low_seconds: (baseline * (self.pace - spread).max(self.pace * 0.5)).round() as u64,
+            high_seconds: (baseline * (self.pace + spread)).round() as u64,
+        }
+    }
+
+    /// Baseline seconds from the route start to `along_m` (linear within a stretch).
+    fn time_at(&self, along_m: f64) -> f64 {
+        let Some(i) = self.stretch_at(along_m) else {
+            return 0.0;
+        };
+        let (a0, a1) = (self.along_m[i], self.along_m[i + 1]);
+        let t = if a1 > a0 {
+            ((along_m - a0) / (a1 - a0)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.cum_s[i] + t * (self.cum_s[i + 1] - self.cum_s[i])
+    }
+
+    /// Baseline seconds per meter at `along_m`.
+    fn pace_at(&self, along_m: f64) -> f64 {
+        let Some(i) = self.stretch_at(along_m) else {
+            return 0.0;
+        };
+        let length_m = self.along_m[i + 1] - self.along_m[i];
+        if length_m > 0.0 {
+            (self.cum_s[i + 1] - self.cum_s[i]) / length_m
+        } else {
+            0.0
+        }
+    }
+
+    /// Polyline stretch (`vertex i → i + 1`) containing `along_m`.
+    fn stretch_at(&self, along_m: f64) -> Option<usize> {
+        let last = self.along_m.len().checked_sub(2)?;
+        Some(
+            self.along_m
+                .partition_point(|&a| a <= along_m)
+                .saturating_sub(1)
+                .min(last),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use nav_ir::*;
+
+    /// North along lon 0 through `lats`, one leg per stretch with `leg_durations`.
+    fn route(lats: &[f64], leg_durations: &[Option<u64>], duration_s: Option<u64>) -> Route {
+        let coords: Vec<_> = lats
+            .iter()
+            .map(|&lat| geo_types::Coord { x: 0.0, y: lat })
+            .collect();
+        let waypoints: Vec<WaypointId> = lats.iter().map(|_| WaypointId::new()).collect();
+        let legs = leg_durations
+            .iter()
+            .enumerate()
+            .map(|(i, &duration_s)| Leg {
+                id: LegId::new(),
+                from: waypoints[i],
+                to: waypoints[i + 1],
+                vertex_range: VertexRange {
+                    start: i as u32,
+                    end: i as u32 + 1,
+                },
+                distance_m: None,
+                duration_s,
+            })
+            .collect();
+        Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: "Eta".into(),
+                description: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                total_distance_m: None,
+                estimated_duration_s: duration_s,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap()),
+                    source: GeometrySource::SnappedToGraph,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: lats[0],
+                        min_lon: 0.0,
+                        max_lat: *lats.last().unwrap(),
+                        max_lon: 0.0,
+                    },
+                },
+                waypoints: vec![],
+                legs,
+                instructions: vec![],
+                constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
+            }],
+            policies: RoutePolicies::default(),
+        }
+    }
+
+    fn model(route: &Route) -> (EtaModel, f64) {
+        let vertices: Vec<Coordinate> =
+            polyline::decode_polyline(&route.segments[0].geometry.polyline.0, 5)
+                .unwrap()
+                .coords()
+                .map(|c| Coordinate::new(c.y, c.x))
+                .collect();
+        let index = PolylineIndex::build(&vertices);
+        let total = index.total_m();
+        let config = EngineConfig::default();
+        let model = EtaModel::build(route, &vertices, &[0], &index, total, &config);
+        (model, total)
+    }
+
+    /// Ride at `speed_mps` from `from_m` for `seconds`, one fix per second.
+    fn ride(model: &mut EtaModel, from_m: f64, t0_s: u64, seconds: u64, speed_mps: f64) -> f64 {
+        let config = EngineConfig::default();
+        let mut along = from_m;
+        for t in t0_s..t0_s + seconds {
+            model.observe(along, Some(speed_mps), Some(t * 1000), &config);
+            along += speed_mps;
+        }
+        along - speed_mps
+    }
+
+    #[test]
+    fn legs_set_the_baseline_per_stretch() {
+        let route = route(&[0.0, 0.01, 0.02], &[Some(100), Some(300)], Some(10_000));
+        let (model, total) = model(&route);
+        let half = total / 2.0;
+        assert_eq!(model.eta_s(0.0, total), 400);
+        assert_eq!(model.eta_s(0.0, half), 100);
+        assert_eq!(model.eta_s(half, half + half / 2.0), 150);
+    }
+
+    #[test]
+    fn route_duration_covers_what_legs_do_not() {
+        let route = route(&[0.0, 0.01, 0.02], &[Some(100), None], Some(400));
+        let (model, total) = model(&route);
+        assert_eq!(model.eta_s(0.0, total / 2.0), 100);
+        assert_eq!(model.eta_s(total / 2.0, total), 300);
+    }
+
+    #[test]
+    fn observed_pace_moves_eta_smoothly() {
+        let route = route(&[0.0, 0.01, 0.02], &[], Some(200));
+        let (mut model, total) = model(&route);
+        // Baseline ~11.1 m/s; the rider does half that.
+        let along = ride(&mut model, 0.0, 0, 60, 5.56);
+        let pace = model.pace();
+        assert!(pace > 1.3 && pace < 2.0, "pace {pace}");
+        let eta = model.eta_s(along, total);
+        let baseline = model.baseline_s(along, total);
+        assert!(eta as f64 > baseline * 1.3, "{eta} vs {baseline}");
+    }
+
+    #[test]
+    fn red_light_holds_the_eta() {
+        let route = route(&[0.0, 0.01, 0.02], &[], Some(200));
+        let (mut model, total) = model(&route);
+        let config = EngineConfig::default();
+        let along = ride(&mut model, 0.0, 0, 30, 11.1);
+        let before = model.eta_s(along, total);
+        for t in 30..90 {
+            model.observe(along, Some(0.0), Some(t * 1000), &config);
+            assert_eq!(model.eta_s(along, total), before);
+        }
+        // Creeping without a speed reading doesn't count either.
+        model.observe(along + 0.3, None, Some(91_000), &config);
+        assert_eq!(model.eta_s(along, total), before);
+    }
+
+    #[test]
+    fn interval_narrows_as_pace_is_learned() {
+        let route = route(&[0.0, 0.01, 0.02], &[], Some(200));
+        let (mut model, total) = model(&route);
+        let fresh = model.interval(0.0, total);
+        assert!(fresh.low_seconds < 200 && fresh.high_seconds > 200);
+        ride(&mut model, 0.0, 0, 120, 11.1);
+        let learned = model.interval(0.0, total);
+        assert!(
+            learned.high_seconds - learned.low_seconds < fresh.high_seconds - fresh.low_seconds
+        );
+        assert!(learned.low_seconds <= model.eta_s(0.0, total));
+        assert!(learned.high_seconds >= model.eta_s(0.0, total));
+    }
+}
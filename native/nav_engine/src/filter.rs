@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest signal gap bridged by dead reckoning before the position is held (seconds).
+pub const MAX_DEAD_RECKONING_S: f64 = 30.0;
+/// Measurements further than this from the prediction restart the filter (meters).
+const RESET_INNOVATION_M: f64 = 100.0;
+/// Accuracy assumed when a fix doesn't report one (meters, 1σ).
+const DEFAULT_ACCURACY_M: f64 = 10.0;
+/// Process noise: unmodelled acceleration (m/s², 1σ).
+const ACCEL_NOISE: f64 = 1.0;
+/// Measurement noise of GPS speed (m/s, 1σ).
+const SPEED_NOISE: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct FilterState {
+    along_m: f64,
+    speed_mps: f64,
+    /// Covariance of `[along_m, speed_mps]`.
+    p: [[f64; 2]; 2],
+    timestamp_ms: u64,
+}
+
+/// Constant-velocity Kalman filter on along-route distance.
+///
+/// The matcher decides *where* on the route a fix lies; this smooths *how far along* the
+/// rider is, weighting each fix by its reported accuracy. Because the state is a distance on
+/// the polyline, predictions stay on the route, which is what dead reckoning needs in
+/// tunnels. Fixes without a timestamp can't be filtered and pass straight through.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RouteFilter {
+    state: Option<FilterState>,
+}
+
+impl RouteFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the current estimate (e.g. after leaving the route).
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// Fold a measured along-route distance into the estimate; returns the filtered distance.
+    pub fn update(
+        &mut self,
+        along_m: f64,
+        accuracy_m: Option<f64>,
+        speed_mps: Option<f64>,
+        timestamp_ms: Option<u64>,
+    ) -> f64 {
+        let Some(ts) = timestamp_ms else {
+            self.state = None;
+            return along_m;
+        };
+        let r = accuracy_m.unwrap_or(DEFAULT_ACCURACY_M).max(1.0).powi(2);
+
+        let predicted = self.state.and_then(|s| {
+            let dt = ts.checked_sub(s.timestamp_ms)? as f64 / 1000.0;
+            (dt > 0.0 && dt <= MAX_DEAD_RECKONING_S).then(|| predict(s, dt))
+        });
+        let mut s = match predicted {
+            Some(s) if (along_m - s.along_m).abs() <= RESET_INNOVATION_M => s,
+            _ => {
+                self.state = Some(FilterState {
+                    along_m,
+                    speed_mps: speed_mps.unwrap_or(0.0),
+                    p: [[r, 0.0], [0.0, 25.0]],
+                    timestamp_ms: ts,
+                });
+                return along_m;
+            }
+        };
+
+        correct(&mut s, 0, along_m, r);
+        if let Some(v) = speed_mps {
+            correct(&mut s, 1, v, SPEED_NOISE * SPEED_NOISE);
+        }
+        s.speed_mps = s.speed_mps.max(0.0);
+        s.timestamp_ms = ts;
+        self.state = Some(s);
+        s.along_m
+    }
+
+    /// Along-route distance extrapolated to `timestamp_ms`, or `None` when there is no
+    /// estimate or the gap is longer than [`MAX_DEAD_RECKONING_S`].
+    pub fn extrapolate(&self, timestamp_ms: u64) -> Option<f64> {
+        let s = self.state?;
+        let dt = timestamp_ms.checked_sub(s.timestamp_ms)? as f64 / 1000.0;
+        (dt <= MAX_DEAD_RECKONING_S).then_some(s.along_m + s.speed_mps * dt)
+    }
+
+    /// Filtered speed along the route (m/s).
+    pub fn speed_mps(&self) -> Option<f64> {
+        self.state.map(|s| s.speed_mps)
+    }
+}
+
+fn predict(mut s: FilterState, dt: f64) -> FilterState {
+    s.along_m += s.speed_mps * dt;
+    let [[p00, p01], [p10, p11]] = s.p;
+    let q = ACCEL_NOISE * ACCEL_NOISE;
+    // P = F P Fᵀ + Q with F = [[1, dt], [0, 1]].
+    s.p = [
+        [
+            p00 + dt * (p01 + p10) + dt * dt * p11 + q * dt.powi(4) / 4.0,
+            p01 + dt * p11 + q * dt.powi(3) / 2.0,
+        ],
+        [p10 + dt * p11 + q * dt.powi(3) / 2.0, p11 + q * dt * dt],
+    ];
+    s
+}
+
+/// Scalar measurement update of state component `i` (0 = distance, 1 = speed).
+fn correct(s: &mut FilterState, i: usize, z: f64, r: f64) {
+    let x = [s.along_m, s.speed_mps];
+    let innovation = z - x[i];
+    let denom = s.p[i][i] + r;
+    let k = [s.p[0][i] / denom, s.p[1][i] / denom];
+    s.along_m += k[0] * innovation;
+    s.speed_mps += k[1] * innovation;
+    let row = s.p[i];
+    for (a, ka) in k.iter().enumerate() {
+        for (b, pb) in row.iter().enumerate() {
+            s.p[a][b] -= ka * pb;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untimed_fixes_pass_through() {
+        let mut f = RouteFilter::new();
+        assert_eq!(f.update(100.0, Some(5.0), None, None), 100.0);
+        assert_eq!(f.update(40.0, Some(5.0), None, None), 40.0);
+        assert_eq!(f.extrapolate(1_000), None);
+    }
+
+    #[test]
+    fn noisy_fixes_are_smoothed() {
+        let mut f = RouteFilter::new();
+        let mut worst_raw: f64 = 0.0;
+        let mut worst_filtered: f64 = 0.0;
+        for i in 0..60u64 {
+            let truth = i as f64 * 10.0;
+            let noise = if i % 2 == 0 { 15.0 } else { -15.0 };
+            let out = f.update(truth + noise, Some(15.0), Some(10.0), Some(i * 1000));
+            if i > 10 {
+                worst_raw = worst_raw.max(noise.abs());
+                worst_filtered = worst_filtered.max((out - truth).abs());
+            }
+        }
+        assert!(worst_filtered < worst_raw / 2.0, "{worst_filtered}");
+        assert!((f.speed_mps().unwrap() - 10.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn low_accuracy_fix_is_down_weighted() {
+        let mut f = RouteFilter::new();
+        for i in 0..10u64 {
+            f.update(i as f64 * 10.0, Some(5.0), Some(10.0), Some(i * 1000));
+        }
+        // 60 m ahead of the prediction, but reported at ±80 m.
+        let out = f.update(160.0, Some(80.0), Some(10.0), Some(10_000));
+        assert!((out - 100.0).abs() < 5.0, "{out}");
+    }
+
+    #[test]
+    fn extrapolates_through_short_gaps_only() {
+        let mut f = RouteFilter::new();
+        for i in 0..10u64 {
+            f.update(i as f64 * 12.0, Some(5.0), Some(12.0), Some(i * 1000));
+        }
+        let ahead = f.extrapolate(19_000).unwrap();
+        assert!((ahead - 228.0).abs() < 3.0, "{ahead}");
+        assert_eq!(f.extrapolate(9_000 + 31_000), None);
+    }
+
+    #[test]
+    fn large_jump_restarts_filter() {
+        let mut f = RouteFilter::new();
+        for i in 0..5u64 {
+            f.update(i as f64 * 10.0, Some(5.0), None, Some(i * 1000));
+        }
+        assert_eq!(f.update(2_000.0, Some(5.0), None, Some(5_000)), 2_000.0);
+    }
+}
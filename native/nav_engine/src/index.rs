@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use nav_ir::Coordinate;
+
+use crate::derive_instructions::haversine_distance;
+use crate::off_route::point_to_segment_distance;
+
+/// Grid cell edge in degrees (~280 m of latitude).
+const CELL_DEG: f64 = 0.0025;
+/// Segments whose bounding box spans more cells than this are kept in a side list that is
+/// always checked, instead of being rasterised into every cell.
+const MAX_CELLS_PER_SEGMENT: usize = 64;
+/// Slightly under the true ~111.2 km so query boxes err on the large side.
+const METERS_PER_DEG_LAT: f64 = 111_000.0;
+
+/// Precomputed lookup structures over a decoded route polyline.
+///
+/// - `cumulative[i]` is the along-route distance from vertex 0 to vertex `i`, so remaining and
+///   between-vertex distances are O(1).
+/// - A uniform lat/lon grid maps cells to the segments crossing them, so nearest-segment
+///   queries only look at segments around the fix instead of the whole route.
+///
+/// Built once per engine; the vertices themselves stay owned by the caller.
+#[derive(Debug, Clone, Default)]
+pub struct PolylineIndex {
+    cumulative: Vec<f64>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    long_segments: Vec<usize>,
+}
+
+impl PolylineIndex {
+    pub fn build(vertices: &[Coordinate]) -> Self {
+        let mut cumulative = Vec::with_capacity(vertices.len());
+        let mut total = 0.0;
+        for (i, v) in vertices.iter().enumerate() {
+            if i > 0 {
+                total += haversine_distance(vertices[i - 1], *v);
+            }
+            cumulative.push(total);
+        }
+
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        let mut long_segments = Vec::new();
+        for i in 0..vertices.len().saturating_sub(1) {
+            let (a, b) = (vertices[i], vertices[i + 1]);
+            let (lat0, lat1) = (
+                cell(a.latitude.min(b.latitude)),
+                cell(a.latitude.max(b.latitude)),
+            );
+            let (lon0, lon1) = (
+                cell(a.longitude.min(b.longitude)),
+                cell(a.longitude.max(b.longitude)),
+            );
+            let span = (lat1 - lat0 + 1) as usize * (lon1 - lon0 + 1) as usize;
+            if span > MAX_CELLS_PER_SEGMENT {
+                long_segments.push(i);
+                continue;
+            }
+            for y in lat0..=lat1 {
+                for x in lon0..=lon1 {
+                    cells.entry((y, x)).or_default().push(i);
+                }
+            }
+        }
+
+        Self {
+            cumulative,
+            cells,
+            long_segments,
+        }
+    }
+
+    /// Total polyline length in meters.
+    pub fn total_m(&self) -> f64 {
+        self.cumulative.last().copied().unwrap_or(0.0)
+    }
+
+    /// Along-route distance from the first vertex to `vertex` (clamped to the last vertex).
+    pub fn distance_at_vertex(&self, vertex: usize) -> f64 {
+        match self.cumulative.len() {
+            0 => 0.0,
+            n => self.cumulative[vertex.min(n - 1)],
+        }
+    }
+
+    /// Along-route distance from `vertex` to the end of the polyline.
+    pub fn remaining_from_vertex(&self, vertex: usize) -> f64 {
+        self.total_m() - self.distance_at_vertex(vertex)
+    }
+
+    /// Along-route distance of a point `fraction` of the way along `segment`.
+    pub fn distance_along(&self, segment: usize, fraction: f64) -> f64 {
+        let start = self.distance_at_vertex(segment);
+        let end = self.distance_at_vertex(segment + 1);
+        start + (end - start) * fraction.clamp(0.0, 1.0)
+    }
+
+    /// First vertex whose along-route distance is `>= distance_m` (`vertex count` if none).
+    pub fn first_vertex_at_or_after(&self, distance_m: f64) -> usize {
+        self.cumulative.partition_point(|&d| d < distance_m)
+    }
+
+    /// Index of the segment containing along-route distance `distance_m` (clamped to the route).
+    pub fn segment_at_distance(&self, distance_m: f64) -> usize {
+        let last_segment = self.cumulative.len().saturating_sub(2);
+        self.cumulative
+            .partition_point(|&d| d <= distance_m)
+            .saturating_sub(1)
+            .min(last_segment)
+    }
+
+    /// Segment and fraction of the point `distance_m` along the route (clamped to the route).
+    pub fn progress_at_distance(&self, distance_m: f64) -> (usize, f64) {
+        let segment = self.segment_at_distance(distance_m);
+        let start = self.distance_at_vertex(segment);
+        let len = self.distance_at_vertex(segment + 1) - start;
+        let fraction = if len > 0.0 {
+            ((distance_m - start) / len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (segment, fraction)
+    }
+
+    /// Segments that may lie within `radius_m` of `pos`, sorted and deduplicated.
+    pub fn segments_near(&self, pos: Coordinate, radius_m: f64) -> Vec<usize> {
+        let dlat = radius_m / METERS_PER_DEG_LAT;
+        let dlon = radius_m / (METERS_PER_DEG_LAT * pos.latitude.to_radians().cos().max(0.01));
+        let (lat0, lat1) = (cell(pos.latitude - dlat), cell(pos.latitude + dlat));
+        let (lon0, lon1) = (cell(pos.longitude - dlon), cell(pos.longitude + dlon));
+        let mut out = self.long_segments.clone();
+        let query_cells = (lat1 - lat0 + 1) as usize * (lon1 - lon0 + 1) as usize;
+        if query_cells > self.cells.len() {
+            // Wide query (fix far from the route): walking the occupied cells is cheaper.
+            for (&(y, x), segs) in &self.cells {
+                if (lat0..=lat1).contains(&y) && (lon0..=lon1).contains(&x) {
+                    out.extend_from_slice(segs);
+                }
+            }
+        } else {
+            for y in lat0..=lat1 {
+                for x in lon0..=lon1 {
+                    if let Some(segs) = self.cells.get(&(y, x)) {
+                        out.extend_from_slice(segs);
+                    }
+                }
+            }
+        }
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+
+    /// Nearest segment to `pos` among those accepted by `accept`, as
+    /// `(segment_index, distance_m, fraction, snapped)`.
+    ///
+    /// Searches a growing radius around `pos` and stops once the best hit is closer than the
+    /// searched radius; falls back to a full scan when the route is farther than that.
+    pub fn nearest_segment(
+        &self,
+        pos: Coordinate,
+        vertices: &[Coordinate],
+        accept: impl Fn(usize) -> bool,
+    ) -> Option<(usize, f64, f64, Coordinate)> {
+        if vertices.len() < 2 {
+            return None;
+        }
+        let mut radius_m = CELL_DEG * METERS_PER_DEG_LAT;
+        for _ in 0..6 {
+            let best = closest_of(pos, vertices, self.segments_near(pos, radius_m), &accept);
+            if let Some(hit) = best {
+                if hit.1 <= radius_m {
+                    return Some(hit);
+                }
+            }
+            radius_m *= 4.0;
+        }
+        closest_of(pos, vertices, 0..vertices.len() - 1, &accept)
+    }
+}
+
+fn cell(deg: f64) -> i32 {
+    (deg / CELL_DEG).floor() as i32
+}
+
+fn closest_of(
+    pos: Coordinate,
+    vertices: &[Coordinate],
+    segments: impl IntoIterator<Item = usize>,
+    accept: &impl Fn(usize) -> bool,
+) -> Option<(usize, f64, f64, Coordinate)> {
+    let mut best: Option<(usize, f64, f64, Coordinate)> = None;
+    for i in segments {
+        if !accept(i) {
+            continue;
+        }
+        let (dist, t, snapped) = point_to_segment_distance(pos, vertices[i], vertices[i + 1]);
+        if best.is_none_or(|b| dist < b.1) {
+            best = Some((i, dist, t, snapped));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::off_route::distance_to_polyline;
+    use crate::progress::remaining_distance;
+
+    fn c(lat: f64, lon: f64) -> Coordinate {
+        Coordinate::new(lat, lon)
+    }
+
+    /// Zig-zag track with many short segments.
+    fn track(n: usize) -> Vec<Coordinate> {
+        (0..n)
+            .map(|i| {
+                let t = i as f64 * 0.0003;
+                c(52.0 + t, 4.0 + (t * 40.0).sin() * 0.002)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prefix_distances_match_linear_sum() {
+        let v = track(500);
+        let idx = PolylineIndex::build(&v);
+        for i in [0, 1, 250, 498, 499] {
+            let linear = remaining_distance(&v, i);
+            assert!((idx.remaining_from_vertex(i) - linear).abs() < 1e-6);
+        }
+        assert!((idx.total_m() - remaining_distance(&v, 0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn nearest_segment_matches_linear_scan() {
+        let v = track(2000);
+        let idx = PolylineIndex::build(&v);
+        for (lat, lon) in [(52.1, 4.001), (52.3, 3.999), (52.59, 4.0015), (53.0, 5.0)] {
+            let p = c(lat, lon);
+            let (_, _, snapped_linear) = distance_to_polyline(p, &v);
+            let (_, dist, _, snapped) = idx.nearest_segment(p, &v, |_| true).unwrap();
+            assert!(
+                (dist - haversine_distance(p, snapped_linear)).abs() < 1e-6,
+                "mismatch at {lat},{lon}"
+            );
+            assert_eq!(snapped, snapped_linear);
+        }
+    }
+
+    #[test]
+    fn long_segments_are_always_candidates() {
+        let v = vec![c(0.0, 0.0), c(1.0, 1.0)];
+        let idx = PolylineIndex::build(&v);
+        assert_eq!(idx.segments_near(c(0.5, 0.5), 10.0), vec![0]);
+        let (seg, dist, _, _) = idx.nearest_segment(c(0.5, 0.5), &v, |_| true).unwrap();
+        assert_eq!(seg, 0);
+        assert!(dist < 1.0);
+    }
+
+    #[test]
+    fn segment_at_distance_binary_search() {
+        let v = vec![c(0.0, 0.0), c(0.001, 0.0), c(0.002, 0.0), c(0.003, 0.0)];
+        let idx = PolylineIndex::build(&v);
+        assert_eq!(idx.segment_at_distance(0.0), 0);
+        assert_eq!(idx.segment_at_distance(150.0), 1);
+        assert_eq!(idx.segment_at_distance(1e9), 2);
+        assert!((idx.distance_along(1, 0.5) - 1.5 * idx.distance_at_vertex(1)).abs() < 1e-6);
+        let (seg, frac) = idx.progress_at_distance(idx.distance_along(1, 0.25));
+        assert_eq!(seg, 1);
+        assert!((frac - 0.25).abs() < 1e-9);
+    }
+}
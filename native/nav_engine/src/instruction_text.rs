@@ -0,0 +1,434 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::types::{Announcement, AnnouncementStage, DerivedInstructionKind};
+
+const METERS_PER_FOOT: f64 = 0.3048;
+const METERS_PER_MILE: f64 = 1609.344;
+/// Distances from this many km / miles up are spoken without a decimal.
+const WHOLE_UNITS_FROM: f64 = 10.0;
+
+/// Languages with an embedded phrase table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Nl,
+    De,
+    Fr,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Nl, Locale::De, Locale::Fr];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Nl => "nl",
+            Locale::De => "de",
+            Locale::Fr => "fr",
+        }
+    }
+
+    fn table_source(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../locales/en.json"),
+            Locale::Nl => include_str!("../locales/nl.json"),
+            Locale::De => include_str!("../locales/de.json"),
+            Locale::Fr => include_str!("../locales/fr.json"),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Accepts a language code or a full tag ("nl", "nl-BE", "de_AT").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lang = s.split(['-', '_']).next().unwrap_or_default();
+        match lang.to_ascii_lowercase().as_str() {
+            "en" => Ok(Locale::En),
+            "nl" => Ok(Locale::Nl),
+            "de" => Ok(Locale::De),
+            "fr" => Ok(Locale::Fr),
+            _ => Err(format!("Unsupported locale: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitSystem {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+impl FromStr for UnitSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "metric" => Ok(UnitSystem::Metric),
+            "imperial" => Ok(UnitSystem::Imperial),
+            other => Err(format!("Unknown unit system: {}", other)),
+        }
+    }
+}
+
+/// One locale's phrase table (`locales/<code>.json`).
+#[derive(Debug, Deserialize)]
+struct LocaleTable {
+    decimal_separator: String,
+    units: UnitNames,
+    /// `[below, step]` pairs: distances under `below` meters are rounded to `step` meters.
+    metric_steps: Vec<[f64; 2]>,
+    /// Same for feet, up to 0.1 mi (528 ft).
+    feet_steps: Vec<[f64; 2]>,
+    with_distance: String,
+    then: String,
+    now: String,
+    /// Per `DerivedInstructionKind::as_str()`: `[without street, with {street}]`.
+    kinds: HashMap<String, [String; 2]>,
+    roundabout: [String; 2],
+    /// `"default"` pattern with `{n}`, plus exact overrides keyed by number.
+    ordinals: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnitNames {
+    m: String,
+    km: String,
+    ft: String,
+    mi: String,
+}
+
+fn table(locale: Locale) -> &'static LocaleTable {
+    static TABLES: OnceLock<HashMap<Locale, LocaleTable>> = OnceLock::new();
+    let tables = TABLES.get_or_init(|| {
+        Locale::ALL
+            .into_iter()
+            .map(|l| {
+                let t = serde_json::from_str(l.table_source())
+                    .unwrap_or_else(|e| panic!("invalid locale table {}: {}", l.as_str(), e));
+                (l, t)
+            })
+            .collect()
+    });
+    &tables[&locale]
+}
+
+/// What to say: the maneuver, where it leads and how far ahead it is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Phrase<'a> {
+    pub kind: DerivedInstructionKind,
+    pub street_name: Option<&'a str>,
+    /// Roundabout exit to take (1-based); turns the phrase into a roundabout instruction.
+    pub exit_number: Option<u32>,
+    /// Distance to the maneuver; `None` for the bare instruction.
+    pub distance_m: Option<f64>,
+}
+
+impl<'a> Phrase<'a> {
+    pub fn new(kind: DerivedInstructionKind) -> Self {
+        Self {
+            kind,
+            street_name: None,
+            exit_number: None,
+            distance_m: None,
+        }
+    }
+
+    pub fn street(mut self, street_name: Option<&'a str>) -> Self {
+        self.street_name = street_name.filter(|s| !s.is_empty());
+        self
+    }
+
+    pub fn exit(mut self, exit_number: u32) -> Self {
+        self.exit_number = Some(exit_number);
+        self
+    }
+
+    pub fn distance(mut self, distance_m: f64) -> Self {
+        self.distance_m = Some(distance_m);
+        self
+    }
+}
+
+/// Builds human-readable instruction text in one locale and unit system.
+///
+/// Flutter, the watch and voice prompts share this so they all use the same wording.
+/// The default is English, metric.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InstructionText {
+    locale: Locale,
+    units: UnitSystem,
+}
+
+impl InstructionText {
+    pub fn new(locale: Locale, units: UnitSystem) -> Self {
+        Self { locale, units }
+    }
+
+    /// Full sentence for `phrase`, e.g. "In 200 m, turn left onto Main Street".
+    pub fn phrase(&self, phrase: &Phrase) -> String {
+        capitalize(&self.clause(phrase))
+    }
+
+    /// Spoken / displayed text for an announcement, merging a follow-up maneuver.
+    pub fn announcement(&self, a: &Announcement) -> String {
+        let t = table(self.locale);
+        let mut phrase = Phrase::new(a.kind).street(a.street_name.as_deref());
+        let mut text = if a.stage == AnnouncementStage::Now {
+            t.now.replace("{instruction}", &self.clause(&phrase))
+        } else {
+            phrase = phrase.distance(a.distance_m);
+            self.clause(&phrase)
+        };
+        if let Some(next) = a.then {
+            text = t
+                .then
+                .replace("{instruction}", &text)
+                .replace("{next}", &self.clause(&Phrase::new(next)));
+        }
+        capitalize(&text)
+    }
+
+    /// Rounded distance with unit, following the locale's rounding steps.
+    pub fn distance(&self, distance_m: f64) -> String {
+        let t = table(self.locale);
+        let m = distance_m.max(0.0);
+        match self.units {
+            UnitSystem::Metric => {
+                if let Some(rounded) = round_by_steps(m, &t.metric_steps) {
+                    return format!("{} {}", rounded, t.units.m);
+                }
+                format!("{} {}", self.large(m / 1000.0), t.units.km)
+            }
+            UnitSystem::Imperial => {
+                if let Some(rounded) = round_by_steps(m / METERS_PER_FOOT, &t.feet_steps) {
+                    return format!("{} {}", rounded, t.units.ft);
+                }
+                format!("{} {}", self.large(m / METERS_PER_MILE), t.units.mi)
+            }
+        }
+    }
+
+    /// Lower-case clause, before sentence capitalization.
+    fn clause(&self, phrase: &Phrase) -> String {
+        let t = table(self.locale);
+        let with_street = phrase.street_name.is_some() as usize;
+        let template = match phrase.exit_number {
+            Some(_) => &t.roundabout[with_street],
+            None => &t.kinds[phrase.kind.as_str()][with_street],
+        };
+        let mut text = template.replace("{street}", phrase.street_name.unwrap_or_default());
+        if let Some(exit) = phrase.exit_number {
+            text = text.replace("{exit}", &ordinal(t, exit));
+        }
+        match phrase.distance_m {
+            Some(d) => t
+                .with_distance
+                .replace("{distance}", &self.distance(d))
+                .replace("{instruction}", &text),
+            None => text,
+        }
+    }
+
+    /// Kilometers / miles: one decimal below 10 (dropped when zero), whole numbers above.
+    fn large(&self, value: f64) -> String {
+        if value >= WHOLE_UNITS_FROM - 0.05 {
+            return format!("{}", value.round() as u64);
+        }
+        let tenths = (value * 10.0).round().max(1.0) as u64;
+        if tenths.is_multiple_of(10) {
+            format!("{}", tenths / 10)
+        } else {
+            let sep = &table(self.locale).decimal_separator;
+            format!("{}{}{}", tenths / 10, sep, tenths % 10)
+        }
+    }
+}
+
+/// Round `value` to the step of the first `[below, step]` band it falls in; `None` when it
+/// is past the last band (or rounds up into it) and should use the larger unit.
+fn round_by_steps(value: f64, steps: &[[f64; 2]]) -> Option<u64> {
+    let limit = steps.last()?[0];
+    let [_, step] = steps.iter().find(|[below, _]| value < *below)?;
+    let rounded = ((value / step).round() * step).max(*step);
+    (rounded < limit).then_some(rounded as u64)
+}
+
+fn ordinal(t: &LocaleTable, n: u32) -> String {
+    t.ordinals
+        .get(&n.to_string())
+        .or_else(|| t.ordinals.get("default"))
+        .map(|p| p.replace("{n}", &n.to_string()))
+        .unwrap_or_else(|| n.to_string())
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(locale: Locale, units: UnitSystem) -> InstructionText {
+        InstructionText::new(locale, units)
+    }
+
+    #[test]
+    fn every_locale_table_covers_every_kind() {
+        use DerivedInstructionKind::*;
+        for locale in Locale::ALL {
+            let t = table(locale);
+            for kind in [
+                Depart,
+                SharpLeft,
+                TurnLeft,
+                SlightLeft,
+                Continue,
+                SlightRight,
+                TurnRight,
+                SharpRight,
+                SegmentTransition,
+                Arrive,
+                UTurn,
+            ] {
+                assert!(t.kinds.contains_key(kind.as_str()), "{locale:?} {kind:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn english_distance_rounding() {
+        let en = text(Locale::En, UnitSystem::Metric);
+        assert_eq!(en.distance(3.0), "10 m");
+        assert_eq!(en.distance(84.0), "80 m");
+        assert_eq!(en.distance(137.0), "150 m");
+        assert_eq!(en.distance(980.0), "1 km");
+        assert_eq!(en.distance(1460.0), "1.5 km");
+        assert_eq!(en.distance(12_600.0), "13 km");
+
+        let imp = text(Locale::En, UnitSystem::Imperial);
+        assert_eq!(imp.distance(30.0), "100 ft");
+        assert_eq!(imp.distance(100.0), "350 ft");
+        assert_eq!(imp.distance(800.0), "0.5 mi");
+        assert_eq!(imp.distance(20_000.0), "12 mi");
+    }
+
+    #[test]
+    fn dutch_distance_rounding() {
+        let nl = text(Locale::Nl, UnitSystem::Metric);
+        assert_eq!(nl.distance(137.0), "150 meter");
+        // Coarser 100 m steps from 500 m.
+        assert_eq!(nl.distance(640.0), "600 meter");
+        assert_eq!(nl.distance(2_340.0), "2,3 kilometer");
+        assert_eq!(
+            text(Locale::Nl, UnitSystem::Imperial).distance(2_414.0),
+            "1,5 mijl"
+        );
+    }
+
+    #[test]
+    fn german_distance_rounding() {
+        let de = text(Locale::De, UnitSystem::Metric);
+        // Finer 10 m steps up to 200 m.
+        assert_eq!(de.distance(137.0), "140 m");
+        assert_eq!(de.distance(640.0), "650 m");
+        assert_eq!(de.distance(2_340.0), "2,3 km");
+        assert_eq!(
+            text(Locale::De, UnitSystem::Imperial).distance(150.0),
+            "500 ft"
+        );
+    }
+
+    #[test]
+    fn french_distance_rounding() {
+        let fr = text(Locale::Fr, UnitSystem::Metric);
+        assert_eq!(fr.distance(137.0), "150 m");
+        assert_eq!(fr.distance(1_000.0), "1 km");
+        assert_eq!(fr.distance(9_960.0), "10 km");
+        assert_eq!(
+            text(Locale::Fr, UnitSystem::Imperial).distance(60.0),
+            "200 pi"
+        );
+    }
+
+    #[test]
+    fn phrases_per_locale() {
+        let p = Phrase::new(DerivedInstructionKind::TurnLeft)
+            .street(Some("Main Street"))
+            .distance(200.0);
+        let metric = UnitSystem::Metric;
+        assert_eq!(
+            text(Locale::En, metric).phrase(&p),
+            "In 200 m, turn left onto Main Street"
+        );
+        assert_eq!(
+            text(Locale::Nl, metric).phrase(&p),
+            "Over 200 meter sla linksaf naar Main Street"
+        );
+        assert_eq!(
+            text(Locale::De, metric).phrase(&p),
+            "In 200 m links abbiegen auf Main Street"
+        );
+        assert_eq!(
+            text(Locale::Fr, metric).phrase(&p),
+            "Dans 200 m, tournez à gauche sur Main Street"
+        );
+        assert_eq!(
+            text(Locale::En, metric).phrase(&Phrase::new(DerivedInstructionKind::Continue)),
+            "Continue straight"
+        );
+    }
+
+    #[test]
+    fn roundabout_exit_ordinals() {
+        let p = Phrase::new(DerivedInstructionKind::Continue).exit(1);
+        let metric = UnitSystem::Metric;
+        assert_eq!(
+            text(Locale::En, metric).phrase(&p),
+            "At the roundabout, take the 1st exit"
+        );
+        assert_eq!(
+            text(Locale::Fr, metric).phrase(&p),
+            "Au rond-point, prenez la 1re sortie"
+        );
+        assert_eq!(
+            text(Locale::De, metric).phrase(&p.exit(3)),
+            "Im Kreisverkehr die 3. Ausfahrt nehmen"
+        );
+    }
+
+    #[test]
+    fn merged_announcement_text() {
+        let a = Announcement {
+            stage: AnnouncementStage::Now,
+            step: 1,
+            kind: DerivedInstructionKind::TurnLeft,
+            street_name: None,
+            distance_m: 20.0,
+            then: Some(DerivedInstructionKind::TurnRight),
+        };
+        assert_eq!(
+            text(Locale::En, UnitSystem::Metric).announcement(&a),
+            "Turn left now, then turn right"
+        );
+    }
+
+    #[test]
+    fn locale_tags_parse() {
+        assert_eq!("nl-BE".parse::<Locale>(), Ok(Locale::Nl));
+        assert_eq!("de_AT".parse::<Locale>(), Ok(Locale::De));
+        assert!("xx".parse::<Locale>().is_err());
+    }
+}
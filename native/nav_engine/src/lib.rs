@@ -3,11 +3,42 @@
 //! Pure logic crate: no I/O, no async, no FFI. Depends only on `nav_ir`.
 //! Feed a `nav_ir::Route` and GPS position updates to get structured `NavigationState` snapshots.
 
+pub mod announcements;
+pub mod candidates;
+pub mod config;
+pub mod constraints;
 pub mod derive_instructions;
 pub mod engine;
+pub mod eta;
+pub mod filter;
+pub mod index;
+pub mod instruction_text;
+pub mod matcher;
 pub mod off_route;
 pub mod progress;
+pub mod rejoin;
+pub mod replay;
+pub mod simulator;
+pub mod snapshot;
+pub mod stops;
 pub mod types;
+pub mod waypoints;
+pub mod wrong_way;
 
+pub use announcements::{AnnouncementPlanner, RoadClass};
+pub use candidates::{CandidateState, CandidateTracker};
+pub use config::{EngineConfig, VehicleProfile};
+pub use constraints::ConstraintMonitor;
 pub use engine::NavigationEngine;
+pub use eta::{EtaModel, EtaState};
+pub use filter::RouteFilter;
+pub use index::PolylineIndex;
+pub use instruction_text::{InstructionText, Locale, Phrase, UnitSystem};
+pub use matcher::{MapMatcher, MatchResult};
+pub use off_route::OffRouteDetector;
+pub use replay::{parse_fix_csv, parse_fix_gpx, ReplayEntry, ReplayEvent, ReplayTimeline};
+pub use simulator::{Detour, RouteSimulator, SimulatorConfig, SpeedProfile};
+pub use snapshot::EngineSnapshot;
+pub use stops::StopAdvisor;
 pub use types::*;
+pub use wrong_way::WrongWayDetector;
@@ -0,0 +1,362 @@
+use nav_ir::Coordinate;
+use serde::{Deserialize, Serialize};
+
+use crate::config::EngineConfig;
+use crate::derive_instructions::{bearing, haversine_distance, normalize_delta};
+use crate::index::PolylineIndex;
+use crate::off_route::point_to_segment_distance;
+
+/// Movement between consecutive fixes below which no heading is derived (GPS jitter).
+const MIN_HEADING_MOVE_M: f64 = 5.0;
+/// How far behind the cursor the local window reaches, to absorb GPS jitter.
+const BACKTRACK_M: f64 = 30.0;
+/// Consecutive fixes without an acceptable local match before searching the whole route.
+const SUSTAINED_DISAGREEMENT_FIXES: u32 = 3;
+
+/// Result of matching one GPS fix onto the route polyline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchResult {
+    /// Distance from the fix to the matched point (meters).
+    pub distance_m: f64,
+    /// Index of the matched polyline segment (`vertices[i] → vertices[i + 1]`).
+    pub segment_index: usize,
+    /// Projection parameter along the matched segment, in `[0, 1]`.
+    pub fraction: f64,
+    /// Projected point on the matched segment.
+    pub snapped: Coordinate,
+    /// Heading derived from the previous fix, when the rider moved far enough to trust it.
+    pub heading_deg: Option<f64>,
+}
+
+impl MatchResult {
+    /// Closest vertex to the matched point, used for step advancement.
+    pub fn nearest_vertex(&self) -> usize {
+        if self.fraction < 0.5 {
+            self.segment_index
+        } else {
+            self.segment_index + 1
+        }
+    }
+}
+
+/// Monotonic, heading-aware map matcher.
+///
+/// Keeps a progress cursor on the polyline and searches a forward window from it first, so
+/// out-and-back rides and figure-eight loops don't jump to the wrong pass. Segments whose
+/// bearing disagrees with the GPS heading by more than `heading_tolerance_degrees` are
+/// rejected (opposite carriageway). The search only widens to the whole route after
+/// `SUSTAINED_DISAGREEMENT_FIXES` consecutive fixes with no acceptable local match; with no
+/// heading-compatible pass within the off-route threshold it keeps the local one, which the
+/// rider is then riding backwards.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MapMatcher {
+    cursor: Option<usize>,
+    last_fix: Option<Coordinate>,
+    disagreements: u32,
+}
+
+struct Candidate {
+    distance_m: f64,
+    segment_index: usize,
+    fraction: f64,
+    snapped: Coordinate,
+}
+
+impl MapMatcher {
+    /// Matcher with no progress yet; the first fix is matched against the whole route.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matcher resuming from a known segment (session resume).
+    pub fn resume_at(segment_index: usize) -> Self {
+        Self {
+            cursor: Some(segment_index),
+            ..Self::default()
+        }
+    }
+
+    /// Segment the matcher currently considers the rider to be on.
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Match a fix onto `vertices` (indexed by `index`) and advance the cursor.
+    pub fn match_position(
+        &mut self,
+        pos: Coordinate,
+        vertices: &[Coordinate],
+        index: &PolylineIndex,
+        config: &EngineConfig,
+    ) -> MatchResult {
+        self.match_position_with_heading(pos, None, vertices, index, config)
+    }
+
+    /// Like [`match_position`](Self::match_position), but prefers `heading_hint` (the fix's
+    /// own GPS bearing) over the heading derived from consecutive fixes.
+    pub fn match_position_with_heading(
+        &mut self,
+        pos: Coordinate,
+        heading_hint: Option<f64>,
+        vertices: &[Coordinate],
+        index: &PolylineIndex,
+        config: &EngineConfig,
+    ) -> MatchResult {
+        let moved_m = self
+            .last_fix
+            .map(|prev| haversine_distance(prev, pos))
+            .unwrap_or(0.0);
+        let heading_deg = match self.last_fix {
+            _ if heading_hint.is_some() => heading_hint,
+            Some(prev) if moved_m >= MIN_HEADING_MOVE_M => Some(bearing(prev, pos)),
+            _ => None,
+        };
+        self.last_fix = Some(pos);
+
+        match vertices.len() {
+            0 => return single(f64::MAX, pos, heading_deg),
+            1 => {
+                return single(
+                    haversine_distance(pos, vertices[0]),
+                    vertices[0],
+                    heading_deg,
+                )
+            }
+            _ => {}
+        }
+        let last_segment = vertices.len() - 2;
+
+        let chosen = match self.cursor {
+            None => global_best(pos, vertices, index, heading_deg, config),
+            Some(cursor) => {
+                let cursor = cursor.min(last_segment);
+                // Reach far enough forward to cover how far the rider could have moved.
+                let reach_m = config.match_window_m + 1.5 * moved_m;
+                let (first, last) = window(index, cursor, last_segment, BACKTRACK_M, reach_m);
+                let local = best_in(pos, vertices, first..=last, heading_deg, config);
+                match local {
+                    Some(c) if c.distance_m <= config.off_route_threshold_m => {
+                        self.disagreements = 0;
+                        c
+                    }
+                    _ => {
+                        self.disagreements += 1;
+                        if self.disagreements >= SUSTAINED_DISAGREEMENT_FIXES {
+                            self.disagreements = 0;
+                            let global = global_best(pos, vertices, index, heading_deg, config);
+                            // No other pass agrees with the heading nearby: the rider is on
+                            // this one, riding it backwards (see `WrongWayDetector`).
+                            let threshold = config.off_route_threshold_m;
+                            match best_in(pos, vertices, first..=last, None, config) {
+                                Some(c)
+                                    if c.distance_m <= threshold
+                                        && global.distance_m > threshold =>
+                                {
+                                    c
+                                }
+                                _ => global,
+                            }
+                        } else {
+                            // Not sustained yet: stay on the local pass, ignoring heading.
+                            local
+                                .or_else(|| best_in(pos, vertices, first..=last, None, config))
+                                .unwrap_or_else(|| global_best(pos, vertices, index, None, config))
+                        }
+                    }
+                }
+            }
+        };
+
+        self.cursor = Some(chosen.segment_index);
+        MatchResult {
+            distance_m: chosen.distance_m,
+            segment_index: chosen.segment_index,
+            fraction: chosen.fraction,
+            snapped: chosen.snapped,
+            heading_deg,
+        }
+    }
+}
+
+fn single(distance_m: f64, snapped: Coordinate, heading_deg: Option<f64>) -> MatchResult {
+    MatchResult {
+        distance_m,
+        segment_index: 0,
+        fraction: 0.0,
+        snapped,
+        heading_deg,
+    }
+}
+
+/// Segment range `[first, last]` around `cursor`: whole segments within `back_m` behind it,
+/// and segments starting less than `ahead_m` beyond its start.
+fn window(
+    index: &PolylineIndex,
+    cursor: usize,
+    last_segment: usize,
+    back_m: f64,
+    ahead_m: f64,
+) -> (usize, usize) {
+    let start_m = index.distance_at_vertex(cursor);
+    let first = index.first_vertex_at_or_after(start_m - back_m).min(cursor);
+    let last = index
+        .first_vertex_at_or_after(start_m + ahead_m)
+        .saturating_sub(1)
+        .clamp(cursor, last_segment);
+    (first, last)
+}
+
+fn heading_compatible(heading_deg: Option<f64>, a: Coordinate, b: Coordinate, tol: f64) -> bool {
+    let Some(heading) = heading_deg else {
+        return true;
+    };
+    if haversine_distance(a, b) < 0.5 {
+        return true;
+    }
+    normalize_delta(bearing(a, b) - heading).abs() <= tol
+}
+
+fn best_in(
+    pos: Coordinate,
+    vertices: &[Coordinate],
+    segments: std::ops::RangeInclusive<usize>,
+    heading_deg: Option<f64>,
+    config: &EngineConfig,
+) -> Option<Candidate> {
+    let mut best: Option<Candidate> = None;
+    for i in segments {
+        let (a, b) = (vertices[i], vertices[i + 1]);
+        if !heading_compatible(heading_deg, a, b, config.heading_tolerance_degrees) {
+            continue;
+        }
+        let (distance_m, fraction, snapped) = point_to_segment_distance(pos, a, b);
+        if best.as_ref().is_none_or(|c| distance_m < c.distance_m) {
+            best = Some(Candidate {
+                distance_m,
+                segment_index: i,
+                fraction,
+                snapped,
+            });
+        }
+    }
+    best
+}
+
+/// Whole-route search; falls back to ignoring heading when no segment is compatible.
+fn global_best(
+    pos: Coordinate,
+    vertices: &[Coordinate],
+    index: &PolylineIndex,
+    heading_deg: Option<f64>,
+    config: &EngineConfig,
+) -> Candidate {
+    let tol = config.heading_tolerance_degrees;
+    index
+        .nearest_segment(pos, vertices, |i| {
+            heading_compatible(heading_deg, vertices[i], vertices[i + 1], tol)
+        })
+        .or_else(|| index.nearest_segment(pos, vertices, |_| true))
+        .map(|(segment_index, distance_m, fraction, snapped)| Candidate {
+            distance_m,
+            segment_index,
+            fraction,
+            snapped,
+        })
+        .expect("polyline has at least one segment")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::off_route::distance_to_polyline;
+
+    fn c(lat: f64, lon: f64) -> Coordinate {
+        Coordinate::new(lat, lon)
+    }
+
+    #[test]
+    fn opposite_carriageway_rejected_by_heading() {
+        // Out-and-back: north on lon 0, back south ~22 m east.
+        let vertices = vec![c(0.0, 0.0), c(0.01, 0.0), c(0.01, 0.0002), c(0.0, 0.0002)];
+        let idx = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let mut m = MapMatcher::resume_at(2);
+        m.match_position(c(0.006, 0.00008), &vertices, &idx, &config);
+        // Heading south, ~9 m from the northbound line but ~13 m from the southbound one.
+        let r = m.match_position(c(0.005, 0.00008), &vertices, &idx, &config);
+        assert_eq!(r.segment_index, 2);
+        assert!(r.heading_deg.is_some());
+        // The global nearest-segment search snaps to the northbound line.
+        assert!(distance_to_polyline(c(0.005, 0.00008), &vertices).1 < 2);
+    }
+
+    #[test]
+    fn figure_eight_crossing_stays_on_current_pass() {
+        // Segment 0 (NE) and segment 2 (NW) cross at (0.005, 0.005).
+        let vertices = vec![c(0.0, 0.0), c(0.01, 0.01), c(0.01, 0.0), c(0.0, 0.01)];
+        let idx = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let mut m = MapMatcher::new();
+        assert_eq!(
+            m.match_position(c(0.0, 0.0), &vertices, &idx, &config)
+                .segment_index,
+            0
+        );
+        // Slightly closer to segment 2 than segment 0.
+        let r = m.match_position(c(0.00501, 0.00499), &vertices, &idx, &config);
+        assert_eq!(r.segment_index, 0);
+    }
+
+    #[test]
+    fn progress_does_not_jump_back_to_earlier_pass() {
+        // Out-and-back on the same line: later pass must not snap to the outbound leg.
+        let vertices = vec![c(0.0, 0.0), c(0.01, 0.0), c(0.0, 0.0)];
+        let idx = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let mut m = MapMatcher::resume_at(1);
+        m.match_position(c(0.004, 0.0), &vertices, &idx, &config);
+        let r = m.match_position(c(0.003, 0.0), &vertices, &idx, &config);
+        assert_eq!(r.segment_index, 1);
+    }
+
+    #[test]
+    fn widens_to_global_after_sustained_disagreement() {
+        let vertices: Vec<Coordinate> = (0..=10).map(|i| c(i as f64 * 0.005, 0.0)).collect();
+        let idx = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let mut m = MapMatcher::resume_at(0);
+        // Stationary fixes far ahead (e.g. after a ferry): no movement-derived reach.
+        let far = c(0.031, 0.0);
+        for _ in 1..SUSTAINED_DISAGREEMENT_FIXES {
+            assert_eq!(
+                m.match_position(far, &vertices, &idx, &config)
+                    .segment_index,
+                0
+            );
+        }
+        let r = m.match_position(far, &vertices, &idx, &config);
+        assert_eq!(r.segment_index, 6);
+        assert!(r.distance_m < 1.0);
+    }
+
+    #[test]
+    fn single_disagreement_keeps_local_match() {
+        let vertices: Vec<Coordinate> = (0..=10).map(|i| c(i as f64 * 0.005, 0.0)).collect();
+        let idx = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let mut m = MapMatcher::resume_at(0);
+        let r = m.match_position(c(0.03, 0.0), &vertices, &idx, &config);
+        assert_eq!(r.segment_index, 0);
+        assert!(r.distance_m > config.off_route_threshold_m);
+    }
+
+    #[test]
+    fn first_fix_uses_global_search() {
+        let vertices: Vec<Coordinate> = (0..=10).map(|i| c(i as f64 * 0.005, 0.0)).collect();
+        let idx = PolylineIndex::build(&vertices);
+        let mut m = MapMatcher::new();
+        let r = m.match_position(c(0.031, 0.0), &vertices, &idx, &EngineConfig::default());
+        assert_eq!(r.segment_index, 6);
+        assert_eq!(m.cursor(), Some(6));
+    }
+}
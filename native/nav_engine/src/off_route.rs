@@ -1,19 +1,81 @@
 use nav_ir::Coordinate;
+use serde::{Deserialize, Serialize};
 
+use crate::config::EngineConfig;
 use crate::derive_instructions::haversine_distance;
 
-/// Off-route threshold in meters.
-pub const OFF_ROUTE_THRESHOLD_M: f64 = 50.0;
+/// A confirmed off-route episode ends once the rider is back within this share of the
+/// threshold, so riding along the threshold doesn't flap.
+const REJOIN_FACTOR: f64 = 0.8;
+
+/// Off-route hysteresis.
+///
+/// A fix counts as beyond the route when its distance minus its reported accuracy exceeds
+/// `off_route_threshold_m`. The rider is confirmed off-route after
+/// `off_route_confirm_fixes` such fixes in a row, or once they have lasted
+/// `off_route_confirm_s` (timed fixes only). Confirmation clears when a fix comes back within
+/// 80% of the threshold.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OffRouteDetector {
+    beyond_fixes: u32,
+    beyond_since_ms: Option<u64>,
+    confirmed: bool,
+    /// The last update confirmed off-route.
+    entered: bool,
+}
+
+impl OffRouteDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one fix; returns whether the rider is (still) confirmed off-route.
+    pub fn update(
+        &mut self,
+        distance_m: f64,
+        accuracy_m: Option<f64>,
+        timestamp_ms: Option<u64>,
+        config: &EngineConfig,
+    ) -> bool {
+        let threshold = config.off_route_threshold_m;
+        self.entered = false;
+        if self.confirmed {
+            if distance_m <= threshold * REJOIN_FACTOR {
+                *self = Self::default();
+            }
+            return self.confirmed;
+        }
+        if distance_m - accuracy_m.unwrap_or(0.0) <= threshold {
+            *self = Self::default();
+            return false;
+        }
+        self.beyond_fixes += 1;
+        let lasted_s = timestamp_ms.map_or(0.0, |ts| {
+            let since = *self.beyond_since_ms.get_or_insert(ts);
+            ts.saturating_sub(since) as f64 / 1000.0
+        });
+        self.confirmed = self.beyond_fixes >= config.off_route_confirm_fixes
+            || lasted_s >= config.off_route_confirm_s;
+        self.entered = self.confirmed;
+        self.confirmed
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed
+    }
+
+    /// Whether the last `update` was the one that confirmed off-route.
+    pub fn entered(&self) -> bool {
+        self.entered
+    }
+}
 
 /// Returns `(distance_m, nearest_vertex_index, snapped_coordinate)` from `pos` to the polyline.
 ///
 /// `snapped_coordinate` is the actual projected point on the closest segment (not a vertex),
 /// giving smooth positional snapping rather than jumping between vertex endpoints.
 /// `nearest_vertex_index` is still the closest vertex index, used for step advancement logic.
-pub fn distance_to_polyline(
-    pos: Coordinate,
-    vertices: &[Coordinate],
-) -> (f64, usize, Coordinate) {
+pub fn distance_to_polyline(pos: Coordinate, vertices: &[Coordinate]) -> (f64, usize, Coordinate) {
     match vertices.len() {
         0 => return (f64::MAX, 0, pos),
         1 => return (haversine_distance(pos, vertices[0]), 0, vertices[0]),
@@ -40,7 +102,7 @@ pub fn distance_to_polyline(
 /// along segment `a→b` and `nearest_coord` is the actual projected point on the segment.
 ///
 /// Uses an approximate Cartesian projection — accurate enough for short navigation segments.
-fn point_to_segment_distance(
+pub(crate) fn point_to_segment_distance(
     pos: Coordinate,
     a: Coordinate,
     b: Coordinate,
@@ -61,6 +123,11 @@ fn point_to_segment_distance(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EngineConfig;
+
+    fn threshold() -> f64 {
+        EngineConfig::default().off_route_threshold_m
+    }
 
     fn c(lat: f64, lon: f64) -> Coordinate {
         Coordinate::new(lat, lon)
@@ -79,11 +146,7 @@ mod tests {
         // 1 degree lon ≈ 111 km → 0.0006 deg ≈ 66.7 m
         let vertices = vec![c(0.0, 0.0), c(1.0, 0.0)];
         let (dist, _, _) = distance_to_polyline(c(0.5, 0.0006), &vertices);
-        assert!(
-            dist > OFF_ROUTE_THRESHOLD_M,
-            "expected off-route, dist={:.1}m",
-            dist
-        );
+        assert!(dist > threshold(), "expected off-route, dist={:.1}m", dist);
     }
 
     #[test]
@@ -92,11 +155,7 @@ mod tests {
         // 0.0002 deg lon ≈ 22.3 m at equator
         let vertices = vec![c(0.0, 0.0), c(1.0, 0.0)];
         let (dist, _, _) = distance_to_polyline(c(0.5, 0.0002), &vertices);
-        assert!(
-            dist < OFF_ROUTE_THRESHOLD_M,
-            "expected on-route, dist={:.1}m",
-            dist
-        );
+        assert!(dist < threshold(), "expected on-route, dist={:.1}m", dist);
     }
 
     #[test]
@@ -106,4 +165,54 @@ mod tests {
         let (_, v1, _) = distance_to_polyline(c(1.5, 0.0), &vertices);
         assert!(v1 >= v0, "vertex index should advance along route");
     }
+
+    #[test]
+    fn confirms_after_consecutive_fixes() {
+        let config = EngineConfig::default();
+        let mut d = OffRouteDetector::new();
+        assert!(!d.update(80.0, None, None, &config));
+        assert!(!d.update(80.0, None, None, &config));
+        assert!(d.update(80.0, None, None, &config));
+    }
+
+    #[test]
+    fn single_excursion_is_not_confirmed() {
+        let config = EngineConfig::default();
+        let mut d = OffRouteDetector::new();
+        d.update(80.0, None, None, &config);
+        d.update(80.0, None, None, &config);
+        assert!(!d.update(10.0, None, None, &config));
+        assert!(!d.update(80.0, None, None, &config));
+    }
+
+    #[test]
+    fn confirms_after_duration_with_sparse_fixes() {
+        let config = EngineConfig::default();
+        let mut d = OffRouteDetector::new();
+        assert!(!d.update(80.0, None, Some(0), &config));
+        assert!(d.update(80.0, None, Some(6_000), &config));
+    }
+
+    #[test]
+    fn inaccurate_fixes_need_more_distance() {
+        let config = EngineConfig::default();
+        let mut d = OffRouteDetector::new();
+        for _ in 0..5 {
+            assert!(!d.update(70.0, Some(30.0), None, &config));
+        }
+    }
+
+    #[test]
+    fn rejoin_needs_margin_inside_threshold() {
+        let config = EngineConfig::default();
+        let mut d = OffRouteDetector::new();
+        for _ in 0..3 {
+            d.update(80.0, None, None, &config);
+        }
+        assert!(
+            d.update(45.0, None, None, &config),
+            "45 m is inside 50 m but not 40 m"
+        );
+        assert!(!d.update(35.0, None, None, &config));
+    }
 }
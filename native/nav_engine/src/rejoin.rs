@@ -0,0 +1,122 @@
+use nav_ir::Coordinate;
+
+use crate::config::EngineConfig;
+use crate::derive_instructions::{bearing, haversine_distance, kind_from_delta, normalize_delta};
+use crate::index::PolylineIndex;
+use crate::types::RejoinGuidance;
+
+/// Way back onto the track between along-route distance `from_m` and polyline segment
+/// `last_segment`: the closest point of that stretch, so never behind where the rider left.
+///
+/// `heading_deg` (the rider's course) turns the bearing into a turn direction.
+pub fn best_rejoin(
+    pos: Coordinate,
+    heading_deg: Option<f64>,
+    from_m: f64,
+    last_segment: usize,
+    vertices: &[Coordinate],
+    index: &PolylineIndex,
+    config: &EngineConfig,
+) -> Option<RejoinGuidance> {
+    if vertices.len() < 2 {
+        return None;
+    }
+    let (first, from_fraction) = index.progress_at_distance(from_m);
+    let last = last_segment.min(vertices.len() - 2);
+    if first > last {
+        return None;
+    }
+    let (segment, fraction, point) = index
+        .nearest_segment(pos, vertices, |i| (first..=last).contains(&i))
+        .map(|(segment, _, fraction, point)| {
+            if segment == first && fraction < from_fraction {
+                // The projection falls behind the exit point; rejoin at the exit point.
+                let (a, b) = (vertices[first], vertices[first + 1]);
+                let at = Coordinate::new(
+                    a.latitude + (b.latitude - a.latitude) * from_fraction,
+                    a.longitude + (b.longitude - a.longitude) * from_fraction,
+                );
+                (segment, from_fraction, at)
+            } else {
+                (segment, fraction, point)
+            }
+        })?;
+
+    let bearing_deg = bearing(pos, point).rem_euclid(360.0);
+    Some(RejoinGuidance {
+        point,
+        along_m: index.distance_along(segment, fraction),
+        distance_m: haversine_distance(pos, point),
+        bearing_deg,
+        direction: heading_deg
+            .map(|h| kind_from_delta(normalize_delta(bearing_deg - h), config.min_turn_degrees)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DerivedInstructionKind;
+
+    fn c(lat: f64, lon: f64) -> Coordinate {
+        Coordinate::new(lat, lon)
+    }
+
+    /// North-going track, 10 vertices 0.001° (~111 m) apart.
+    fn track() -> Vec<Coordinate> {
+        (0..10).map(|i| c(i as f64 * 0.001, 0.0)).collect()
+    }
+
+    #[test]
+    fn rejoins_at_nearest_point_ahead() {
+        let vertices = track();
+        let index = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        // Left the track at ~222 m; now 200 m east of the 555 m mark.
+        let pos = c(0.005, 0.0018);
+        let g = best_rejoin(pos, None, 222.0, 8, &vertices, &index, &config).unwrap();
+        assert!((g.point.latitude - 0.005).abs() < 1e-6);
+        assert!((g.along_m - 556.0).abs() < 2.0, "{}", g.along_m);
+        assert!((g.distance_m - 200.0).abs() < 2.0, "{}", g.distance_m);
+        assert!((g.bearing_deg - 270.0).abs() < 1.0, "{}", g.bearing_deg);
+        assert_eq!(g.direction, None);
+    }
+
+    #[test]
+    fn never_rejoins_behind_exit_point() {
+        let vertices = track();
+        let index = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        // Rider went back beside the start, but left the track at ~555 m.
+        let pos = c(0.001, 0.001);
+        let g = best_rejoin(pos, None, 555.0, 8, &vertices, &index, &config).unwrap();
+        assert!((g.along_m - 555.0).abs() < 1.0, "{}", g.along_m);
+        assert!(g.point.latitude > 0.0049);
+    }
+
+    #[test]
+    fn direction_is_relative_to_heading() {
+        let vertices = track();
+        let index = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        let pos = c(0.005, 0.0018);
+        // Track is due west: riding north means turning left, riding south turning right.
+        let north = best_rejoin(pos, Some(0.0), 0.0, 8, &vertices, &index, &config).unwrap();
+        assert_eq!(north.direction, Some(DerivedInstructionKind::TurnLeft));
+        let south = best_rejoin(pos, Some(180.0), 0.0, 8, &vertices, &index, &config).unwrap();
+        assert_eq!(south.direction, Some(DerivedInstructionKind::TurnRight));
+        let west = best_rejoin(pos, Some(270.0), 0.0, 8, &vertices, &index, &config).unwrap();
+        assert_eq!(west.direction, Some(DerivedInstructionKind::Continue));
+    }
+
+    #[test]
+    fn stays_within_the_segment() {
+        let vertices = track();
+        let index = PolylineIndex::build(&vertices);
+        let config = EngineConfig::default();
+        // Closest to vertex 8, but the stretch ends at segment 4 (vertex 5).
+        let pos = c(0.008, 0.0005);
+        let g = best_rejoin(pos, None, 0.0, 4, &vertices, &index, &config).unwrap();
+        assert!((g.point.latitude - 0.005).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,345 @@
+use nav_ir::Route;
+use serde::{Deserialize, Serialize};
+
+use crate::config::EngineConfig;
+use crate::derive_instructions::haversine_distance;
+use crate::engine::NavigationEngine;
+use crate::types::{GpsFix, NavigationState};
+
+/// Read a recorded fix log from CSV.
+///
+/// The first row names the columns, in any order: `timestamp_ms` (or `time_ms`), `lat` (or
+/// `latitude`), `lon` (or `lng`, `longitude`), and optionally `accuracy_m`, `bearing_deg`,
+/// `speed_mps`. Empty cells are missing values; `#` starts a comment line.
+pub fn parse_fix_csv(text: &str) -> Result<Vec<GpsFix>, String> {
+    let mut lines = text
+        .lines()
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty() && !l.starts_with('#'));
+    let (_, header) = lines.next().ok_or("Fix log is empty")?;
+    let columns: Vec<String> = header
+        .split(',')
+        .map(|c| c.trim().to_ascii_lowercase())
+        .collect();
+    let column = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+    let lat = column(&["lat", "latitude"]).ok_or("Fix log has no lat column")?;
+    let lon = column(&["lon", "lng", "longitude"]).ok_or("Fix log has no lon column")?;
+    let timestamp = column(&["timestamp_ms", "time_ms"]);
+    let accuracy = column(&["accuracy_m"]);
+    let bearing = column(&["bearing_deg"]);
+    let speed = column(&["speed_mps"]);
+
+    lines
+        .map(|(n, line)| {
+            let cells: Vec<&str> = line.split(',').map(str::trim).collect();
+            let cell = |i: Option<usize>| -> Result<Option<f64>, String> {
+                match i.and_then(|i| cells.get(i)).filter(|c| !c.is_empty()) {
+                    Some(c) => c
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| format!("Line {}: invalid number {c:?}", n + 1)),
+                    None => Ok(None),
+                }
+            };
+            let required = |i| cell(Some(i))?.ok_or(format!("Line {}: missing coordinate", n + 1));
+            Ok(GpsFix {
+                coordinate: nav_ir::Coordinate::new(required(lat)?, required(lon)?),
+                accuracy_m: cell(accuracy)?,
+                bearing_deg: cell(bearing)?,
+                speed_mps: cell(speed)?,
+                timestamp_ms: cell(timestamp)?.map(|t| t as u64),
+            })
+        })
+        .collect()
+}
+
+/// Read a recorded fix log from a GPX track. Speed is derived from consecutive timed points.
+pub fn parse_fix_gpx(bytes: &[u8]) -> Result<Vec<GpsFix>, String> {
+    let points = nav_ir::gpx_track_points(bytes)?;
+    let mut fixes: Vec<GpsFix> = Vec::with_capacity(points.len());
+    for point in points {
+        let timestamp_ms = point.time.map(|t| t.timestamp_millis().max(0) as u64);
+        let speed_mps = fixes.last().and_then(|prev| {
+            let dt_s = timestamp_ms?.checked_sub(prev.timestamp_ms?)? as f64 / 1000.0;
+            (dt_s > 0.0).then(|| haversine_distance(prev.coordinate, point.coordinate) / dt_s)
+        });
+        fixes.push(GpsFix {
+            speed_mps,
+            timestamp_ms,
+            ..GpsFix::new(point.coordinate)
+        });
+    }
+    Ok(fixes)
+}
+
+/// Something that changed between two consecutive states of a replay.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ReplayEvent {
+    Step {
+        from: usize,
+        to: usize,
+    },
+    OffRoute,
+    BackOnRoute,
+    /// Riding against the route direction was confirmed ("make a U-turn").
+    WrongWay,
+    WaypointReached {
+        index: usize,
+    },
+    Announcement {
+        stage: String,
+        step: usize,
+    },
+    /// The ETA moved into another band (see [`eta_band_minutes`]).
+    EtaBand {
+        minutes: u64,
+    },
+    Arrived,
+}
+
+/// A [`ReplayEvent`] and the fix that caused it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    /// Index of the fix in the log.
+    pub fix: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_ms: Option<u64>,
+    #[serde(flatten)]
+    pub event: ReplayEvent,
+}
+
+/// ETA rounded down to a band: whole minutes up to 10 minutes, then 5-minute steps, so small
+/// ETA jitter doesn't churn golden snapshots.
+pub fn eta_band_minutes(eta_seconds: u64) -> u64 {
+    let minutes = eta_seconds / 60;
+    if minutes < 10 {
+        minutes
+    } else {
+        minutes - minutes % 5
+    }
+}
+
+/// Deterministic replay of a recorded fix log through a [`NavigationEngine`].
+///
+/// Keeps every `NavigationState` plus a timeline of what changed: step changes, off-route
+/// flags, waypoints, announcements, ETA bands and arrival. The timeline is written as a
+/// golden snapshot (one JSON object per line) and compared against the committed one, so a
+/// field bug report becomes a regression test for the matcher and instruction logic.
+#[derive(Debug, Clone)]
+pub struct ReplayTimeline {
+    pub states: Vec<NavigationState>,
+    pub entries: Vec<ReplayEntry>,
+}
+
+impl ReplayTimeline {
+    pub fn run(
+        route: Route,
+        config: EngineConfig,
+        fixes: impl IntoIterator<Item = GpsFix>,
+    ) -> Self {
+        let mut engine = NavigationEngine::new(route, config);
+        let mut states: Vec<NavigationState> = Vec::new();
+        let mut entries = Vec::new();
+        for (fix_index, fix) in fixes.into_iter().enumerate() {
+            let state = engine.update_fix(fix);
+            let mut push = |event| {
+                entries.push(ReplayEntry {
+                    fix: fix_index,
+                    timestamp_ms: fix.timestamp_ms,
+                    event,
+                })
+            };
+            let prev = states.last();
+            let prev_step = prev.map_or(0, |p| p.current_step);
+            if state.current_step != prev_step {
+                push(ReplayEvent::Step {
+                    from: prev_step,
+                    to: state.current_step,
+                });
+            }
+            let was_off_route = prev.is_some_and(|p| p.off_route.is_off_route);
+            match (was_off_route, state.off_route.is_off_route) {
+                (false, true) => push(ReplayEvent::OffRoute),
+                (true, false) => push(ReplayEvent::BackOnRoute),
+                _ => {}
+            }
+            if state.wrong_way.as_ref().is_some_and(|w| w.entered) {
+                push(ReplayEvent::WrongWay);
+            }
+            for &index in &state.reached_waypoints {
+                push(ReplayEvent::WaypointReached { index });
+            }
+            for a in &state.announcements {
+                push(ReplayEvent::Announcement {
+                    stage: a.stage.as_str().to_string(),
+                    step: a.step,
+                });
+            }
+            let band = eta_band_minutes(state.eta_seconds);
+            if prev.is_none_or(|p| eta_band_minutes(p.eta_seconds) != band) {
+                push(ReplayEvent::EtaBand { minutes: band });
+            }
+            if state.arrived && !prev.is_some_and(|p| p.arrived) {
+                push(ReplayEvent::Arrived);
+            }
+            states.push(state);
+        }
+        Self { states, entries }
+    }
+
+    /// The timeline as a golden snapshot: one JSON entry per line.
+    pub fn to_golden(&self) -> String {
+        self.entries
+            .iter()
+            .map(|e| serde_json::to_string(e).expect("replay entries serialize") + "\n")
+            .collect()
+    }
+
+    /// Compare against a golden snapshot; the error lists the first differences.
+    pub fn compare_golden(&self, golden: &str) -> Result<(), String> {
+        let expected: Vec<ReplayEntry> = golden
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .enumerate()
+            .map(|(n, l)| {
+                serde_json::from_str(l).map_err(|e| format!("golden line {}: {e}", n + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        if expected == self.entries {
+            return Ok(());
+        }
+        let show = |e: Option<&ReplayEntry>| {
+            e.map_or("<none>".to_string(), |e| serde_json::to_string(e).unwrap())
+        };
+        let diffs: Vec<String> = (0..expected.len().max(self.entries.len()))
+            .filter(|&i| expected.get(i) != self.entries.get(i))
+            .take(5)
+            .map(|i| {
+                format!(
+                    "entry {i}:\n  expected {}\n  actual   {}",
+                    show(expected.get(i)),
+                    show(self.entries.get(i))
+                )
+            })
+            .collect();
+        Err(format!(
+            "replay differs from golden ({} expected, {} actual entries)\n{}",
+            expected.len(),
+            self.entries.len(),
+            diffs.join("\n")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn csv_columns_are_found_by_name() {
+        let fixes = parse_fix_csv(
+            "# recorded 2026-10-01\n\
+             lon,lat,timestamp_ms,speed_mps\n\
+             4.35,50.85,1000,12.5\n\
+             4.36,50.86,2000,\n",
+        )
+        .unwrap();
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(fixes[0].coordinate.latitude, 50.85);
+        assert_eq!(fixes[0].coordinate.longitude, 4.35);
+        assert_eq!(fixes[0].speed_mps, Some(12.5));
+        assert_eq!(fixes[1].speed_mps, None);
+        assert_eq!(fixes[1].timestamp_ms, Some(2000));
+        assert_eq!(fixes[1].accuracy_m, None);
+    }
+
+    #[test]
+    fn csv_errors_name_the_line() {
+        let err = parse_fix_csv("lat,lon\n50.0,x\n").unwrap_err();
+        assert!(err.contains("Line 2"), "{err}");
+        assert!(parse_fix_csv("time_ms,x\n").is_err());
+    }
+
+    #[test]
+    fn gpx_track_derives_speed_from_time() {
+        let gpx = br#"<?xml version="1.0"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk><trkseg>
+    <trkpt lat="0.0" lon="0.0"><time>2026-10-01T10:00:00Z</time></trkpt>
+    <trkpt lat="0.001" lon="0.0"><time>2026-10-01T10:00:10Z</time></trkpt>
+  </trkseg></trk>
+</gpx>"#;
+        let fixes = parse_fix_gpx(gpx).unwrap();
+        assert_eq!(fixes.len(), 2);
+        assert_eq!(
+            fixes[1].timestamp_ms.unwrap() - fixes[0].timestamp_ms.unwrap(),
+            10_000
+        );
+        assert!((fixes[1].speed_mps.unwrap() - 11.1).abs() < 0.1);
+    }
+
+    #[test]
+    fn golden_mismatch_is_reported() {
+        let timeline = ReplayTimeline {
+            states: vec![],
+            entries: vec![ReplayEntry {
+                fix: 3,
+                timestamp_ms: Some(3000),
+                event: ReplayEvent::Step { from: 0, to: 1 },
+            }],
+        };
+        let golden = timeline.to_golden();
+        assert_eq!(
+            golden,
+            "{\"fix\":3,\"timestamp_ms\":3000,\"event\":\"step\",\"from\":0,\"to\":1}\n"
+        );
+        assert!(timeline.compare_golden(&golden).is_ok());
+        let err = timeline
+            .compare_golden(&golden.replace("\"to\":1", "\"to\":2"))
+            .unwrap_err();
+        assert!(err.contains("entry 0"), "{err}");
+    }
+
+    /// Replays every recorded drive in `tests/replays/<name>/` (`route.json`, `fixes.csv` or
+    /// `fixes.gpx`, optional `profile`) against its `golden.jsonl`. Run with
+    /// `UPDATE_GOLDEN=1` to rewrite the snapshots after an intended behavior change.
+    #[test]
+    fn recorded_drives_match_golden_snapshots() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/replays");
+        let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+        let mut dirs: Vec<_> = std::fs::read_dir(&root)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .filter(|p| p.is_dir())
+            .collect();
+        dirs.sort();
+        assert!(!dirs.is_empty());
+        let mut failures = vec![];
+        for dir in dirs {
+            let route: Route =
+                serde_json::from_str(&std::fs::read_to_string(dir.join("route.json")).unwrap())
+                    .unwrap();
+            let fixes = match std::fs::read_to_string(dir.join("fixes.csv")) {
+                Ok(csv) => parse_fix_csv(&csv).unwrap(),
+                Err(_) => parse_fix_gpx(&std::fs::read(dir.join("fixes.gpx")).unwrap()).unwrap(),
+            };
+            let profile = std::fs::read_to_string(dir.join("profile"))
+                .map(|p| p.trim().parse().unwrap())
+                .unwrap_or_default();
+            let timeline = ReplayTimeline::run(route, EngineConfig::for_profile(profile), fixes);
+            let golden_path = dir.join("golden.jsonl");
+            if update {
+                std::fs::write(&golden_path, timeline.to_golden()).unwrap();
+                continue;
+            }
+            let golden = std::fs::read_to_string(&golden_path).unwrap_or_default();
+            if let Err(e) = timeline.compare_golden(&golden) {
+                failures.push(format!("{}: {e}", dir.display()));
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+    }
+}
@@ -0,0 +1,413 @@
+use nav_ir::{Coordinate, Route};
+use serde::{Deserialize, Serialize};
+
+use crate::announcements::RoadClass;
+use crate::config::EngineConfig;
+use crate::derive_instructions::bearing;
+use crate::engine::NavigationEngine;
+use crate::types::{DerivedInstructionKind, GpsFix};
+
+const METERS_PER_DEG_LAT: f64 = 111_320.0;
+/// The simulated rider never stops completely, so every run reaches the end.
+const MIN_SPEED_MPS: f64 = 0.5;
+/// Share of a detour spent moving away from (and back to) the route.
+const DETOUR_RAMP: f64 = 0.2;
+
+/// How fast the simulated rider moves along the route.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SpeedProfile {
+    /// The same speed everywhere.
+    Constant { speed_mps: f64 },
+    /// Speed by road class of the segment, inferred from its speed limit (urban without one).
+    ByRoadClass {
+        urban_mps: f64,
+        rural_mps: f64,
+        highway_mps: f64,
+    },
+    /// Cruise, braking to `turn_mps` into every turn and accelerating out of it.
+    TurnAware {
+        cruise_mps: f64,
+        turn_mps: f64,
+        accel_mps2: f64,
+    },
+}
+
+/// A deliberate excursion to the right of the route (negative `offset_m`: to the left).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Detour {
+    /// Along-route distance where the rider starts leaving the route (meters).
+    pub start_m: f64,
+    /// Along-route length of the excursion (meters).
+    pub length_m: f64,
+    /// Largest sideways distance from the route (meters).
+    pub offset_m: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SimulatorConfig {
+    pub speed: SpeedProfile,
+    /// Time between fixes (ms).
+    pub interval_ms: u64,
+    /// Timestamp of the first fix (ms).
+    pub start_timestamp_ms: u64,
+    /// Horizontal GPS noise (meters, 1σ per axis).
+    pub noise_m: f64,
+    /// Probability that a fix is lost, in `[0, 1]`. The final fix is never dropped.
+    pub dropout_probability: f64,
+    pub detours: Vec<Detour>,
+    /// Seed for noise and dropouts; equal seeds give equal runs.
+    pub seed: u64,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            speed: SpeedProfile::Constant { speed_mps: 13.9 },
+            interval_ms: 1000,
+            start_timestamp_ms: 0,
+            noise_m: 0.0,
+            dropout_probability: 0.0,
+            detours: vec![],
+            seed: 1,
+        }
+    }
+}
+
+/// Synthetic GPS fixes along a route, for demos and tests.
+///
+/// Walks the route's chained polyline from start to end at the configured speed profile and
+/// yields one timestamped [`GpsFix`] per interval, with noise, dropouts and detours applied.
+/// Dropped fixes are simply missing: the next fix's timestamp jumps.
+pub struct RouteSimulator {
+    engine: NavigationEngine,
+    config: SimulatorConfig,
+    /// Along-route distance of every turn, for `SpeedProfile::TurnAware`.
+    turns_m: Vec<f64>,
+    along_m: f64,
+    timestamp_ms: u64,
+    rng: u64,
+    done: bool,
+}
+
+impl RouteSimulator {
+    pub fn new(route: Route, config: SimulatorConfig) -> Self {
+        let engine = NavigationEngine::new(route, EngineConfig::default());
+        let turns_m = engine
+            .instructions()
+            .iter()
+            .filter(|i| {
+                !matches!(
+                    i.kind,
+                    DerivedInstructionKind::Depart
+                        | DerivedInstructionKind::Continue
+                        | DerivedInstructionKind::SegmentTransition
+                        | DerivedInstructionKind::Arrive
+                )
+            })
+            .map(|i| engine.index().distance_at_vertex(i.vertex_index))
+            .collect();
+        Self {
+            done: engine.polyline_vertices().len() < 2,
+            timestamp_ms: config.start_timestamp_ms,
+            rng: config.seed,
+            engine,
+            config,
+            turns_m,
+            along_m: 0.0,
+        }
+    }
+
+    /// Length of the simulated polyline (meters).
+    pub fn total_m(&self) -> f64 {
+        self.engine.index().total_m()
+    }
+
+    /// Speed of the profile at along-route distance `along_m` (m/s).
+    pub fn speed_at(&self, along_m: f64) -> f64 {
+        let speed = match self.config.speed {
+            SpeedProfile::Constant { speed_mps } => speed_mps,
+            SpeedProfile::ByRoadClass {
+                urban_mps,
+                rural_mps,
+                highway_mps,
+            } => {
+                let (segment, _) = self.engine.index().progress_at_distance(along_m);
+                let max_speed = self
+                    .engine
+                    .route()
+                    .segments
+                    .get(self.engine.route_segment_at(segment))
+                    .and_then(|s| s.constraints.max_speed_kmh);
+                match RoadClass::infer(max_speed, 0.0) {
+                    RoadClass::Urban => urban_mps,
+                    RoadClass::Rural => rural_mps,
+                    RoadClass::Highway => highway_mps,
+                }
+            }
+            SpeedProfile::TurnAware {
+                cruise_mps,
+                turn_mps,
+                accel_mps2,
+            } => {
+                // v² = v_turn² + 2·a·d, towards the nearest turn on either side.
+                let nearest_turn_m = self
+                    .turns_m
+                    .iter()
+                    .map(|t| (t - along_m).abs())
+                    .fold(f64::INFINITY, f64::min);
+                cruise_mps.min((turn_mps * turn_mps + 2.0 * accel_mps2 * nearest_turn_m).sqrt())
+            }
+        };
+        speed.max(MIN_SPEED_MPS)
+    }
+
+    /// Noise-free position at `along_m`, including detours, and the route bearing there.
+    fn position_at(&self, along_m: f64) -> (Coordinate, f64) {
+        let vertices = self.engine.polyline_vertices();
+        let (segment, fraction) = self.engine.index().progress_at_distance(along_m);
+        let (a, b) = (vertices[segment], vertices[segment + 1]);
+        let on_route = Coordinate::new(
+            a.latitude + (b.latitude - a.latitude) * fraction,
+            a.longitude + (b.longitude - a.longitude) * fraction,
+        );
+        let course = bearing(a, b).rem_euclid(360.0);
+        let sideways_m: f64 = self
+            .config
+            .detours
+            .iter()
+            .filter(|d| d.length_m > 0.0)
+            .map(|d| {
+                let t = (along_m - d.start_m) / d.length_m;
+                if (0.0..=1.0).contains(&t) {
+                    d.offset_m * (t / DETOUR_RAMP).min((1.0 - t) / DETOUR_RAMP).min(1.0)
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        let right = (course + 90.0).to_radians();
+        let pos = shift(on_route, sideways_m * right.cos(), sideways_m * right.sin());
+        (pos, course)
+    }
+
+    /// SplitMix64; uniform in `[0, 1)`.
+    fn next_uniform(&mut self) -> f64 {
+        self.rng = self.rng.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal sample (Box–Muller).
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_uniform().max(f64::MIN_POSITIVE);
+        let u2 = self.next_uniform();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+impl Iterator for RouteSimulator {
+    type Item = GpsFix;
+
+    fn next(&mut self) -> Option<GpsFix> {
+        loop {
+            if self.done {
+                return None;
+            }
+            let total_m = self.total_m();
+            let along_m = self.along_m;
+            let speed = self.speed_at(along_m);
+            let (pos, course) = self.position_at(along_m);
+            let timestamp_ms = self.timestamp_ms;
+
+            let last = along_m >= total_m;
+            self.done = last;
+            self.along_m = (along_m + speed * self.config.interval_ms as f64 / 1000.0).min(total_m);
+            self.timestamp_ms += self.config.interval_ms;
+
+            let dropped = self.next_uniform() < self.config.dropout_probability;
+            let (north_m, east_m) = (self.next_gaussian(), self.next_gaussian());
+            if dropped && !last {
+                continue;
+            }
+            let noise = self.config.noise_m;
+            return Some(GpsFix {
+                coordinate: shift(pos, north_m * noise, east_m * noise),
+                accuracy_m: Some(noise.max(3.0)),
+                bearing_deg: Some(course),
+                speed_mps: Some(speed),
+                timestamp_ms: Some(timestamp_ms),
+            });
+        }
+    }
+}
+
+/// Move `pos` by the given distances (meters; equirectangular, fine at these scales).
+fn shift(pos: Coordinate, north_m: f64, east_m: f64) -> Coordinate {
+    let lat_scale = pos.latitude.to_radians().cos().max(0.01);
+    Coordinate::new(
+        pos.latitude + north_m / METERS_PER_DEG_LAT,
+        pos.longitude + east_m / (METERS_PER_DEG_LAT * lat_scale),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::off_route::distance_to_polyline;
+    use chrono::Utc;
+    use nav_ir::*;
+
+    /// North 0.01° (~1.1 km), then east 0.01°: one right turn at ~1.1 km.
+    fn route() -> Route {
+        let coords = vec![
+            geo_types::Coord { x: 0.0, y: 0.0 },
+            geo_types::Coord { x: 0.0, y: 0.01 },
+            geo_types::Coord { x: 0.01, y: 0.01 },
+        ];
+        Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: "Sim".into(),
+                description: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                total_distance_m: None,
+                estimated_duration_s: None,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap()),
+                    source: GeometrySource::SnappedToGraph,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: 0.0,
+                        min_lon: 0.0,
+                        max_lat: 0.01,
+                        max_lon: 0.01,
+                    },
+                },
+                waypoints: vec![],
+                legs: vec![],
+                instructions: vec![],
+                constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
+            }],
+            policies: RoutePolicies::default(),
+        }
+    }
+
+    fn vertices() -> Vec<Coordinate> {
+        vec![
+            Coordinate::new(0.0, 0.0),
+            Coordinate::new(0.01, 0.0),
+            Coordinate::new(0.01, 0.01),
+        ]
+    }
+
+    #[test]
+    fn constant_speed_walks_route_to_the_end() {
+        let config = SimulatorConfig {
+            speed: SpeedProfile::Constant { speed_mps: 20.0 },
+            ..SimulatorConfig::default()
+        };
+        let sim = RouteSimulator::new(route(), config);
+        let total = sim.total_m();
+        let fixes: Vec<GpsFix> = sim.collect();
+        assert_eq!(fixes.len(), (total / 20.0).ceil() as usize + 1);
+        for (i, fix) in fixes.iter().enumerate() {
+            assert_eq!(fix.timestamp_ms, Some(i as u64 * 1000));
+            let (d, _, _) = distance_to_polyline(fix.coordinate, &vertices());
+            assert!(d < 0.5, "noise-free fix {i} is {d} m off");
+        }
+        let end = fixes.last().unwrap().coordinate;
+        assert!((end.latitude - 0.01).abs() < 1e-6 && (end.longitude - 0.01).abs() < 1e-6);
+    }
+
+    #[test]
+    fn noise_and_dropouts_are_seeded() {
+        let config = SimulatorConfig {
+            noise_m: 8.0,
+            dropout_probability: 0.3,
+            seed: 42,
+            ..SimulatorConfig::default()
+        };
+        let a: Vec<GpsFix> = RouteSimulator::new(route(), config.clone()).collect();
+        let b: Vec<GpsFix> = RouteSimulator::new(route(), config).collect();
+        assert_eq!(a, b);
+
+        let clean = RouteSimulator::new(route(), SimulatorConfig::default()).count();
+        assert!(
+            a.len() < clean * 85 / 100 && a.len() > clean / 2,
+            "{}",
+            a.len()
+        );
+        let worst = a
+            .iter()
+            .map(|f| distance_to_polyline(f.coordinate, &vertices()).0)
+            .fold(0.0, f64::max);
+        assert!(worst > 5.0 && worst < 60.0, "{worst}");
+    }
+
+    #[test]
+    fn detour_leaves_and_returns_to_route() {
+        let config = SimulatorConfig {
+            speed: SpeedProfile::Constant { speed_mps: 10.0 },
+            detours: vec![Detour {
+                start_m: 200.0,
+                length_m: 500.0,
+                offset_m: 150.0,
+            }],
+            ..SimulatorConfig::default()
+        };
+        let off: Vec<f64> = RouteSimulator::new(route(), config)
+            .map(|f| distance_to_polyline(f.coordinate, &vertices()).0)
+            .collect();
+        assert!(off[10] < 1.0);
+        assert!((off[45] - 150.0).abs() < 2.0, "{}", off[45]);
+        assert!(off[80] < 1.0);
+    }
+
+    #[test]
+    fn turn_aware_profile_slows_into_turns() {
+        let config = SimulatorConfig {
+            speed: SpeedProfile::TurnAware {
+                cruise_mps: 25.0,
+                turn_mps: 5.0,
+                accel_mps2: 1.5,
+            },
+            ..SimulatorConfig::default()
+        };
+        let sim = RouteSimulator::new(route(), config);
+        let turn_m = 1113.0;
+        assert_eq!(sim.speed_at(500.0), 25.0);
+        assert!((sim.speed_at(turn_m) - 5.0).abs() < 0.5);
+        assert!(sim.speed_at(turn_m - 50.0) < sim.speed_at(turn_m - 150.0));
+        assert!(sim.speed_at(turn_m + 50.0) < sim.speed_at(turn_m + 150.0));
+    }
+
+    #[test]
+    fn engine_follows_simulated_ride_to_arrival() {
+        let config = SimulatorConfig {
+            noise_m: 5.0,
+            dropout_probability: 0.1,
+            ..SimulatorConfig::default()
+        };
+        let mut engine = NavigationEngine::new(route(), EngineConfig::default());
+        let states: Vec<_> = RouteSimulator::new(route(), config)
+            .map(|fix| engine.update_fix(fix))
+            .collect();
+        assert!(states.iter().all(|s| !s.off_route.is_off_route));
+        assert!(states.last().unwrap().distance_remaining_m < 30.0);
+    }
+}
@@ -0,0 +1,290 @@
+use nav_ir::RouteId;
+use serde::{Deserialize, Serialize};
+
+use crate::announcements::AnnouncementPlanner;
+use crate::eta::EtaState;
+use crate::filter::RouteFilter;
+use crate::matcher::MapMatcher;
+use crate::off_route::OffRouteDetector;
+use crate::stops::StopAdvisor;
+use crate::types::{ConstraintAlert, RejoinGuidance, RouteProgress};
+use crate::wrong_way::WrongWayDetector;
+
+/// Everything a [`NavigationEngine`](crate::NavigationEngine) has learned from the fixes so
+/// far: the progress cursor, the smoothing filter, off-route and wrong-way counters, the
+/// announcement history, passed waypoints, the observed pace, active alerts, fuel range and
+/// riding time.
+///
+/// Taken with `NavigationEngine::snapshot` and restored with
+/// `NavigationEngine::from_snapshot`, which gives identical behavior from that point on.
+/// Everything derived from the route itself (polyline, index, instructions, ETA baseline) is
+/// rebuilt instead of stored. The format is versioned; a snapshot of another version is
+/// rejected rather than misread.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    /// Format version, [`EngineSnapshot::VERSION`] when taken by this build.
+    pub version: u32,
+    /// Route the snapshot was taken on; it only restores onto the same route.
+    pub route_id: RouteId,
+    pub(crate) current_step: usize,
+    pub(crate) distance_traveled_m: f64,
+    pub(crate) traveled_offset_m: f64,
+    pub(crate) last_on_route_m: f64,
+    pub(crate) progress: Option<RouteProgress>,
+    pub(crate) matcher: MapMatcher,
+    pub(crate) waypoints_passed: usize,
+    pub(crate) announcer: AnnouncementPlanner,
+    pub(crate) filter: RouteFilter,
+    pub(crate) eta: EtaState,
+    pub(crate) off_route: OffRouteDetector,
+    pub(crate) wrong_way: WrongWayDetector,
+    pub(crate) constraint_alerts: Vec<ConstraintAlert>,
+    pub(crate) speed_limit_kmh: Option<u32>,
+    /// Absent from snapshots taken before fuel and break tracking; starts afresh then.
+    #[serde(default)]
+    pub(crate) stops: StopAdvisor,
+    pub(crate) rejoin: Option<RejoinGuidance>,
+}
+
+impl EngineSnapshot {
+    /// Current snapshot format version.
+    pub const VERSION: u32 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EngineConfig;
+    use crate::derive_instructions::bearing;
+    use crate::engine::NavigationEngine;
+    use crate::index::PolylineIndex;
+    use crate::types::{GpsFix, NavigationState};
+    use chrono::Utc;
+    use nav_ir::*;
+    use proptest::prelude::*;
+
+    const METERS_PER_DEG: f64 = 111_320.0;
+
+    /// Zigzag route from `legs` of (turn degrees, length meters), with a Via halfway and a
+    /// speed limit and toll span the constraints avoid.
+    fn zigzag_route(legs: &[(f64, f64)], behavior: OffRouteBehavior) -> Route {
+        let mut heading: f64 = 0.0;
+        let mut points = vec![(52.0, 5.0)];
+        for &(turn, length_m) in legs {
+            heading += turn;
+            let &(lat, lon) = points.last().unwrap();
+            let (s, c) = heading.to_radians().sin_cos();
+            points.push((
+                lat + c * length_m / METERS_PER_DEG,
+                lon + s * length_m / (METERS_PER_DEG * lat.to_radians().cos()),
+            ));
+        }
+        let coords: Vec<_> = points
+            .iter()
+            .map(|&(lat, lon)| geo_types::Coord { x: lon, y: lat })
+            .collect();
+        let waypoint = |i: usize, kind| Waypoint {
+            id: WaypointId::new(),
+            coordinate: Coordinate::new(points[i].0, points[i].1),
+            kind,
+            radius_m: None,
+            name: None,
+            description: None,
+            role: None,
+            category: None,
+            geometry_ref: None,
+        };
+        let last = points.len() - 1;
+        let now = Utc::now();
+        Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: "Snapshot".into(),
+                description: None,
+                created_at: now,
+                updated_at: now,
+                total_distance_m: None,
+                estimated_duration_s: None,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(polyline::encode_coordinates(coords, 5).unwrap()),
+                    source: GeometrySource::SnappedToGraph,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: -90.0,
+                        min_lon: -180.0,
+                        max_lat: 90.0,
+                        max_lon: 180.0,
+                    },
+                },
+                waypoints: vec![
+                    waypoint(0, WaypointKind::Start),
+                    waypoint(last / 2, WaypointKind::Via),
+                    waypoint(last, WaypointKind::Stop),
+                ],
+                legs: vec![],
+                instructions: vec![],
+                constraints: SegmentConstraints {
+                    avoid_tolls: true,
+                    max_speed_kmh: Some(50),
+                    ..SegmentConstraints::default()
+                },
+                street_names: vec![],
+                road_spans: vec![RoadSpan {
+                    vertex_range: VertexRange {
+                        start: 1,
+                        end: 2.min(last as u32),
+                    },
+                    attribute: RoadAttribute::Toll,
+                }],
+            }],
+            policies: RoutePolicies {
+                off_route_behavior: behavior,
+                ..RoutePolicies::default()
+            },
+        }
+    }
+
+    /// One input to the engine: a fix `advance_m` further along (negative: backwards),
+    /// `lateral_m` beside the route, or a signal gap when `gap` is set.
+    #[derive(Debug, Clone)]
+    struct Step {
+        advance_m: f64,
+        lateral_m: f64,
+        speed_mps: Option<f64>,
+        accuracy_m: Option<f64>,
+        timed: bool,
+        with_bearing: bool,
+        gap: bool,
+    }
+
+    fn step() -> impl Strategy<Value = Step> {
+        (
+            -20.0..40.0f64,
+            prop_oneof![4 => -8.0..8.0f64, 1 => -150.0..150.0f64],
+            proptest::option::of(0.0..35.0f64),
+            proptest::option::of(2.0..80.0f64),
+            proptest::bool::weighted(0.8),
+            any::<bool>(),
+            proptest::bool::weighted(0.1),
+        )
+            .prop_map(
+                |(advance_m, lateral_m, speed_mps, accuracy_m, timed, with_bearing, gap)| Step {
+                    advance_m,
+                    lateral_m,
+                    speed_mps,
+                    accuracy_m,
+                    timed,
+                    with_bearing,
+                    gap,
+                },
+            )
+    }
+
+    /// Feed `steps` to `engine`, starting at step number `first` and along distance `along_m`.
+    fn drive(
+        engine: &mut NavigationEngine,
+        index: &PolylineIndex,
+        vertices: &[Coordinate],
+        steps: &[Step],
+        first: usize,
+        along_m: &mut f64,
+    ) -> Vec<Option<NavigationState>> {
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let timestamp_ms = (first + i) as u64 * 1000;
+                *along_m = (*along_m + s.advance_m).clamp(0.0, index.total_m());
+                if s.gap {
+                    return engine.estimate_position(timestamp_ms);
+                }
+                let (seg, t) = index.progress_at_distance(*along_m);
+                let (a, b) = (vertices[seg], vertices[(seg + 1).min(vertices.len() - 1)]);
+                let course = bearing(a, b);
+                let (east, north) = (course + 90.0).to_radians().sin_cos();
+                let lat = a.latitude + (b.latitude - a.latitude) * t;
+                let lon = a.longitude + (b.longitude - a.longitude) * t;
+                let coordinate = Coordinate::new(
+                    lat + north * s.lateral_m / METERS_PER_DEG,
+                    lon + east * s.lateral_m / (METERS_PER_DEG * lat.to_radians().cos()),
+                );
+                let heading = if s.advance_m < 0.0 {
+                    course + 180.0
+                } else {
+                    course
+                };
+                Some(engine.update_fix(GpsFix {
+                    coordinate,
+                    accuracy_m: s.accuracy_m,
+                    bearing_deg: s.with_bearing.then_some(heading.rem_euclid(360.0)),
+                    speed_mps: s.speed_mps,
+                    timestamp_ms: s.timed.then_some(timestamp_ms),
+                }))
+            })
+            .collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn restored_engine_behaves_identically(
+            legs in prop::collection::vec((-120.0..120.0f64, 60.0..400.0f64), 2..8),
+            behavior in prop_oneof![
+                Just(OffRouteBehavior::Recalculate),
+                Just(OffRouteBehavior::SnapToRoute),
+                Just(OffRouteBehavior::AlertOnly),
+            ],
+            steps in prop::collection::vec(step(), 1..120),
+            split in 0.0..1.0f64,
+        ) {
+            let route = zigzag_route(&legs, behavior);
+            let config = EngineConfig::default();
+            let mut uninterrupted = NavigationEngine::new(route.clone(), config.clone());
+            let vertices = uninterrupted.polyline_vertices().to_vec();
+            let index = PolylineIndex::build(&vertices);
+            let split = (split * steps.len() as f64) as usize;
+
+            let mut along_m = 0.0;
+            let expected = drive(&mut uninterrupted, &index, &vertices, &steps, 0, &mut along_m);
+
+            let mut before = NavigationEngine::new(route.clone(), config.clone());
+            let mut along_m = 0.0;
+            drive(&mut before, &index, &vertices, &steps[..split], 0, &mut along_m);
+            let json = serde_json::to_string(&before.snapshot()).unwrap();
+            let snapshot: EngineSnapshot = serde_json::from_str(&json).unwrap();
+            let mut restored = NavigationEngine::from_snapshot(route, config, snapshot).unwrap();
+            let actual = drive(&mut restored, &index, &vertices, &steps[split..], split, &mut along_m);
+
+            prop_assert_eq!(&expected[split..], &actual[..]);
+            prop_assert_eq!(uninterrupted.snapshot(), restored.snapshot());
+        }
+    }
+
+    #[test]
+    fn snapshot_of_another_version_or_route_is_rejected() {
+        let route = zigzag_route(
+            &[(0.0, 200.0), (90.0, 200.0)],
+            OffRouteBehavior::Recalculate,
+        );
+        let engine = NavigationEngine::new(route.clone(), EngineConfig::default());
+        let mut snapshot = engine.snapshot();
+        snapshot.version += 1;
+        assert!(
+            NavigationEngine::from_snapshot(route.clone(), EngineConfig::default(), snapshot)
+                .is_err()
+        );
+        let other = zigzag_route(&[(0.0, 200.0)], OffRouteBehavior::Recalculate);
+        assert!(
+            NavigationEngine::from_snapshot(other, EngineConfig::default(), engine.snapshot())
+                .is_err()
+        );
+    }
+}
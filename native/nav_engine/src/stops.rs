@@ -0,0 +1,245 @@
+use nav_ir::WaypointKind;
+use serde::{Deserialize, Serialize};
+
+use crate::config::EngineConfig;
+
+/// Below this GPS speed the rider counts as stopped (m/s).
+const STOPPED_MPS: f64 = 1.0;
+
+/// Fuel range and riding time, for the fuel and break prompts.
+///
+/// Range: with a `vehicle_range_m` configured, the range left shrinks by the distance
+/// traveled and refills when a Fuel waypoint is reached. It is low while the next Fuel
+/// waypoint (or the destination, with none left) is further away than that.
+///
+/// Breaks: time moving since the last break adds up until `break_after_s`, when a break is
+/// due. Standing still for `break_min_stop_s`, or no fixes for that long, counts as a break.
+/// Reaching a Break waypoint alone does not: the rider may ride past it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StopAdvisor {
+    /// Range left (meters); `None` until the first update, then full.
+    range_left_m: Option<f64>,
+    /// `distance_traveled_m` at the last update.
+    last_traveled_m: Option<f64>,
+    /// Time moving since the last break (seconds).
+    riding_s: f64,
+    last_fix_ms: Option<u64>,
+    /// Timestamp of the first fix of the current stop.
+    stopped_since_ms: Option<u64>,
+    low_range: bool,
+    break_due: bool,
+    #[serde(skip)]
+    low_range_entered: bool,
+    #[serde(skip)]
+    break_entered: bool,
+}
+
+impl StopAdvisor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Range left right now, e.g. a tank that isn't full at the start (meters).
+    pub fn set_range_left(&mut self, range_m: f64) {
+        self.range_left_m = Some(range_m.max(0.0));
+    }
+
+    /// Account for one position. `traveled_m` is the session's `distance_traveled_m`,
+    /// `reached` the kinds of the waypoints reached with it and `fuel_target_m` the distance
+    /// to the next Fuel waypoint, else to the destination.
+    pub fn update(
+        &mut self,
+        traveled_m: f64,
+        timestamp_ms: Option<u64>,
+        speed_mps: Option<f64>,
+        reached: &[WaypointKind],
+        fuel_target_m: f64,
+        config: &EngineConfig,
+    ) {
+        let moved_m = self
+            .last_traveled_m
+            .map_or(0.0, |last| (traveled_m - last).max(0.0));
+        self.last_traveled_m = Some(traveled_m);
+
+        let was_low = self.low_range;
+        match config.vehicle_range_m {
+            Some(full_m) => {
+                let mut left_m = (self.range_left_m.unwrap_or(full_m) - moved_m).max(0.0);
+                if reached.contains(&WaypointKind::Fuel) {
+                    left_m = full_m;
+                }
+                self.range_left_m = Some(left_m);
+                self.low_range = left_m < fuel_target_m;
+            }
+            None => {
+                self.range_left_m = None;
+                self.low_range = false;
+            }
+        }
+        self.low_range_entered = self.low_range && !was_low;
+
+        let was_due = self.break_due;
+        if let Some(now_ms) = timestamp_ms {
+            let dt_s = self
+                .last_fix_ms
+                .map_or(0.0, |last| now_ms.saturating_sub(last) as f64 / 1000.0);
+            self.last_fix_ms = Some(now_ms);
+            let moving = speed_mps.map_or(moved_m > 0.0, |v| v >= STOPPED_MPS);
+            if dt_s >= config.break_min_stop_s {
+                // The app was closed or the phone lost power; assume a rest.
+                self.riding_s = 0.0;
+                self.stopped_since_ms = None;
+            } else if moving {
+                self.riding_s += dt_s;
+                self.stopped_since_ms = None;
+            } else {
+                let since_ms = *self.stopped_since_ms.get_or_insert(now_ms);
+                if now_ms.saturating_sub(since_ms) as f64 / 1000.0 >= config.break_min_stop_s {
+                    self.riding_s = 0.0;
+                }
+            }
+        }
+        self.break_due = config
+            .break_after_s
+            .is_some_and(|limit_s| self.riding_s >= limit_s);
+        self.break_entered = self.break_due && !was_due;
+    }
+
+    /// Range left (meters); `None` without a configured vehicle range.
+    pub fn range_left_m(&self) -> Option<f64> {
+        self.range_left_m
+    }
+
+    /// The next Fuel waypoint (or the destination) is out of range.
+    pub fn low_range(&self) -> bool {
+        self.low_range
+    }
+
+    /// `low_range` started with the last update.
+    pub fn low_range_entered(&self) -> bool {
+        self.low_range_entered
+    }
+
+    /// Time moving since the last break (seconds).
+    pub fn riding_s(&self) -> f64 {
+        self.riding_s
+    }
+
+    pub fn break_due(&self) -> bool {
+        self.break_due
+    }
+
+    /// `break_due` started with the last update.
+    pub fn break_entered(&self) -> bool {
+        self.break_entered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::VehicleProfile;
+
+    fn config() -> EngineConfig {
+        EngineConfig {
+            vehicle_range_m: Some(10_000.0),
+            break_after_s: Some(600.0),
+            break_min_stop_s: 120.0,
+            ..EngineConfig::for_profile(VehicleProfile::Motorcycle)
+        }
+    }
+
+    #[test]
+    fn range_shrinks_with_distance_and_refills_at_fuel() {
+        let config = config();
+        let mut stops = StopAdvisor::new();
+        stops.update(0.0, None, None, &[], 8_000.0, &config);
+        assert_eq!(stops.range_left_m(), Some(10_000.0));
+        assert!(!stops.low_range());
+
+        stops.update(3_000.0, None, None, &[], 5_000.0, &config);
+        assert_eq!(stops.range_left_m(), Some(7_000.0));
+        assert!(!stops.low_range());
+
+        // A detour ate into the range: the pump is now out of reach.
+        stops.update(6_000.0, None, None, &[], 4_500.0, &config);
+        assert!(stops.low_range() && stops.low_range_entered());
+        stops.update(6_500.0, None, None, &[], 4_000.0, &config);
+        assert!(stops.low_range() && !stops.low_range_entered());
+
+        stops.update(7_000.0, None, None, &[WaypointKind::Fuel], 9_000.0, &config);
+        assert_eq!(stops.range_left_m(), Some(10_000.0));
+        assert!(!stops.low_range());
+    }
+
+    #[test]
+    fn partial_tank_and_no_configured_range() {
+        let mut stops = StopAdvisor::new();
+        stops.set_range_left(2_000.0);
+        stops.update(0.0, None, None, &[], 3_000.0, &config());
+        assert_eq!(stops.range_left_m(), Some(2_000.0));
+        assert!(stops.low_range_entered());
+
+        let mut stops = StopAdvisor::new();
+        stops.update(0.0, None, None, &[], 1e9, &EngineConfig::default());
+        assert_eq!(stops.range_left_m(), None);
+        assert!(!stops.low_range());
+    }
+
+    #[test]
+    fn break_is_due_after_riding_time_and_reset_by_a_stop() {
+        let config = config();
+        let mut stops = StopAdvisor::new();
+        let mut due_at = None;
+        for t in 0..=70u64 {
+            stops.update(
+                t as f64 * 100.0,
+                Some(t * 10_000),
+                Some(10.0),
+                &[],
+                1e9,
+                &config,
+            );
+            if stops.break_entered() {
+                due_at = Some(t);
+            }
+        }
+        assert_eq!(due_at, Some(60));
+        assert!(stops.break_due());
+
+        // A short stop at the lights is not a break; two minutes parked is.
+        let mut t = 71;
+        for _ in 0..6 {
+            stops.update(7_000.0, Some(t * 10_000), Some(0.0), &[], 1e9, &config);
+            t += 1;
+        }
+        assert!(stops.break_due());
+        for _ in 0..7 {
+            stops.update(7_000.0, Some(t * 10_000), Some(0.0), &[], 1e9, &config);
+            t += 1;
+        }
+        assert!(!stops.break_due());
+        assert_eq!(stops.riding_s(), 0.0);
+
+        // So is a long gap between fixes.
+        stops.update(7_100.0, Some(t * 10_000), Some(10.0), &[], 1e9, &config);
+        stops.update(
+            7_200.0,
+            Some(t * 10_000 + 50_000),
+            Some(10.0),
+            &[],
+            1e9,
+            &config,
+        );
+        assert_eq!(stops.riding_s(), 60.0);
+        stops.update(
+            7_300.0,
+            Some(t * 10_000 + 500_000),
+            Some(10.0),
+            &[],
+            1e9,
+            &config,
+        );
+        assert_eq!(stops.riding_s(), 0.0);
+    }
+}
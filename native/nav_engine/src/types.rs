@@ -1,6 +1,7 @@
-use nav_ir::{Coordinate, OffRouteBehavior};
+use nav_ir::{Coordinate, OffRouteBehavior, SegmentIntent, WaypointKind};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DerivedInstructionKind {
     Depart,
     SharpLeft,
@@ -10,7 +11,11 @@ pub enum DerivedInstructionKind {
     SlightRight,
     TurnRight,
     SharpRight,
+    /// The route moves on to its next `nav_ir::RouteSegment`.
+    SegmentTransition,
     Arrive,
+    /// Turn around; only issued while riding the wrong way, never derived from geometry.
+    UTurn,
 }
 
 impl DerivedInstructionKind {
@@ -24,12 +29,38 @@ impl DerivedInstructionKind {
             DerivedInstructionKind::SlightRight => "slight_right",
             DerivedInstructionKind::TurnRight => "turn_right",
             DerivedInstructionKind::SharpRight => "sharp_right",
+            DerivedInstructionKind::SegmentTransition => "segment_transition",
             DerivedInstructionKind::Arrive => "arrive",
+            DerivedInstructionKind::UTurn => "u_turn",
         }
     }
 }
 
-#[derive(Debug, Clone)]
+impl std::str::FromStr for DerivedInstructionKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use DerivedInstructionKind::*;
+        [
+            Depart,
+            SharpLeft,
+            TurnLeft,
+            SlightLeft,
+            Continue,
+            SlightRight,
+            TurnRight,
+            SharpRight,
+            SegmentTransition,
+            Arrive,
+            UTurn,
+        ]
+        .into_iter()
+        .find(|k| k.as_str() == s)
+        .ok_or_else(|| format!("Unknown instruction kind: {}", s))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct DerivedInstruction {
     pub kind: DerivedInstructionKind,
     /// Index into the decoded polyline vertices where this instruction triggers.
@@ -39,21 +70,181 @@ pub struct DerivedInstruction {
     pub street_name: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// One GPS fix as reported by the platform location API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsFix {
+    pub coordinate: Coordinate,
+    /// Horizontal accuracy radius (meters).
+    pub accuracy_m: Option<f64>,
+    /// Course over ground (degrees from north); only trusted while moving.
+    pub bearing_deg: Option<f64>,
+    pub speed_mps: Option<f64>,
+    /// Fix time (Unix ms). Without it the fix is used as-is, with no smoothing.
+    pub timestamp_ms: Option<u64>,
+}
+
+impl GpsFix {
+    /// Bare coordinate with no accuracy, bearing, speed or time.
+    pub fn new(coordinate: Coordinate) -> Self {
+        Self {
+            coordinate,
+            accuracy_m: None,
+            bearing_deg: None,
+            speed_mps: None,
+            timestamp_ms: None,
+        }
+    }
+}
+
+/// Position along the decoded polyline: `fraction` of the way along segment
+/// `vertices[segment_index] → vertices[segment_index + 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct RouteProgress {
+    pub segment_index: usize,
+    pub fraction: f64,
+}
+
+/// Progress towards the next Via / Stop / Fuel / Break waypoint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaypointProgress {
+    /// Position in the route's waypoints flattened across segments.
+    pub index: usize,
+    pub kind: WaypointKind,
+    pub name: Option<String>,
+    /// Along-route distance to the waypoint (meters).
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+/// Likely range of an ETA (80% interval, seconds from now).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EtaInterval {
+    pub low_seconds: u64,
+    pub high_seconds: u64,
+}
+
+/// Time to the end of a route leg not yet finished.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegEta {
+    /// Position in the route's legs flattened across segments.
+    pub index: usize,
+    /// Along-route distance to the end of the leg (meters).
+    pub distance_m: f64,
+    pub eta_seconds: u64,
+}
+
+/// Prompt stage for an upcoming maneuver, from furthest to closest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AnnouncementStage {
+    /// Heads-up well before the maneuver ("In 1 km, turn left").
+    Early,
+    /// Get into position ("In 200 m, turn left").
+    Prepare,
+    /// Execute ("Turn left now").
+    Now,
+}
+
+impl AnnouncementStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AnnouncementStage::Early => "early",
+            AnnouncementStage::Prepare => "prepare",
+            AnnouncementStage::Now => "now",
+        }
+    }
+}
+
+/// A prompt to speak / vibrate on this fix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Announcement {
+    pub stage: AnnouncementStage,
+    /// Index of the announced instruction in `NavigationEngine::instructions()`.
+    pub step: usize,
+    pub kind: DerivedInstructionKind,
+    pub street_name: Option<String>,
+    /// Along-route distance to the maneuver when the prompt fired (meters).
+    pub distance_m: f64,
+    /// A maneuver following right after this one ("turn left, then immediately right").
+    pub then: Option<DerivedInstructionKind>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct OffRouteStatus {
+    /// Confirmed off-route: the threshold was exceeded for long enough (see
+    /// `OffRouteDetector`).
     pub is_off_route: bool,
+    /// This fix alone is further from the route than the threshold.
+    pub exceeds_threshold: bool,
+    /// Off-route was confirmed on this fix (rising edge of `is_off_route`).
+    pub entered: bool,
     pub distance_from_route_m: f64,
     pub behavior: OffRouteBehavior,
 }
 
-#[derive(Debug, Clone)]
+/// Way back onto a `FixedGeometry` track after leaving it (rejoin mode).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RejoinGuidance {
+    /// Closest point on the track ahead of where the rider left it.
+    pub point: Coordinate,
+    /// Along-route distance of `point` (meters).
+    pub along_m: f64,
+    /// Straight-line distance from the rider to `point` (meters).
+    pub distance_m: f64,
+    /// Compass bearing from the rider to `point` (degrees, 0 = north).
+    pub bearing_deg: f64,
+    /// Turn towards `point` relative to the rider's course; `None` without a course.
+    pub direction: Option<DerivedInstructionKind>,
+}
+
+/// Riding against the route direction (see `WrongWayDetector`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrongWayStatus {
+    /// "Make a U-turn", at the rider's position on the route.
+    pub instruction: DerivedInstruction,
+    /// Along-route distance lost since the rider started heading the wrong way (meters).
+    pub distance_m: f64,
+    /// Wrong-way was confirmed on this fix.
+    pub entered: bool,
+}
+
+/// Fuel range against the distance still to ride (see `StopAdvisor`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeStatus {
+    /// Distance the vehicle can still go (meters).
+    pub range_left_m: f64,
+    /// Next Fuel waypoint ahead, if any.
+    pub next_fuel: Option<WaypointProgress>,
+    /// The next Fuel waypoint, or the destination with none left, is out of range.
+    pub low: bool,
+    /// `low` started with this fix.
+    pub entered: bool,
+}
+
+/// A break is due after riding for a long time (see `StopAdvisor`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakSuggestion {
+    /// Time moving since the last break (seconds).
+    pub riding_s: u64,
+    /// Next Break waypoint ahead, if any.
+    pub next_break: Option<WaypointProgress>,
+    /// The break became due with this fix.
+    pub entered: bool,
+}
+
+/// A route constraint the rider is breaking right now; see `ConstraintMonitor`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConstraintAlert {
-    SpeedLimit { max_kmh: u32 },
+    /// Faster than the speed limit in force.
+    Speeding { max_kmh: u32 },
+    /// On a highway span of a segment that avoids highways.
     AvoidHighway,
+    /// On a toll span of a segment that avoids tolls.
     AvoidToll,
+    /// On an unpaved span of a segment that avoids unpaved roads.
+    AvoidUnpaved,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NavigationState {
     pub current_step: usize,
     pub current_instruction: DerivedInstruction,
@@ -63,8 +254,46 @@ pub struct NavigationState {
     /// Total remaining distance to destination.
     pub distance_remaining_m: f64,
     pub eta_seconds: u64,
+    /// Likely range of `eta_seconds`; narrows as the rider's pace becomes known.
+    pub eta_interval: EtaInterval,
     pub off_route: OffRouteStatus,
+    /// Constraint alerts in force at the current position.
     pub constraint_alerts: Vec<ConstraintAlert>,
+    /// Alerts raised by this update (entered a span, started speeding).
+    pub raised_alerts: Vec<ConstraintAlert>,
+    /// Alerts cleared by this update.
+    pub cleared_alerts: Vec<ConstraintAlert>,
+    /// Speed limit at the current position, if the route knows it.
+    pub speed_limit_kmh: Option<u32>,
     /// GPS position snapped onto the polyline.
     pub snapped_position: Coordinate,
+    /// Index into `route.segments` of the segment being ridden.
+    pub active_segment: usize,
+    /// Intent of the active segment (`None` for a route without segments).
+    pub segment_intent: Option<SegmentIntent>,
+    /// Next waypoint still ahead, if any.
+    pub next_waypoint: Option<WaypointProgress>,
+    /// Every waypoint still ahead, in route order (starts with `next_waypoint`).
+    pub upcoming_waypoints: Vec<WaypointProgress>,
+    /// Route legs not yet finished, in route order.
+    pub leg_etas: Vec<LegEta>,
+    /// Number of tracked waypoints already reached.
+    pub waypoints_passed: usize,
+    /// Waypoint indices reached by this fix (usually empty).
+    pub reached_waypoints: Vec<usize>,
+    /// All waypoints reached and the end of the route is within the destination radius.
+    pub arrived: bool,
+    /// Voice / haptic prompts triggered by this fix (usually empty).
+    pub announcements: Vec<Announcement>,
+    /// Position was dead-reckoned along the route (signal gap or low-accuracy fix).
+    pub estimated: bool,
+    /// Set while confirmed off a `FixedGeometry` segment (unless the route snaps).
+    pub rejoin: Option<RejoinGuidance>,
+    /// Set while confirmed riding against the route direction; also sets
+    /// `off_route.is_off_route`.
+    pub wrong_way: Option<WrongWayStatus>,
+    /// Fuel range; `None` unless `vehicle_range_m` is configured.
+    pub range: Option<RangeStatus>,
+    /// Set while a break is due.
+    pub break_suggestion: Option<BreakSuggestion>,
 }
@@ -0,0 +1,286 @@
+use nav_ir::{Coordinate, GeometryRefKind, Route, WaypointKind};
+
+use crate::derive_instructions::haversine_distance;
+use crate::index::PolylineIndex;
+
+/// Arrival radius used when a waypoint has no `radius_m`.
+pub fn default_radius_m(kind: WaypointKind) -> f64 {
+    match kind {
+        WaypointKind::Stop => 25.0,
+        WaypointKind::Via => 30.0,
+        WaypointKind::Fuel => 40.0,
+        // Parking areas and viewpoints are spread out.
+        WaypointKind::Break => 60.0,
+        WaypointKind::Start | WaypointKind::Shaping | WaypointKind::Poi => 0.0,
+    }
+}
+
+/// Waypoint kinds the rider actually arrives at (Start, Shaping and Poi are not tracked).
+fn is_tracked(kind: WaypointKind) -> bool {
+    matches!(
+        kind,
+        WaypointKind::Via | WaypointKind::Stop | WaypointKind::Fuel | WaypointKind::Break
+    )
+}
+
+/// Distance to the destination within which a route with no final `Stop` counts as arrived.
+pub(crate) fn destination_radius_m(tracker: &WaypointTracker, total_m: f64) -> f64 {
+    tracker
+        .waypoints
+        .last()
+        .filter(|wp| wp.kind == WaypointKind::Stop && wp.along_m + wp.radius_m >= total_m)
+        .map(|wp| wp.radius_m)
+        .unwrap_or_else(|| default_radius_m(WaypointKind::Stop))
+}
+
+/// A waypoint the engine watches for arrival.
+#[derive(Debug, Clone)]
+pub struct TrackedWaypoint {
+    /// Position in the route's waypoints flattened across segments (`WaypointReached::index`).
+    pub index: usize,
+    pub kind: WaypointKind,
+    pub name: Option<String>,
+    pub coordinate: Coordinate,
+    pub radius_m: f64,
+    /// Along-route distance of the waypoint on the chained polyline.
+    pub along_m: f64,
+}
+
+/// Detects arrival at tracked waypoints, in route order.
+///
+/// A waypoint counts as reached once the rider is within its radius *and* route progress has
+/// come within that radius of it (so a later waypoint next to an earlier pass doesn't fire),
+/// or once progress has moved past it.
+#[derive(Debug, Clone, Default)]
+pub struct WaypointTracker {
+    waypoints: Vec<TrackedWaypoint>,
+    passed: usize,
+}
+
+impl WaypointTracker {
+    /// Collect tracked waypoints from every segment, locating each on the chained polyline.
+    pub fn build(
+        route: &Route,
+        vertices: &[Coordinate],
+        segment_offsets: &[usize],
+        index: &PolylineIndex,
+    ) -> Self {
+        let mut waypoints = Vec::new();
+        let mut flat_index = 0;
+        for (seg_idx, seg) in route.segments.iter().enumerate() {
+            let offset = segment_offsets.get(seg_idx).copied().unwrap_or(0);
+            let end = segment_offsets
+                .get(seg_idx + 1)
+                .copied()
+                .unwrap_or(vertices.len().saturating_sub(1));
+            for wp in &seg.waypoints {
+                let index_here = flat_index;
+                flat_index += 1;
+                if !is_tracked(wp.kind) {
+                    continue;
+                }
+                let along_m = along_from_ref(wp, offset, index).unwrap_or_else(|| {
+                    index
+                        .nearest_segment(wp.coordinate, vertices, |i| {
+                            i >= offset && i < end.max(offset + 1)
+                        })
+                        .map(|(seg, _, t, _)| index.distance_along(seg, t))
+                        .unwrap_or(0.0)
+                });
+                waypoints.push(TrackedWaypoint {
+                    index: index_here,
+                    kind: wp.kind,
+                    name: wp.name.clone(),
+                    coordinate: wp.coordinate,
+                    radius_m: wp.radius_m.unwrap_or_else(|| default_radius_m(wp.kind)),
+                    along_m,
+                });
+            }
+        }
+        // Keep route order even if refs were authored out of order.
+        waypoints.sort_by(|a, b| a.along_m.total_cmp(&b.along_m));
+        Self {
+            waypoints,
+            passed: 0,
+        }
+    }
+
+    /// Mark every waypoint behind `along_m` as passed (session resume).
+    pub fn skip_to(&mut self, along_m: f64) {
+        while let Some(wp) = self.waypoints.get(self.passed) {
+            if wp.along_m + wp.radius_m >= along_m {
+                break;
+            }
+            self.passed += 1;
+        }
+    }
+
+    /// Mark exactly the first `passed` waypoints as reached (restore from a snapshot).
+    pub fn set_passed(&mut self, passed: usize) {
+        self.passed = passed.min(self.waypoints.len());
+    }
+
+    /// Check the fix against pending waypoints; returns the ones reached by this fix.
+    pub fn update(&mut self, pos: Coordinate, along_m: f64) -> Vec<TrackedWaypoint> {
+        let mut reached = Vec::new();
+        while let Some(wp) = self.waypoints.get(self.passed) {
+            let within = haversine_distance(pos, wp.coordinate) <= wp.radius_m
+                && along_m >= wp.along_m - wp.radius_m;
+            let gone_past = along_m > wp.along_m + wp.radius_m;
+            if !(within || gone_past) {
+                break;
+            }
+            reached.push(wp.clone());
+            self.passed += 1;
+        }
+        reached
+    }
+
+    /// Next waypoint not yet reached.
+    pub fn next(&self) -> Option<&TrackedWaypoint> {
+        self.waypoints.get(self.passed)
+    }
+
+    /// Number of tracked waypoints already reached or passed.
+    pub fn passed(&self) -> usize {
+        self.passed
+    }
+
+    /// All waypoints have been reached.
+    pub fn finished(&self) -> bool {
+        self.passed >= self.waypoints.len()
+    }
+
+    pub fn waypoints(&self) -> &[TrackedWaypoint] {
+        &self.waypoints
+    }
+}
+
+fn along_from_ref(wp: &nav_ir::Waypoint, offset: usize, index: &PolylineIndex) -> Option<f64> {
+    let gr = wp.geometry_ref.as_ref()?;
+    match gr.kind {
+        GeometryRefKind::VertexIndex => {
+            Some(index.distance_at_vertex(offset + gr.vertex_index? as usize))
+        }
+        GeometryRefKind::SegmentFraction => {
+            Some(index.distance_along(offset + gr.seg_start_index? as usize, gr.fraction? as f64))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use nav_ir::*;
+
+    fn c(lat: f64, lon: f64) -> Coordinate {
+        Coordinate::new(lat, lon)
+    }
+
+    fn waypoint(kind: WaypointKind, coordinate: Coordinate) -> Waypoint {
+        Waypoint {
+            id: WaypointId::new(),
+            coordinate,
+            kind,
+            radius_m: None,
+            name: None,
+            description: None,
+            role: None,
+            category: None,
+            geometry_ref: None,
+        }
+    }
+
+    /// Out-and-back along lon 0 with a Via on the way back, next to the outbound pass.
+    fn out_and_back() -> (Route, Vec<Coordinate>) {
+        let vertices = vec![c(0.0, 0.0), c(0.01, 0.0), c(0.0, 0.0001)];
+        let route = Route {
+            schema_version: Route::CURRENT_SCHEMA_VERSION,
+            id: RouteId::new(),
+            metadata: RouteMetadata {
+                name: "Test".into(),
+                description: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                total_distance_m: None,
+                estimated_duration_s: None,
+                tags: vec![],
+                source: None,
+            },
+            segments: vec![RouteSegment {
+                id: SegmentId::new(),
+                intent: SegmentIntent::Recalculatable,
+                geometry: RouteGeometry {
+                    polyline: EncodedPolyline(String::new()),
+                    source: GeometrySource::Synthetic,
+                    confidence: GeometryConfidence::High,
+                    bounding_box: BoundingBox {
+                        min_lat: 0.0,
+                        min_lon: 0.0,
+                        max_lat: 0.01,
+                        max_lon: 0.0001,
+                    },
+                },
+                waypoints: vec![
+                    waypoint(WaypointKind::Start, c(0.0, 0.0)),
+                    waypoint(WaypointKind::Via, c(0.005, 0.0001)),
+                    waypoint(WaypointKind::Stop, c(0.0, 0.0001)),
+                ],
+                legs: vec![],
+                instructions: vec![],
+                constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
+            }],
+            policies: RoutePolicies::default(),
+        };
+        (route, vertices)
+    }
+
+    #[test]
+    fn waypoints_located_on_the_right_pass() {
+        let (route, vertices) = out_and_back();
+        let index = PolylineIndex::build(&vertices);
+        let tracker = WaypointTracker::build(&route, &vertices, &[0], &index);
+        let wps = tracker.waypoints();
+        assert_eq!(wps.len(), 2, "Start is not tracked");
+        assert_eq!((wps[0].index, wps[0].kind), (1, WaypointKind::Via));
+        assert!((wps[0].along_m - 1668.0).abs() < 5.0, "{}", wps[0].along_m);
+        assert_eq!(wps[0].radius_m, default_radius_m(WaypointKind::Via));
+    }
+
+    #[test]
+    fn nearby_earlier_pass_does_not_trigger_arrival() {
+        let (route, vertices) = out_and_back();
+        let index = PolylineIndex::build(&vertices);
+        let mut tracker = WaypointTracker::build(&route, &vertices, &[0], &index);
+        // Outbound, ~11 m from the Via but ~1.1 km before it along the route.
+        assert!(tracker.update(c(0.005, 0.0), 556.0).is_empty());
+        // On the way back.
+        let reached = tracker.update(c(0.005, 0.0001), 1668.0);
+        assert_eq!(reached.len(), 1);
+        assert_eq!(reached[0].index, 1);
+        assert_eq!(tracker.passed(), 1);
+        assert!(!tracker.finished());
+    }
+
+    #[test]
+    fn progress_past_a_waypoint_counts_as_passed() {
+        let (route, vertices) = out_and_back();
+        let index = PolylineIndex::build(&vertices);
+        let mut tracker = WaypointTracker::build(&route, &vertices, &[0], &index);
+        let reached = tracker.update(c(0.002, 0.0001), 2000.0);
+        assert_eq!(reached.len(), 1);
+        assert_eq!(tracker.next().map(|w| w.kind), Some(WaypointKind::Stop));
+    }
+
+    #[test]
+    fn skip_to_marks_earlier_waypoints_passed() {
+        let (route, vertices) = out_and_back();
+        let index = PolylineIndex::build(&vertices);
+        let mut tracker = WaypointTracker::build(&route, &vertices, &[0], &index);
+        tracker.skip_to(1800.0);
+        assert_eq!(tracker.passed(), 1);
+    }
+}
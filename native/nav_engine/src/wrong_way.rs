@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::EngineConfig;
+use crate::derive_instructions::normalize_delta;
+
+/// A fix must lose at least this much along-route distance to count as moving backwards
+/// (meters); absorbs projection jitter while stopped.
+const MIN_BACKTRACK_STEP_M: f64 = 0.5;
+/// A confirmed wrong-way clears once the heading is back within this of the route direction
+/// and the rider makes progress again (degrees).
+const FORWARD_DEGREES: f64 = 90.0;
+
+/// Wrong-way detection: the rider follows the route backwards.
+///
+/// A fix counts as against the route when its heading differs from the matched segment's
+/// bearing by at least `wrong_way_heading_degrees` *and* its along-route distance went down.
+/// Wrong-way is confirmed after `wrong_way_confirm_fixes` such fixes in a row that together
+/// lost `wrong_way_min_distance_m`, and clears once the rider heads along the route and makes
+/// progress again. Fixes without a heading (standing still) leave the state unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WrongWayDetector {
+    against_fixes: u32,
+    backtracked_m: f64,
+    last_along_m: Option<f64>,
+    confirmed: bool,
+    /// The last update confirmed wrong-way.
+    entered: bool,
+}
+
+impl WrongWayDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed one on-route fix; returns whether the rider is (still) confirmed wrong-way.
+    pub fn update(
+        &mut self,
+        heading_deg: Option<f64>,
+        segment_bearing_deg: f64,
+        along_m: f64,
+        config: &EngineConfig,
+    ) -> bool {
+        self.entered = false;
+        let Some(heading) = heading_deg else {
+            return self.confirmed;
+        };
+        let delta = normalize_delta(heading - segment_bearing_deg).abs();
+        let step_m = self.last_along_m.map_or(0.0, |last| along_m - last);
+        self.last_along_m = Some(along_m);
+
+        if self.confirmed {
+            if delta < FORWARD_DEGREES && step_m > 0.0 {
+                self.reset_keeping(along_m);
+            } else if step_m < 0.0 {
+                self.backtracked_m -= step_m;
+            }
+            return self.confirmed;
+        }
+        if delta < config.wrong_way_heading_degrees || step_m > -MIN_BACKTRACK_STEP_M {
+            self.reset_keeping(along_m);
+            return false;
+        }
+        self.against_fixes += 1;
+        self.backtracked_m -= step_m;
+        self.confirmed = self.against_fixes >= config.wrong_way_confirm_fixes
+            && self.backtracked_m >= config.wrong_way_min_distance_m;
+        self.entered = self.confirmed;
+        self.confirmed
+    }
+
+    /// Forget everything, e.g. while off the route where the segment bearing means nothing.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmed
+    }
+
+    /// Whether the last `update` was the one that confirmed wrong-way.
+    pub fn entered(&self) -> bool {
+        self.entered
+    }
+
+    /// Along-route distance lost while heading against the route (meters).
+    pub fn backtracked_m(&self) -> f64 {
+        self.backtracked_m
+    }
+
+    fn reset_keeping(&mut self, along_m: f64) {
+        *self = Self {
+            last_along_m: Some(along_m),
+            ..Self::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixes heading `heading` at along-route distances `along`, on a north-going segment.
+    fn feed(d: &mut WrongWayDetector, heading: f64, along: &[f64]) -> Vec<bool> {
+        let config = EngineConfig::default();
+        along
+            .iter()
+            .map(|&a| d.update(Some(heading), 0.0, a, &config))
+            .collect()
+    }
+
+    #[test]
+    fn confirms_sustained_backwards_movement() {
+        let mut d = WrongWayDetector::new();
+        assert_eq!(
+            feed(&mut d, 180.0, &[500.0, 490.0, 480.0, 470.0]),
+            [false, false, false, true]
+        );
+        assert!(d.entered());
+        assert!(d.backtracked_m() >= 30.0);
+        assert_eq!(feed(&mut d, 180.0, &[460.0]), [true]);
+        assert!(!d.entered(), "entered fires once");
+    }
+
+    #[test]
+    fn needs_both_heading_and_backwards_progress() {
+        let mut d = WrongWayDetector::new();
+        // Heading south but progress still increases: a matching glitch, not wrong-way.
+        assert!(!feed(&mut d, 180.0, &[500.0, 510.0, 520.0, 530.0, 540.0]).contains(&true));
+        // Moving backwards with a forward heading: projection jitter.
+        assert!(!feed(&mut d, 10.0, &[530.0, 520.0, 510.0, 500.0, 490.0]).contains(&true));
+    }
+
+    #[test]
+    fn short_creep_backwards_is_not_confirmed() {
+        let mut d = WrongWayDetector::new();
+        assert!(!feed(&mut d, 180.0, &[500.0, 498.0, 496.0, 494.0, 492.0]).contains(&true));
+    }
+
+    #[test]
+    fn stopping_keeps_state_and_u_turn_clears_it() {
+        let config = EngineConfig::default();
+        let mut d = WrongWayDetector::new();
+        feed(&mut d, 180.0, &[500.0, 485.0, 470.0, 455.0]);
+        assert!(d.is_confirmed());
+        assert!(d.update(None, 0.0, 455.0, &config));
+        assert!(
+            feed(&mut d, 180.0, &[455.0])[0],
+            "turning around, no progress yet"
+        );
+        assert!(!feed(&mut d, 5.0, &[465.0])[0]);
+    }
+}
@@ -93,6 +93,8 @@ pub fn normalize_custom(
             legs: vec![],
             instructions: vec![],
             constraints: SegmentConstraints::default(),
+            street_names: vec![],
+            road_spans: vec![],
         }],
         policies: RoutePolicies::default(),
     };
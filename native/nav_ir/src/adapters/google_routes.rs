@@ -164,6 +164,8 @@ pub fn normalize_google_routes(json: &str, waypoints: &[(f64, f64)]) -> Result<R
             legs: vec![],
             instructions: vec![],
             constraints: SegmentConstraints::default(),
+            street_names: vec![],
+            road_spans: vec![],
         }],
         policies: RoutePolicies::default(),
     };
@@ -3,15 +3,51 @@
 //! Normalizes GPX track/route into a single Nav-IR Route (one segment, FixedGeometry, ImportedExact).
 //! Computes total distance from track/route points; optionally estimates duration from distance.
 
+use crate::digest::{digest_track, DigestOptions, TrackPoint};
 use crate::{
     BoundingBox, Coordinate, EncodedPolyline, GeometryConfidence, GeometrySource, ImportSource,
     Route, RouteGeometry, RouteMetadata, RoutePolicies, RouteSegment, SegmentConstraints,
     SegmentId, SegmentIntent, Waypoint, WaypointId, WaypointKind,
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use geo_types::Coord;
 use std::io::BufReader;
 
+/// Points of the first track (all segments), or of the first route when there is no track.
+///
+/// Used to replay recorded rides as well as to import them: `<time>` is kept when present.
+pub fn gpx_track_points(bytes: &[u8]) -> Result<Vec<TrackPoint>, String> {
+    let reader = BufReader::new(bytes);
+    let gpx = gpx::read(reader).map_err(|e| format!("GPX parse error: {}", e))?;
+    track_points(&gpx)
+}
+
+fn track_points(gpx: &gpx::Gpx) -> Result<Vec<TrackPoint>, String> {
+    let to_track_point = |wpt: &gpx::Waypoint| {
+        let c = wpt.point();
+        TrackPoint {
+            coordinate: Coordinate::new(c.y(), c.x()),
+            time: wpt
+                .time
+                .and_then(|t| t.format().ok())
+                .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                .map(|t| t.with_timezone(&Utc)),
+        }
+    };
+    if let Some(track) = gpx.tracks.first() {
+        Ok(track
+            .segments
+            .iter()
+            .flat_map(|seg| seg.points.iter())
+            .map(to_track_point)
+            .collect())
+    } else if let Some(rte) = gpx.routes.first() {
+        Ok(rte.points.iter().map(to_track_point).collect())
+    } else {
+        Err("GPX has no tracks or routes".to_string())
+    }
+}
+
 /// Approximate haversine distance in meters between (lat1, lon1) and (lat2, lon2).
 fn haversine_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
     const R: f64 = 6_371_000.0; // Earth radius in meters
@@ -38,14 +74,67 @@ fn total_distance_m(points: &[(f64, f64)]) -> f64 {
         .sum()
 }
 
+type Extras = std::collections::HashMap<String, serde_json::Value>;
+
+/// Route name, description and `source.extras` shared by all GPX imports.
+fn describe(gpx: &gpx::Gpx) -> (String, Option<String>, Extras) {
+    let name = gpx
+        .tracks
+        .first()
+        .and_then(|t| t.name.as_deref())
+        .or_else(|| gpx.routes.first().and_then(|r| r.name.as_deref()))
+        .unwrap_or("Imported from GPX")
+        .to_string();
+
+    let description = gpx
+        .metadata
+        .as_ref()
+        .and_then(|m| m.description.as_deref())
+        .or_else(|| gpx.tracks.first().and_then(|t| t.description.as_deref()))
+        .or_else(|| gpx.routes.first().and_then(|r| r.description.as_deref()))
+        .map(String::from);
+
+    let mut extras = std::collections::HashMap::new();
+    if let Some(route_type) = gpx
+        .routes
+        .first()
+        .and_then(|r| r.type_.as_deref())
+        .or_else(|| gpx.tracks.first().and_then(|t| t.type_.as_deref()))
+    {
+        extras.insert(
+            "type".to_string(),
+            serde_json::Value::String(route_type.to_string()),
+        );
+    }
+    if let Some(comment) = gpx
+        .routes
+        .first()
+        .and_then(|r| r.comment.as_deref())
+        .or_else(|| gpx.tracks.first().and_then(|t| t.comment.as_deref()))
+    {
+        extras.insert(
+            "comment".to_string(),
+            serde_json::Value::String(comment.to_string()),
+        );
+    }
+
+    (name, description, extras)
+}
+
 /// Normalize GPX bytes (track or route) into a Nav-IR Route.
 ///
 /// Uses the first track; all segments are flattened into one polyline. First point → Start waypoint,
 /// last point → Stop waypoint. Intent is FixedGeometry, source ImportedExact.
 pub fn normalize_gpx(bytes: &[u8]) -> Result<Route, String> {
+    normalize(&read_gpx(bytes)?)
+}
+
+fn read_gpx(bytes: &[u8]) -> Result<gpx::Gpx, String> {
     let reader = BufReader::new(bytes);
-    let gpx = gpx::read(reader).map_err(|e| format!("GPX parse error: {}", e))?;
+    gpx::read(reader).map_err(|e| format!("GPX parse error: {}", e))
+}
 
+fn normalize(gpx: &gpx::Gpx) -> Result<Route, String> {
     let points: Vec<(f64, f64)> = if !gpx.tracks.is_empty() {
         gpx.tracks[0]
             .segments
@@ -100,45 +189,7 @@ pub fn normalize_gpx(bytes: &[u8]) -> Result<Route, String> {
         },
     );
 
-    let name = gpx
-        .tracks
-        .first()
-        .and_then(|t| t.name.as_deref())
-        .or_else(|| gpx.routes.first().and_then(|r| r.name.as_deref()))
-        .unwrap_or("Imported from GPX")
-        .to_string();
-
-    let description = gpx
-        .metadata
-        .as_ref()
-        .and_then(|m| m.description.as_deref())
-        .or_else(|| gpx.tracks.first().and_then(|t| t.description.as_deref()))
-        .or_else(|| gpx.routes.first().and_then(|r| r.description.as_deref()))
-        .map(String::from);
-
-    let mut extras = std::collections::HashMap::new();
-    if let Some(route_type) = gpx
-        .routes
-        .first()
-        .and_then(|r| r.type_.as_deref())
-        .or_else(|| gpx.tracks.first().and_then(|t| t.type_.as_deref()))
-    {
-        extras.insert(
-            "type".to_string(),
-            serde_json::Value::String(route_type.to_string()),
-        );
-    }
-    if let Some(comment) = gpx
-        .routes
-        .first()
-        .and_then(|r| r.comment.as_deref())
-        .or_else(|| gpx.tracks.first().and_then(|t| t.comment.as_deref()))
-    {
-        extras.insert(
-            "comment".to_string(),
-            serde_json::Value::String(comment.to_string()),
-        );
-    }
+    let (name, description, extras) = describe(gpx);
 
     let waypoints: Vec<Waypoint> = if !gpx.routes.is_empty() && gpx.routes[0].points.len() >= 2 {
         let rte = &gpx.routes[0];
@@ -233,9 +284,55 @@ pub fn normalize_gpx(bytes: &[u8]) -> Result<Route, String> {
             legs: vec![],
             instructions: vec![],
             constraints: SegmentConstraints::default(),
+            street_names: vec![],
+            road_spans: vec![],
         }],
         policies: RoutePolicies::default(),
     };
     route.validate().map_err(|e| e.to_string())?;
     Ok(route)
 }
+
+/// Digest a recorded GPX track into a Nav-IR Route with synthesized turns and `Break` stops.
+///
+/// Unlike [`normalize_gpx`], the geometry is cleaned and instructions are derived offline (see
+/// [`crate::digest`]). Track point `<time>` values drive dwell detection and leg durations; a
+/// GPX with only `<rte>` points is digested without timestamps.
+pub fn digest_gpx(bytes: &[u8], options: &DigestOptions) -> Result<Route, String> {
+    digest(&read_gpx(bytes)?, options)
+}
+
+fn digest(gpx: &gpx::Gpx, options: &DigestOptions) -> Result<Route, String> {
+    let points = track_points(gpx)?;
+
+    let mut route = digest_track(&points, options)?;
+    let (name, description, extras) = describe(gpx);
+    let now = route.metadata.created_at;
+    route.metadata.name = name.clone();
+    route.metadata.description = description;
+    route.metadata.tags = vec!["gpx".to_string(), "digested".to_string()];
+    route.metadata.source = Some(ImportSource {
+        format: "gpx".to_string(),
+        creator: gpx.creator.clone(),
+        imported_at: now,
+        original_name: Some(name),
+        extras,
+    });
+    Ok(route)
+}
+
+/// GPX import: a recorded `<trk>` is digested ([`digest_gpx`] with default options) so that it
+/// gets real turns and `Break` stops; a file with only a planned `<rte>` is normalized as it is
+/// ([`normalize_gpx`]).
+pub fn import_gpx(bytes: &[u8]) -> Result<Route, String> {
+    let gpx = read_gpx(bytes)?;
+    let recorded = gpx
+        .tracks
+        .first()
+        .is_some_and(|t| t.segments.iter().any(|s| !s.points.is_empty()));
+    if recorded {
+        digest(&gpx, &DigestOptions::default())
+    } else {
+        normalize(&gpx)
+    }
+}
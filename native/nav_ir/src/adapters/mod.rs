@@ -3,6 +3,8 @@
 //! nav_ir becomes the bridge between external ecosystems (OSRM, GPX, custom APIs)
 //! and the rest of the system; Flutter and device_comm stop caring where the route came from.
 
+use crate::{StreetSpan, VertexRange};
+
 mod custom_api;
 mod google_routes;
 mod gpx;
@@ -12,7 +14,29 @@ mod valhalla;
 
 pub use custom_api::normalize_custom;
 pub use google_routes::normalize_google_routes;
-pub use gpx::normalize_gpx;
+pub use gpx::{digest_gpx, gpx_track_points, import_gpx, normalize_gpx};
 pub use graphhopper::normalize_graphhopper;
 pub use osrm::{normalize_osrm, OsrmResponse};
 pub use valhalla::normalize_valhalla;
+
+/// Street spans from `(first_vertex, last_vertex, name)` stretches in route order. Unnamed
+/// stretches are skipped and consecutive stretches on the same street are merged.
+fn street_spans<'a>(stretches: impl IntoIterator<Item = (u32, u32, &'a str)>) -> Vec<StreetSpan> {
+    let mut spans: Vec<StreetSpan> = Vec::new();
+    for (start, end, name) in stretches {
+        let name = name.trim();
+        if name.is_empty() || end <= start {
+            continue;
+        }
+        match spans.last_mut() {
+            Some(last) if last.name == name && last.vertex_range.end == start => {
+                last.vertex_range.end = end;
+            }
+            _ => spans.push(StreetSpan {
+                vertex_range: VertexRange { start, end },
+                name: name.to_string(),
+            }),
+        }
+    }
+    spans
+}
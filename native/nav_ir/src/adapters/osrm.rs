@@ -5,11 +5,14 @@
 use crate::{
     BoundingBox, Coordinate, EncodedPolyline, GeometryConfidence, GeometrySource, Route,
     RouteGeometry, RouteMetadata, RoutePolicies, RouteSegment, SegmentConstraints, SegmentId,
-    SegmentIntent, Waypoint, WaypointId, WaypointKind,
+    SegmentIntent, StreetSpan, Waypoint, WaypointId, WaypointKind,
 };
 use chrono::Utc;
+use geo_types::Coord;
 use serde::Deserialize;
 
+use super::street_spans;
+
 /// Minimal OSRM route/v1/driving response shape (waypoints + first route).
 #[derive(Debug, Deserialize)]
 pub struct OsrmResponse {
@@ -31,6 +34,61 @@ pub struct OsrmRoute {
     pub distance: f64,
     pub duration: f64,
     pub geometry: String,
+    /// Present when requested with `steps=true`.
+    #[serde(default)]
+    pub legs: Vec<OsrmLeg>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OsrmLeg {
+    #[serde(default)]
+    pub steps: Vec<OsrmStep>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OsrmStep {
+    /// Street name; empty for unnamed roads.
+    #[serde(default)]
+    pub name: String,
+    /// The step's own stretch of the overview geometry (polyline5).
+    pub geometry: String,
+}
+
+/// Quantized coordinate, so that step and overview vertices compare exactly.
+fn vertex_key(c: &Coord<f64>) -> (i64, i64) {
+    ((c.y * 1e5).round() as i64, (c.x * 1e5).round() as i64)
+}
+
+/// Street spans for the overview `vertices`, found by walking each step's geometry along them.
+///
+/// Consecutive steps share their boundary vertex, and arrive/depart steps collapse to a single
+/// vertex. If a step doesn't line up with the overview, no spans are returned rather than
+/// misplaced ones.
+fn osrm_street_spans(vertices: &[Coord<f64>], legs: &[OsrmLeg]) -> Vec<StreetSpan> {
+    let keys: Vec<(i64, i64)> = vertices.iter().map(vertex_key).collect();
+    let mut stretches = Vec::new();
+    let mut cursor = 0usize;
+    for step in legs.iter().flat_map(|leg| &leg.steps) {
+        let Ok(line) = polyline::decode_polyline(&step.geometry, 5) else {
+            return vec![];
+        };
+        let mut points = line.0.iter().map(vertex_key);
+        if points.next() != keys.get(cursor).copied() {
+            return vec![];
+        }
+        let start = cursor;
+        for point in points {
+            if keys[cursor] == point {
+                continue;
+            }
+            cursor += 1;
+            if keys.get(cursor) != Some(&point) {
+                return vec![];
+            }
+        }
+        stretches.push((start as u32, cursor as u32, step.name.as_str()));
+    }
+    street_spans(stretches)
 }
 
 impl TryFrom<OsrmResponse> for Route {
@@ -116,6 +174,8 @@ impl TryFrom<OsrmResponse> for Route {
         if waypoints.len() < 2 {
             return Err("Need at least two waypoints (Start and Stop)".to_string());
         }
+        let vertices: Vec<Coord<f64>> = decoded.coords().copied().collect();
+        let street_names = osrm_street_spans(&vertices, &route_data.legs);
         let now = Utc::now();
         let route = Route {
             schema_version: Route::CURRENT_SCHEMA_VERSION,
@@ -148,6 +208,8 @@ impl TryFrom<OsrmResponse> for Route {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names,
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -158,10 +220,77 @@ impl TryFrom<OsrmResponse> for Route {
 
 /// Normalize OSRM route JSON into a Nav-IR Route.
 ///
-/// Expects the response from OSRM `route/v1/driving/{coords}?overview=full&geometries=polyline`.
+/// Expects the response from OSRM
+/// `route/v1/driving/{coords}?overview=full&geometries=polyline&steps=true`; street names are
+/// taken from the steps, and left empty without them.
 /// Waypoints are taken from `response.waypoints` if present, otherwise from geometry endpoints.
 pub fn normalize_osrm(json: &str) -> Result<Route, String> {
     let response: OsrmResponse =
         serde_json::from_str(json).map_err(|e| format!("Invalid OSRM JSON: {}", e))?;
     response.try_into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(coords: &[(f64, f64)]) -> String {
+        // coords are (lat, lon)
+        let geo_coords: Vec<Coord<f64>> = coords
+            .iter()
+            .map(|(lat, lon)| Coord { x: *lon, y: *lat })
+            .collect();
+        polyline::encode_coordinates(geo_coords, 5).unwrap()
+    }
+
+    fn response(steps: &[(&str, &[(f64, f64)])]) -> String {
+        let overview = encode(&[(52.5, 13.4), (52.51, 13.41), (52.52, 13.40), (52.53, 13.42)]);
+        let steps: Vec<serde_json::Value> = steps
+            .iter()
+            .map(|(name, coords)| serde_json::json!({"name": name, "geometry": encode(coords)}))
+            .collect();
+        serde_json::json!({
+            "routes": [{
+                "distance": 3500.0,
+                "duration": 420.0,
+                "geometry": overview,
+                "legs": [{"steps": steps}]
+            }]
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn normalize_osrm_names_streets_from_steps() {
+        let json = response(&[
+            ("Invalidenstraße", &[(52.5, 13.4), (52.51, 13.41)]),
+            ("Invalidenstraße", &[(52.51, 13.41), (52.52, 13.40)]),
+            ("", &[(52.52, 13.40), (52.53, 13.42)]),
+            ("Torstraße", &[(52.53, 13.42), (52.53, 13.42)]),
+        ]);
+
+        let route = normalize_osrm(&json).unwrap();
+        let names: Vec<(u32, u32, &str)> = route.segments[0]
+            .street_names
+            .iter()
+            .map(|s| (s.vertex_range.start, s.vertex_range.end, s.name.as_str()))
+            .collect();
+        assert_eq!(names, [(0, 2, "Invalidenstraße")]);
+    }
+
+    #[test]
+    fn normalize_osrm_drops_street_names_that_do_not_line_up() {
+        let json = response(&[
+            ("Invalidenstraße", &[(52.5, 13.4), (52.52, 13.40)]),
+            ("Torstraße", &[(52.52, 13.40), (52.53, 13.42)]),
+        ]);
+
+        let route = normalize_osrm(&json).unwrap();
+        assert!(route.segments[0].street_names.is_empty());
+
+        let without_steps = response(&[]);
+        assert!(normalize_osrm(&without_steps).unwrap().segments[0]
+            .street_names
+            .is_empty());
+    }
+}
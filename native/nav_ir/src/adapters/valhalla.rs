@@ -5,6 +5,8 @@
 //! Critical precision note: Valhalla encodes `trip.legs[].shape` at **precision 6** (polyline6),
 //! while Nav-IR uses precision 5 everywhere. This adapter decodes at 6 and re-encodes at 5.
 //! Distance is in km in the summary — multiply by 1000 to get meters.
+//! Street names come from the first leg's maneuvers, whose shape indices point into that leg's
+//! shape.
 
 use crate::{
     BoundingBox, Coordinate, EncodedPolyline, GeometryConfidence, GeometrySource, Route,
@@ -15,6 +17,8 @@ use chrono::Utc;
 use geo_types::Coord;
 use serde::Deserialize;
 
+use super::street_spans;
+
 #[derive(Debug, Deserialize)]
 struct ValhallaResponse {
     trip: ValhallaTrip,
@@ -31,6 +35,16 @@ struct ValhallaTrip {
 #[derive(Debug, Deserialize)]
 struct ValhallaLeg {
     shape: String,
+    #[serde(default)]
+    maneuvers: Vec<ValhallaManeuver>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ValhallaManeuver {
+    #[serde(default)]
+    street_names: Vec<String>,
+    begin_shape_index: u32,
+    end_shape_index: u32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,12 +71,11 @@ pub fn normalize_valhalla(json: &str) -> Result<Route, String> {
         serde_json::from_str(json).map_err(|e| format!("Invalid Valhalla JSON: {}", e))?;
     let trip = &response.trip;
 
-    let shape = trip
+    let leg = trip
         .legs
         .first()
-        .ok_or_else(|| "Valhalla response has no legs".to_string())?
-        .shape
-        .as_str();
+        .ok_or_else(|| "Valhalla response has no legs".to_string())?;
+    let shape = leg.shape.as_str();
 
     // Valhalla uses polyline6; Nav-IR uses precision 5.
     let decoded = polyline::decode_polyline(shape, 6)
@@ -147,6 +160,15 @@ pub fn normalize_valhalla(json: &str) -> Result<Route, String> {
         return Err("Need at least two waypoints (Start and Stop)".to_string());
     }
 
+    let last_vertex = (coords.len() - 1) as u32;
+    let street_names = street_spans(leg.maneuvers.iter().map(|m| {
+        (
+            m.begin_shape_index.min(last_vertex),
+            m.end_shape_index.min(last_vertex),
+            m.street_names.first().map_or("", String::as_str),
+        )
+    }));
+
     let now = Utc::now();
     let route = Route {
         schema_version: Route::CURRENT_SCHEMA_VERSION,
@@ -179,6 +201,8 @@ pub fn normalize_valhalla(json: &str) -> Result<Route, String> {
             legs: vec![],
             instructions: vec![],
             constraints: SegmentConstraints::default(),
+            street_names,
+            road_spans: vec![],
         }],
         policies: RoutePolicies::default(),
     };
@@ -278,6 +302,42 @@ mod tests {
         assert_eq!(seg.waypoints[1].kind, WaypointKind::Stop);
     }
 
+    #[test]
+    fn normalize_valhalla_names_streets_from_maneuvers() {
+        // JSON-quoted: this shape's encoding contains a backslash.
+        let shape = serde_json::to_string(&make_polyline6(&[
+            (40.7128, -74.0060),
+            (40.7200, -74.0000),
+            (40.7350, -73.9950),
+            (40.7580, -73.9855),
+        ]))
+        .unwrap();
+        let json = format!(
+            r#"{{
+                "trip": {{
+                    "legs": [{{
+                        "shape": {shape},
+                        "maneuvers": [
+                            {{"street_names": ["Broadway"], "begin_shape_index": 0, "end_shape_index": 1}},
+                            {{"street_names": ["Broadway"], "begin_shape_index": 1, "end_shape_index": 2}},
+                            {{"street_names": ["5th Avenue", "US 9"], "begin_shape_index": 2, "end_shape_index": 3}},
+                            {{"begin_shape_index": 3, "end_shape_index": 3}}
+                        ]
+                    }}],
+                    "summary": {{"length": 6.0, "time": 700.0}}
+                }}
+            }}"#
+        );
+
+        let route = normalize_valhalla(&json).unwrap();
+        let names: Vec<(u32, u32, &str)> = route.segments[0]
+            .street_names
+            .iter()
+            .map(|s| (s.vertex_range.start, s.vertex_range.end, s.name.as_str()))
+            .collect();
+        assert_eq!(names, [(0, 2, "Broadway"), (2, 3, "5th Avenue")]);
+    }
+
     #[test]
     fn normalize_valhalla_rejects_missing_legs() {
         let json = r#"{
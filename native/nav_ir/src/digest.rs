@@ -0,0 +1,870 @@
+//! Track digestion: recorded track points → Nav-IR Route with synthesized instructions and stops.
+//!
+//! Recorded tracks (GPX `<trk>`) carry no turn-by-turn data and are full of GPS noise. This pass
+//! runs offline, once per import:
+//!
+//! 1. Dwell clusters (rider stationary for `min_dwell_s`, needs timestamps) collapse into a single
+//!    vertex and become `Break` waypoints.
+//! 2. Spikes (single-fix jumps away from the track and back) are removed.
+//! 3. The track is smoothed with a moving average over `smoothing_window_m`.
+//! 4. Zig-zag noise below `simplify_tolerance_m` is removed with Douglas–Peucker.
+//! 5. Turns are found from heading change measured over `turn_window_m` on either side of each
+//!    sample point, so a long sweeping curve does not produce a prompt but a junction does.
+//!
+//! Instructions reference the digested polyline with `SegmentFraction` geometry refs.
+
+use crate::{
+    BoundingBox, Coordinate, EncodedPolyline, GeometryConfidence, GeometryRef, GeometryRefKind,
+    GeometrySource, Instruction, InstructionId, InstructionKind, Leg, LegId, Route, RouteGeometry,
+    RouteMetadata, RoutePolicies, RouteSegment, SegmentConstraints, SegmentId, SegmentIntent,
+    VertexRange, Waypoint, WaypointCategory, WaypointId, WaypointKind,
+};
+use chrono::{DateTime, Utc};
+use geo_types::Coord;
+
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+/// Fallback speed when the track has no timestamps (~15 km/h, same as `normalize_gpx`).
+const FALLBACK_SPEED_MPS: f64 = 1000.0 / 240.0;
+
+/// One recorded track point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPoint {
+    pub coordinate: Coordinate,
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl TrackPoint {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            coordinate: Coordinate::new(latitude, longitude),
+            time: None,
+        }
+    }
+
+    /// Builder-style setter for the recording timestamp.
+    pub fn with_time(mut self, time: DateTime<Utc>) -> Self {
+        self.time = Some(time);
+        self
+    }
+}
+
+/// Tuning knobs for [`digest_track`]. Defaults suit motorcycle/bicycle recordings at 1 Hz.
+#[derive(Debug, Clone)]
+pub struct DigestOptions {
+    /// Width of the moving-average window used to smooth positions (meters).
+    pub smoothing_window_m: f64,
+    /// Douglas–Peucker tolerance; lateral wobble below this is dropped (meters).
+    pub simplify_tolerance_m: f64,
+    /// A vertex where the track reverses direction (> 150°) and the points either side of it
+    /// are closer together than this is treated as a GPS spike and removed (meters).
+    pub spike_max_gap_m: f64,
+    /// Heading change is measured between the track `turn_window_m` before and after a point.
+    pub turn_window_m: f64,
+    /// Minimum windowed heading change that counts as a turn (degrees).
+    pub min_turn_degrees: f64,
+    /// Two turns closer than this are the same maneuver; the sharper one wins (meters).
+    pub turn_separation_m: f64,
+    /// Points within this radius of a cluster's first point belong to the same dwell (meters).
+    pub dwell_radius_m: f64,
+    /// Minimum stationary time for a cluster to become a `Break` waypoint (seconds).
+    pub min_dwell_s: i64,
+}
+
+impl Default for DigestOptions {
+    fn default() -> Self {
+        Self {
+            smoothing_window_m: 20.0,
+            simplify_tolerance_m: 4.0,
+            spike_max_gap_m: 25.0,
+            turn_window_m: 30.0,
+            min_turn_degrees: 40.0,
+            turn_separation_m: 50.0,
+            dwell_radius_m: 30.0,
+            min_dwell_s: 180,
+        }
+    }
+}
+
+/// Working vertex carried through the digestion pipeline.
+#[derive(Debug, Clone)]
+struct Vertex {
+    lat: f64,
+    lon: f64,
+    time: Option<DateTime<Utc>>,
+    /// Seconds spent stationary at this vertex (collapsed dwell cluster).
+    dwell_s: Option<i64>,
+}
+
+impl Vertex {
+    fn coord(&self) -> Coordinate {
+        Coordinate::new(self.lat, self.lon)
+    }
+
+    /// Dwell vertices must survive spike removal, smoothing and simplification. (Track
+    /// endpoints survive too, but each pass keeps those itself.)
+    fn pinned(&self) -> bool {
+        self.dwell_s.is_some()
+    }
+}
+
+/// Digest a recorded track into a single-segment `FixedGeometry` Nav-IR route.
+///
+/// Returns an error when fewer than two distinct points remain after cleaning.
+pub fn digest_track(points: &[TrackPoint], options: &DigestOptions) -> Result<Route, String> {
+    let mut vertices: Vec<Vertex> = Vec::with_capacity(points.len());
+    for p in points {
+        let c = p.coordinate;
+        Coordinate::try_new(c.latitude, c.longitude).map_err(|e| e.to_string())?;
+        if let Some(last) = vertices.last() {
+            if last.lat == c.latitude && last.lon == c.longitude {
+                continue;
+            }
+        }
+        vertices.push(Vertex {
+            lat: c.latitude,
+            lon: c.longitude,
+            time: p.time,
+            dwell_s: None,
+        });
+    }
+    if vertices.len() < 2 {
+        return Err("Track has fewer than 2 distinct points".to_string());
+    }
+
+    let vertices = collapse_dwells(vertices, options);
+    let vertices = remove_spikes(vertices, options);
+    let vertices = smooth(vertices, options);
+    let vertices = simplify(vertices, options);
+    if vertices.len() < 2 {
+        return Err("Track collapsed to fewer than 2 points".to_string());
+    }
+
+    let coords: Vec<Coordinate> = vertices.iter().map(Vertex::coord).collect();
+    let cum = cumulative_distances(&coords);
+    let total_distance_m = *cum.last().unwrap_or(&0.0);
+
+    let turns = detect_turns(&coords, &cum, options);
+    let instructions = build_instructions(&coords, &cum, &turns);
+    let waypoints = build_waypoints(&vertices, options);
+    let legs = build_legs(&vertices, &waypoints, &cum);
+
+    let moving_time_s = moving_time_s(&vertices);
+    let estimated_duration_s = moving_time_s.or_else(|| {
+        (total_distance_m > 0.0).then(|| (total_distance_m / FALLBACK_SPEED_MPS).round() as u64)
+    });
+
+    let polyline_str = polyline::encode_coordinates(
+        coords
+            .iter()
+            .map(|c| Coord {
+                x: c.longitude,
+                y: c.latitude,
+            })
+            .collect::<Vec<_>>(),
+        5,
+    )
+    .map_err(|e| format!("Polyline encode: {}", e))?;
+
+    let bounding_box = coords.iter().fold(
+        BoundingBox {
+            min_lat: 90.0,
+            min_lon: 180.0,
+            max_lat: -90.0,
+            max_lon: -180.0,
+        },
+        |b, c| BoundingBox {
+            min_lat: b.min_lat.min(c.latitude),
+            min_lon: b.min_lon.min(c.longitude),
+            max_lat: b.max_lat.max(c.latitude),
+            max_lon: b.max_lon.max(c.longitude),
+        },
+    );
+
+    let now = Utc::now();
+    let route = Route {
+        schema_version: Route::CURRENT_SCHEMA_VERSION,
+        id: crate::RouteId::new(),
+        metadata: RouteMetadata {
+            name: "Digested track".to_string(),
+            description: None,
+            created_at: now,
+            updated_at: now,
+            total_distance_m: Some(total_distance_m),
+            estimated_duration_s,
+            tags: vec!["digested".to_string()],
+            source: None,
+        },
+        segments: vec![RouteSegment {
+            id: SegmentId::new(),
+            intent: SegmentIntent::FixedGeometry,
+            geometry: RouteGeometry {
+                polyline: EncodedPolyline(polyline_str),
+                source: GeometrySource::ImportedExact,
+                // Smoothed and simplified: close to, but not exactly, what was recorded.
+                confidence: GeometryConfidence::Medium,
+                bounding_box,
+            },
+            waypoints,
+            legs,
+            instructions,
+            constraints: SegmentConstraints::default(),
+            street_names: vec![],
+            road_spans: vec![],
+        }],
+        policies: RoutePolicies::default(),
+    };
+    route.validate().map_err(|e| e.to_string())?;
+    Ok(route)
+}
+
+// ── Geometry helpers ─────────────────────────────────────────────────────────
+
+fn haversine_m(a: Coordinate, b: Coordinate) -> f64 {
+    let lat1 = a.latitude.to_radians();
+    let lat2 = b.latitude.to_radians();
+    let dlat = lat2 - lat1;
+    let dlon = (b.longitude - a.longitude).to_radians();
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    EARTH_RADIUS_M * 2.0 * h.sqrt().atan2((1.0 - h).sqrt())
+}
+
+fn bearing_deg(from: Coordinate, to: Coordinate) -> f64 {
+    let lat1 = from.latitude.to_radians();
+    let lat2 = to.latitude.to_radians();
+    let dlon = (to.longitude - from.longitude).to_radians();
+    let y = dlon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+    y.atan2(x).to_degrees()
+}
+
+/// Normalise a bearing delta to [-180, 180].
+fn normalize_delta(delta: f64) -> f64 {
+    let d = delta % 360.0;
+    if d > 180.0 {
+        d - 360.0
+    } else if d < -180.0 {
+        d + 360.0
+    } else {
+        d
+    }
+}
+
+/// Local equirectangular projection (meters) around `origin`; accurate over a few kilometers.
+fn to_local(origin: Coordinate, c: Coordinate) -> (f64, f64) {
+    let x = (c.longitude - origin.longitude).to_radians()
+        * EARTH_RADIUS_M
+        * origin.latitude.to_radians().cos();
+    let y = (c.latitude - origin.latitude).to_radians() * EARTH_RADIUS_M;
+    (x, y)
+}
+
+/// Perpendicular distance (meters) from `p` to the segment `a→b`.
+fn cross_track_m(p: Coordinate, a: Coordinate, b: Coordinate) -> f64 {
+    let (px, py) = to_local(a, p);
+    let (bx, by) = to_local(a, b);
+    let len_sq = bx * bx + by * by;
+    if len_sq < 1e-9 {
+        return (px * px + py * py).sqrt();
+    }
+    let t = ((px * bx + py * by) / len_sq).clamp(0.0, 1.0);
+    let (dx, dy) = (px - t * bx, py - t * by);
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn cumulative_distances(coords: &[Coordinate]) -> Vec<f64> {
+    let mut cum = Vec::with_capacity(coords.len());
+    let mut acc = 0.0;
+    for (i, c) in coords.iter().enumerate() {
+        if i > 0 {
+            acc += haversine_m(coords[i - 1], *c);
+        }
+        cum.push(acc);
+    }
+    cum
+}
+
+/// Position at `distance` along the polyline as `(segment start index, fraction, coordinate)`.
+fn point_at(coords: &[Coordinate], cum: &[f64], distance: f64) -> (usize, f64, Coordinate) {
+    let last = coords.len() - 1;
+    let d = distance.clamp(0.0, cum[last]);
+    // First vertex whose cumulative distance exceeds `d`; the segment starts one before it.
+    let seg = cum
+        .partition_point(|&c| c <= d)
+        .saturating_sub(1)
+        .min(last - 1);
+    let len = cum[seg + 1] - cum[seg];
+    let fraction = if len > 0.0 {
+        ((d - cum[seg]) / len).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (a, b) = (coords[seg], coords[seg + 1]);
+    let c = Coordinate::new(
+        a.latitude + (b.latitude - a.latitude) * fraction,
+        a.longitude + (b.longitude - a.longitude) * fraction,
+    );
+    (seg, fraction, c)
+}
+
+// ── Cleaning passes ──────────────────────────────────────────────────────────
+
+/// Collapse runs of points that stay within `dwell_radius_m` for at least `min_dwell_s`.
+///
+/// The cluster becomes one vertex at its centroid carrying the arrival time and dwell duration.
+/// Tracks without timestamps pass through unchanged.
+fn collapse_dwells(vertices: Vec<Vertex>, options: &DigestOptions) -> Vec<Vertex> {
+    let mut out = Vec::with_capacity(vertices.len());
+    let mut i = 0;
+    while i < vertices.len() {
+        let anchor = vertices[i].coord();
+        let mut j = i;
+        while j + 1 < vertices.len()
+            && haversine_m(anchor, vertices[j + 1].coord()) <= options.dwell_radius_m
+        {
+            j += 1;
+        }
+        let dwell_s = match (vertices[i].time, vertices[j].time) {
+            (Some(t0), Some(t1)) => Some((t1 - t0).num_seconds()),
+            _ => None,
+        };
+        match dwell_s {
+            Some(secs) if j > i && secs >= options.min_dwell_s => {
+                let n = (j - i + 1) as f64;
+                let (lat, lon) = vertices[i..=j]
+                    .iter()
+                    .fold((0.0, 0.0), |(la, lo), v| (la + v.lat, lo + v.lon));
+                out.push(Vertex {
+                    lat: lat / n,
+                    lon: lon / n,
+                    time: vertices[i].time,
+                    dwell_s: Some(secs),
+                });
+                i = j + 1;
+            }
+            _ => {
+                out.push(vertices[i].clone());
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Drop isolated out-and-back jumps: a vertex where the track reverses (> 150°) and comes back
+/// to within `spike_max_gap_m` of where it left.
+fn remove_spikes(mut vertices: Vec<Vertex>, options: &DigestOptions) -> Vec<Vertex> {
+    loop {
+        let mut removed = false;
+        let mut i = 1;
+        while i + 1 < vertices.len() {
+            let (a, b, c) = (
+                vertices[i - 1].coord(),
+                vertices[i].coord(),
+                vertices[i + 1].coord(),
+            );
+            let reversal = normalize_delta(bearing_deg(b, c) - bearing_deg(a, b)).abs() > 150.0;
+            let returns = haversine_m(a, c) < options.spike_max_gap_m;
+            if reversal && returns && !vertices[i].pinned() {
+                vertices.remove(i);
+                removed = true;
+            } else {
+                i += 1;
+            }
+        }
+        if !removed {
+            return vertices;
+        }
+    }
+}
+
+/// Moving average over a distance window. Endpoints and pinned vertices keep their position.
+fn smooth(vertices: Vec<Vertex>, options: &DigestOptions) -> Vec<Vertex> {
+    let half = options.smoothing_window_m / 2.0;
+    if vertices.len() < 3 || half <= 0.0 {
+        return vertices;
+    }
+    let coords: Vec<Coordinate> = vertices.iter().map(Vertex::coord).collect();
+    let cum = cumulative_distances(&coords);
+    let last = vertices.len() - 1;
+    vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if i == 0 || i == last || v.pinned() {
+                return v.clone();
+            }
+            let (mut lat, mut lon, mut n) = (0.0, 0.0, 0.0);
+            let mut j = i;
+            while j > 0 && cum[i] - cum[j - 1] <= half && !vertices[j - 1].pinned() {
+                j -= 1;
+            }
+            let mut k = i;
+            while k < last && cum[k + 1] - cum[i] <= half && !vertices[k + 1].pinned() {
+                k += 1;
+            }
+            for c in &coords[j..=k] {
+                lat += c.latitude;
+                lon += c.longitude;
+                n += 1.0;
+            }
+            Vertex {
+                lat: lat / n,
+                lon: lon / n,
+                ..v.clone()
+            }
+        })
+        .collect()
+}
+
+/// Douglas–Peucker simplification that never drops pinned vertices.
+fn simplify(vertices: Vec<Vertex>, options: &DigestOptions) -> Vec<Vertex> {
+    if vertices.len() < 3 {
+        return vertices;
+    }
+    let coords: Vec<Coordinate> = vertices.iter().map(Vertex::coord).collect();
+    let mut keep = vec![false; vertices.len()];
+    keep[0] = true;
+    keep[vertices.len() - 1] = true;
+    for (k, v) in keep.iter_mut().zip(&vertices) {
+        *k |= v.pinned();
+    }
+
+    // Simplify each stretch between consecutive kept vertices independently.
+    let anchors: Vec<usize> = (0..vertices.len()).filter(|&i| keep[i]).collect();
+    let mut stack: Vec<(usize, usize)> = anchors.windows(2).map(|w| (w[0], w[1])).collect();
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+        let (mut max_d, mut max_i) = (0.0, start);
+        for i in start + 1..end {
+            let d = cross_track_m(coords[i], coords[start], coords[end]);
+            if d > max_d {
+                max_d = d;
+                max_i = i;
+            }
+        }
+        if max_d > options.simplify_tolerance_m {
+            keep[max_i] = true;
+            stack.push((start, max_i));
+            stack.push((max_i, end));
+        }
+    }
+    vertices
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(v, k)| k.then_some(v))
+        .collect()
+}
+
+// ── Turn detection ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy)]
+struct Turn {
+    distance_m: f64,
+    delta_deg: f64,
+}
+
+/// Windowed heading change at `distance`: bearing over the `window` before vs. the `window` after.
+fn heading_change_at(coords: &[Coordinate], cum: &[f64], distance: f64, window: f64) -> f64 {
+    let (_, _, before) = point_at(coords, cum, distance - window);
+    let (_, _, here) = point_at(coords, cum, distance);
+    let (_, _, after) = point_at(coords, cum, distance + window);
+    if haversine_m(before, here) < 1.0 || haversine_m(here, after) < 1.0 {
+        return 0.0;
+    }
+    normalize_delta(bearing_deg(here, after) - bearing_deg(before, here))
+}
+
+fn detect_turns(coords: &[Coordinate], cum: &[f64], options: &DigestOptions) -> Vec<Turn> {
+    let total = *cum.last().unwrap_or(&0.0);
+    let window = options.turn_window_m;
+    if total < 2.0 * window {
+        return vec![];
+    }
+
+    // Sample every vertex plus a regular grid so long segments are not skipped.
+    let step = (window / 4.0).max(1.0);
+    let mut samples: Vec<f64> = cum.to_vec();
+    let mut s = 0.0;
+    while s < total {
+        samples.push(s);
+        s += step;
+    }
+    samples.retain(|&d| d >= window / 2.0 && d <= total - window / 2.0);
+    samples.sort_by(f64::total_cmp);
+    samples.dedup_by(|a, b| (*a - *b).abs() < 0.01);
+
+    let mut candidates: Vec<Turn> = samples
+        .iter()
+        .map(|&d| Turn {
+            distance_m: d,
+            delta_deg: heading_change_at(coords, cum, d, window),
+        })
+        .filter(|t| t.delta_deg.abs() >= options.min_turn_degrees)
+        .collect();
+
+    // Non-maximum suppression: sharpest first, drop anything within the separation distance.
+    candidates.sort_by(|a, b| b.delta_deg.abs().total_cmp(&a.delta_deg.abs()));
+    let mut turns: Vec<Turn> = Vec::new();
+    for c in candidates {
+        if turns
+            .iter()
+            .all(|t| (t.distance_m - c.distance_m).abs() >= options.turn_separation_m)
+        {
+            turns.push(c);
+        }
+    }
+    turns.sort_by(|a, b| a.distance_m.total_cmp(&b.distance_m));
+
+    // Zig-zag: a left/right pair in quick succession that cancels out is a jog, not two turns.
+    let mut merged: Vec<Turn> = Vec::with_capacity(turns.len());
+    let mut i = 0;
+    while i < turns.len() {
+        if let Some(next) = turns.get(i + 1) {
+            let cur = turns[i];
+            let close = next.distance_m - cur.distance_m < 2.0 * options.turn_separation_m;
+            let opposite = cur.delta_deg.signum() != next.delta_deg.signum();
+            let net = normalize_delta(cur.delta_deg + next.delta_deg).abs();
+            if close && opposite && net < options.min_turn_degrees {
+                i += 2;
+                continue;
+            }
+        }
+        merged.push(turns[i]);
+        i += 1;
+    }
+    merged
+}
+
+fn fraction_ref(seg_start_index: usize, fraction: f64) -> GeometryRef {
+    GeometryRef {
+        kind: GeometryRefKind::SegmentFraction,
+        vertex_index: None,
+        seg_start_index: Some(seg_start_index as u32),
+        fraction: Some(fraction as f32),
+    }
+}
+
+fn build_instructions(coords: &[Coordinate], cum: &[f64], turns: &[Turn]) -> Vec<Instruction> {
+    let total = *cum.last().unwrap_or(&0.0);
+    let mut stops: Vec<(f64, InstructionKind)> = Vec::with_capacity(turns.len() + 2);
+    stops.push((0.0, InstructionKind::Depart));
+    for t in turns {
+        let kind = if t.delta_deg > 0.0 {
+            InstructionKind::TurnRight
+        } else {
+            InstructionKind::TurnLeft
+        };
+        stops.push((t.distance_m, kind));
+    }
+    stops.push((total, InstructionKind::Arrive));
+
+    stops
+        .iter()
+        .enumerate()
+        .map(|(i, &(distance, kind))| {
+            let (seg, fraction, coordinate) = point_at(coords, cum, distance);
+            let distance_to_next_m = stops
+                .get(i + 1)
+                .map(|&(next, _)| next - distance)
+                .unwrap_or(0.0);
+            Instruction {
+                id: InstructionId::new(),
+                coordinate: Some(coordinate),
+                geometry_ref: Some(fraction_ref(seg, fraction)),
+                kind,
+                distance_to_next_m: Some(distance_to_next_m),
+                street_name: None,
+            }
+        })
+        .collect()
+}
+
+// ── Waypoints and legs ───────────────────────────────────────────────────────
+
+fn vertex_waypoint(v: &Vertex, index: usize, kind: WaypointKind) -> Waypoint {
+    Waypoint {
+        id: WaypointId::new(),
+        coordinate: v.coord(),
+        kind,
+        radius_m: None,
+        name: None,
+        description: None,
+        role: None,
+        category: None,
+        geometry_ref: Some(GeometryRef {
+            kind: GeometryRefKind::VertexIndex,
+            vertex_index: Some(index as u32),
+            seg_start_index: None,
+            fraction: None,
+        }),
+    }
+}
+
+/// Start, one `Break` per interior dwell, Stop. Dwells at either end are absorbed by Start/Stop.
+fn build_waypoints(vertices: &[Vertex], options: &DigestOptions) -> Vec<Waypoint> {
+    let last = vertices.len() - 1;
+    let mut waypoints = vec![vertex_waypoint(&vertices[0], 0, WaypointKind::Start)];
+    for (i, v) in vertices.iter().enumerate().take(last).skip(1) {
+        if let Some(secs) = v.dwell_s {
+            let mut wp = vertex_waypoint(v, i, WaypointKind::Break);
+            wp.radius_m = Some(options.dwell_radius_m);
+            wp.category = Some(WaypointCategory::Break);
+            wp.description = Some(format!("Stopped for {} min", (secs + 30) / 60));
+            waypoints.push(wp);
+        }
+    }
+    waypoints.push(vertex_waypoint(&vertices[last], last, WaypointKind::Stop));
+    waypoints
+}
+
+fn build_legs(vertices: &[Vertex], waypoints: &[Waypoint], cum: &[f64]) -> Vec<Leg> {
+    let index_of = |wp: &Waypoint| {
+        wp.geometry_ref
+            .as_ref()
+            .and_then(|r| r.vertex_index)
+            .unwrap_or(0) as usize
+    };
+    waypoints
+        .windows(2)
+        .map(|w| {
+            let (start, end) = (index_of(&w[0]), index_of(&w[1]));
+            // Leave the stop time at the starting vertex out of the leg duration.
+            let duration_s = match (vertices[start].time, vertices[end].time) {
+                (Some(t0), Some(t1)) => {
+                    let elapsed = (t1 - t0).num_seconds() - vertices[start].dwell_s.unwrap_or(0);
+                    Some(elapsed.max(0) as u64)
+                }
+                _ => None,
+            };
+            Leg {
+                id: LegId::new(),
+                from: w[0].id,
+                to: w[1].id,
+                vertex_range: VertexRange {
+                    start: start as u32,
+                    end: end as u32,
+                },
+                distance_m: Some(cum[end] - cum[start]),
+                duration_s,
+            }
+        })
+        .collect()
+}
+
+/// Elapsed time between first and last point minus time spent in dwells.
+fn moving_time_s(vertices: &[Vertex]) -> Option<u64> {
+    let first = vertices.first()?.time?;
+    let last = vertices.last()?;
+    let elapsed = (last.time? - first).num_seconds() + last.dwell_s.unwrap_or(0);
+    let dwelling: i64 = vertices.iter().filter_map(|v| v.dwell_s).sum();
+    Some((elapsed - dwelling).max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// ~1 m in degrees of latitude.
+    const M: f64 = 1.0 / 111_195.0;
+
+    fn t(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    /// Points every `step_m` meters along a list of (north_m, east_m) corners.
+    fn densify(corners: &[(f64, f64)], step_m: f64) -> Vec<(f64, f64)> {
+        let mut out = vec![corners[0]];
+        for w in corners.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let len = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+            let n = (len / step_m).ceil().max(1.0) as usize;
+            for k in 1..=n {
+                let f = k as f64 / n as f64;
+                out.push((a.0 + (b.0 - a.0) * f, a.1 + (b.1 - a.1) * f));
+            }
+        }
+        out
+    }
+
+    fn track(local: &[(f64, f64)]) -> Vec<TrackPoint> {
+        local
+            .iter()
+            .map(|(n, e)| TrackPoint::new(52.0 + n * M, 5.0 + e * M / 52f64.to_radians().cos()))
+            .collect()
+    }
+
+    fn turns(route: &Route) -> Vec<InstructionKind> {
+        route.segments[0]
+            .instructions
+            .iter()
+            .map(|i| i.kind)
+            .filter(|k| !matches!(k, InstructionKind::Depart | InstructionKind::Arrive))
+            .collect()
+    }
+
+    #[test]
+    fn right_angle_corner_yields_single_turn() {
+        let pts = track(&densify(&[(0.0, 0.0), (400.0, 0.0), (400.0, 400.0)], 5.0));
+        let route = digest_track(&pts, &DigestOptions::default()).unwrap();
+        assert_eq!(turns(&route), vec![InstructionKind::TurnRight]);
+    }
+
+    #[test]
+    fn instructions_use_segment_fraction_refs() {
+        let pts = track(&densify(&[(0.0, 0.0), (400.0, 0.0), (400.0, -400.0)], 5.0));
+        let route = digest_track(&pts, &DigestOptions::default()).unwrap();
+        let seg = &route.segments[0];
+        assert_eq!(
+            seg.instructions.first().unwrap().kind,
+            InstructionKind::Depart
+        );
+        assert_eq!(
+            seg.instructions.last().unwrap().kind,
+            InstructionKind::Arrive
+        );
+        for inst in &seg.instructions {
+            let gr = inst.geometry_ref.as_ref().unwrap();
+            assert_eq!(gr.kind, GeometryRefKind::SegmentFraction);
+            assert!(gr.seg_start_index.is_some());
+        }
+        let turn = &seg.instructions[1];
+        assert_eq!(turn.kind, InstructionKind::TurnLeft);
+        let d = turn.distance_to_next_m.unwrap();
+        assert!((d - 400.0).abs() < 20.0, "turn → arrive ≈ 400 m, got {}", d);
+    }
+
+    #[test]
+    fn sweeping_curve_is_not_a_turn() {
+        // Quarter circle with 300 m radius: 90° of heading change spread over ~470 m.
+        let pts: Vec<(f64, f64)> = (0..=94)
+            .map(|k| {
+                let a = (k as f64 / 94.0) * std::f64::consts::FRAC_PI_2;
+                (300.0 * a.sin(), 300.0 * (1.0 - a.cos()))
+            })
+            .collect();
+        let route = digest_track(&track(&pts), &DigestOptions::default()).unwrap();
+        assert!(turns(&route).is_empty(), "got {:?}", turns(&route));
+    }
+
+    #[test]
+    fn lateral_gps_jitter_is_collapsed() {
+        // Straight 1 km north with ±3 m alternating east/west noise.
+        let pts: Vec<(f64, f64)> = (0..=200)
+            .map(|k| (k as f64 * 5.0, if k % 2 == 0 { 3.0 } else { -3.0 }))
+            .collect();
+        let route = digest_track(&track(&pts), &DigestOptions::default()).unwrap();
+        assert!(turns(&route).is_empty(), "got {:?}", turns(&route));
+        let decoded = polyline::decode_polyline(&route.segments[0].geometry.polyline.0, 5).unwrap();
+        assert!(
+            decoded.0.len() < 20,
+            "expected simplification, got {}",
+            decoded.0.len()
+        );
+    }
+
+    #[test]
+    fn chicane_jog_is_not_reported() {
+        // North, jog 15 m east over 20 m, continue north.
+        let pts = track(&densify(
+            &[(0.0, 0.0), (300.0, 0.0), (320.0, 15.0), (600.0, 15.0)],
+            5.0,
+        ));
+        let route = digest_track(&pts, &DigestOptions::default()).unwrap();
+        assert!(turns(&route).is_empty(), "got {:?}", turns(&route));
+    }
+
+    #[test]
+    fn spike_is_removed() {
+        let mut local = densify(&[(0.0, 0.0), (500.0, 0.0)], 10.0);
+        local.insert(25, (245.0, 0.0));
+        local.insert(26, (240.0, 120.0)); // 120 m GPS jump east and back
+        let route = digest_track(&track(&local), &DigestOptions::default()).unwrap();
+        let b = &route.segments[0].geometry.bounding_box;
+        assert!((b.max_lon - 5.0) / (M / 52f64.to_radians().cos()) < 10.0);
+    }
+
+    #[test]
+    fn dwell_cluster_becomes_break_waypoint() {
+        let mut pts = Vec::new();
+        let mut secs = 0;
+        for (n, e) in densify(&[(0.0, 0.0), (500.0, 0.0)], 10.0) {
+            pts.push(TrackPoint::new(52.0 + n * M, 5.0 + e * M).with_time(t(secs)));
+            secs += 2;
+        }
+        // Ten minutes parked with a few meters of jitter.
+        for k in 0..60 {
+            let j = (k % 3) as f64;
+            pts.push(TrackPoint::new(52.0 + (500.0 + j) * M, 5.0 + j * M).with_time(t(secs)));
+            secs += 10;
+        }
+        for (n, e) in densify(&[(500.0, 0.0), (1000.0, 0.0)], 10.0)
+            .into_iter()
+            .skip(1)
+        {
+            pts.push(TrackPoint::new(52.0 + n * M, 5.0 + e * M).with_time(t(secs)));
+            secs += 2;
+        }
+
+        let route = digest_track(&pts, &DigestOptions::default()).unwrap();
+        let seg = &route.segments[0];
+        let kinds: Vec<_> = seg.waypoints.iter().map(|w| w.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![WaypointKind::Start, WaypointKind::Break, WaypointKind::Stop]
+        );
+        assert_eq!(seg.waypoints[1].category, Some(WaypointCategory::Break));
+        assert_eq!(seg.legs.len(), 2);
+        // Moving time excludes the ten minute stop.
+        let moving = route.metadata.estimated_duration_s.unwrap();
+        assert!(moving < 300, "moving time {}s", moving);
+    }
+
+    #[test]
+    fn digest_gpx_reads_track_times() {
+        let gpx = r#"<?xml version="1.0"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk><name>Evening ride</name><trkseg>
+    <trkpt lat="52.000" lon="5.000"><time>2025-05-01T18:00:00Z</time></trkpt>
+    <trkpt lat="52.005" lon="5.000"><time>2025-05-01T18:01:00Z</time></trkpt>
+    <trkpt lat="52.010" lon="5.000"><time>2025-05-01T18:02:30Z</time></trkpt>
+  </trkseg></trk>
+</gpx>"#;
+        let route = crate::digest_gpx(gpx.as_bytes(), &DigestOptions::default()).unwrap();
+        assert_eq!(route.metadata.name, "Evening ride");
+        assert_eq!(route.metadata.estimated_duration_s, Some(150));
+        assert_eq!(route.segments[0].intent, SegmentIntent::FixedGeometry);
+    }
+
+    #[test]
+    fn import_gpx_digests_tracks_and_keeps_planned_routes() {
+        let track = r#"<?xml version="1.0"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk><name>Ride</name><trkseg>
+    <trkpt lat="52.000" lon="5.000"/>
+    <trkpt lat="52.005" lon="5.000"/>
+    <trkpt lat="52.010" lon="5.000"/>
+  </trkseg></trk>
+</gpx>"#;
+        let route = crate::import_gpx(track.as_bytes()).unwrap();
+        assert!(route.metadata.tags.contains(&"digested".to_string()));
+
+        let planned = r#"<?xml version="1.0"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+  <rte><name>Plan</name>
+    <rtept lat="52.000" lon="5.000"><name>Home</name></rtept>
+    <rtept lat="52.010" lon="5.010"><name>Cafe</name></rtept>
+  </rte>
+</gpx>"#;
+        let route = crate::import_gpx(planned.as_bytes()).unwrap();
+        assert_eq!(route.metadata.tags, vec!["gpx".to_string()]);
+        assert_eq!(route.segments[0].waypoints[1].name.as_deref(), Some("Cafe"));
+    }
+
+    #[test]
+    fn too_few_points_is_error() {
+        assert!(digest_track(&[TrackPoint::new(1.0, 1.0)], &DigestOptions::default()).is_err());
+    }
+}
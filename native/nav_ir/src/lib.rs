@@ -1,15 +1,18 @@
 //! Navigation Intermediate Representation (Nav-IR).
 //!
 //! Canonical, engine-agnostic route format. No dependency on device_comm or nav_core.
-//! Use the `adapters` module to normalize OSRM, GPX, or custom API output into Nav-IR.
+//! Use the `adapters` module to normalize OSRM, GPX, or custom API output into Nav-IR, and
+//! `digest` to turn raw recorded tracks into routes with synthesized instructions.
 
 mod adapters;
+pub mod digest;
 mod types;
 
 pub use adapters::{
-    normalize_custom, normalize_google_routes, normalize_gpx, normalize_graphhopper,
-    normalize_osrm, normalize_valhalla, OsrmResponse,
+    digest_gpx, gpx_track_points, import_gpx, normalize_custom, normalize_google_routes,
+    normalize_gpx, normalize_graphhopper, normalize_osrm, normalize_valhalla, OsrmResponse,
 };
+pub use digest::{digest_track, DigestOptions, TrackPoint};
 pub use types::*;
 
 #[cfg(test)]
@@ -74,6 +77,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -132,6 +137,39 @@ mod tests {
         assert_eq!(route.segments.len(), 1);
         assert_eq!(route.segments[0].waypoints.len(), 3);
         assert_eq!(route.segments[0].intent, SegmentIntent::Recalculatable);
+        assert_eq!(
+            route.segments[0].road_spans[1].attribute,
+            RoadAttribute::SpeedLimit { max_kmh: 120 }
+        );
+        assert_eq!(route.segments[0].street_names[1].name, "A99");
+    }
+
+    #[test]
+    fn validate_rejects_reversed_road_span() {
+        let mut route: Route =
+            serde_json::from_str(include_str!("../fixtures/osrm_like.json")).unwrap();
+        route.segments[0].road_spans[1].vertex_range = VertexRange { start: 2, end: 1 };
+        assert_eq!(
+            route.validate(),
+            Err(ValidationError::RoadSpanVertexRangeInvalid {
+                segment_index: 0,
+                span_index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn validate_rejects_reversed_street_span() {
+        let mut route: Route =
+            serde_json::from_str(include_str!("../fixtures/osrm_like.json")).unwrap();
+        route.segments[0].street_names[0].vertex_range = VertexRange { start: 1, end: 0 };
+        assert_eq!(
+            route.validate(),
+            Err(ValidationError::StreetSpanVertexRangeInvalid {
+                segment_index: 0,
+                span_index: 0
+            })
+        );
     }
 
     #[test]
@@ -209,6 +247,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -274,6 +314,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -339,6 +381,8 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -418,6 +462,8 @@ mod tests {
                     street_name: None,
                 }],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -488,6 +534,8 @@ mod tests {
                     street_name: None,
                 }],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
         };
@@ -360,6 +360,34 @@ pub struct Leg {
     pub duration_s: Option<u64>,
 }
 
+// --- RoadSpan ---
+
+/// Road attribute a router reported for a stretch of segment geometry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoadAttribute {
+    Toll,
+    Highway,
+    Unpaved,
+    /// Posted speed limit; overrides the segment's `max_speed_kmh` within the span.
+    SpeedLimit {
+        max_kmh: u32,
+    },
+}
+
+/// `attribute` applies between the segment's geometry vertices `vertex_range.start..=end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoadSpan {
+    pub vertex_range: VertexRange,
+    pub attribute: RoadAttribute,
+}
+
+/// The segment's geometry vertices `vertex_range.start..=end` run along street `name`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreetSpan {
+    pub vertex_range: VertexRange,
+    pub name: String,
+}
+
 // --- RouteSegment ---
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -372,6 +400,12 @@ pub struct RouteSegment {
     pub legs: Vec<Leg>,
     pub instructions: Vec<Instruction>,
     pub constraints: SegmentConstraints,
+    /// Toll, highway, unpaved and speed-limit stretches, when the source knows them.
+    #[serde(default)]
+    pub road_spans: Vec<RoadSpan>,
+    /// Street names along the geometry, when the source knows them.
+    #[serde(default)]
+    pub street_names: Vec<StreetSpan>,
 }
 
 // --- Route ---
@@ -419,6 +453,14 @@ pub enum ValidationError {
     LegsNotMonotonic {
         segment_index: usize,
     },
+    RoadSpanVertexRangeInvalid {
+        segment_index: usize,
+        span_index: usize,
+    },
+    StreetSpanVertexRangeInvalid {
+        segment_index: usize,
+        span_index: usize,
+    },
 }
 
 impl std::fmt::Display for ValidationError {
@@ -485,6 +527,22 @@ impl std::fmt::Display for ValidationError {
                 "segment {} legs must be monotonic (ordered vertex ranges)",
                 segment_index
             ),
+            ValidationError::RoadSpanVertexRangeInvalid {
+                segment_index,
+                span_index,
+            } => write!(
+                f,
+                "segment {} road span {} vertex_range must have start <= end",
+                segment_index, span_index
+            ),
+            ValidationError::StreetSpanVertexRangeInvalid {
+                segment_index,
+                span_index,
+            } => write!(
+                f,
+                "segment {} street span {} vertex_range must have start <= end",
+                segment_index, span_index
+            ),
         }
     }
 }
@@ -599,6 +657,22 @@ impl Route {
                 }
                 prev_end = Some(leg.vertex_range.end);
             }
+            for (span_idx, span) in seg.road_spans.iter().enumerate() {
+                if span.vertex_range.start > span.vertex_range.end {
+                    return Err(ValidationError::RoadSpanVertexRangeInvalid {
+                        segment_index: idx,
+                        span_index: span_idx,
+                    });
+                }
+            }
+            for (span_idx, span) in seg.street_names.iter().enumerate() {
+                if span.vertex_range.start > span.vertex_range.end {
+                    return Err(ValidationError::StreetSpanVertexRangeInvalid {
+                        segment_index: idx,
+                        span_index: span_idx,
+                    });
+                }
+            }
         }
         Ok(())
     }
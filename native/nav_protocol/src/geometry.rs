@@ -0,0 +1,292 @@
+//! Route geometry for RouteBlobs: quantized, delta-encoded `RawPoints`, simplification to a
+//! point budget, and the zoom levels in `Metadata`.
+//!
+//! `RawPoints` carry coordinates quantized to 1e-5 degrees. The first point is absolute and
+//! every following point is the difference to the one before, so that most points fit in a
+//! byte or two as sint32 varints. Deltas are taken between quantized values, so decoding
+//! doesn't accumulate rounding error.
+
+use nav_ir::Coordinate;
+
+use crate::proto;
+
+const EARTH_RADIUS_M: f64 = 6_371_000.0;
+
+/// Simplification stops once no point is further than this off the line (meters): about half
+/// the 1e-5 degree quantization step, below which `RawPoints` can't tell anyway.
+const MIN_DEVIATION_M: f64 = 0.5;
+
+/// Highest zoom level put in `Metadata`.
+pub const MAX_ZOOM: u32 = 18;
+
+/// Web Mercator tile size (pixels).
+const TILE_PX: f64 = 256.0;
+
+/// At `preferred_zoom`, consecutive route points are about this far apart on screen (pixels).
+const POINT_SPACING_PX: f64 = 32.0;
+
+/// How `nav_ir_route_to_route_blob` encodes the route geometry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RouteBlobOptions {
+    /// Send delta-encoded `RawPoints` instead of the encoded polyline.
+    pub raw_points: bool,
+    /// Simplify `RawPoints` to at most this many points; `None` keeps them all.
+    pub max_points: Option<usize>,
+}
+
+impl RouteBlobOptions {
+    /// The encoding a device asked for in its `DeviceCapabilities` handshake.
+    pub fn from_capabilities(capabilities: &proto::DeviceCapabilities) -> Self {
+        Self {
+            raw_points: capabilities.prefers_raw_points,
+            max_points: (capabilities.max_route_points > 0)
+                .then_some(capabilities.max_route_points as usize),
+        }
+    }
+}
+
+fn quantize(degrees: f64) -> i32 {
+    (degrees * 1e5).round() as i32
+}
+
+/// Quantize and delta-encode coordinates into `RawPoints`.
+pub fn encode_raw_points(coords: &[Coordinate]) -> proto::RawPoints {
+    let mut previous = (0, 0);
+    let points = coords
+        .iter()
+        .map(|c| {
+            let current = (quantize(c.latitude), quantize(c.longitude));
+            let point = proto::Point {
+                lat_e5: current.0.wrapping_sub(previous.0),
+                lon_e5: current.1.wrapping_sub(previous.1),
+            };
+            previous = current;
+            point
+        })
+        .collect();
+    proto::RawPoints { points }
+}
+
+/// Undo `encode_raw_points`: sum the deltas back into coordinates.
+pub fn decode_raw_points(raw: &proto::RawPoints) -> Vec<Coordinate> {
+    let (mut lat_e5, mut lon_e5) = (0i32, 0i32);
+    raw.points
+        .iter()
+        .map(|p| {
+            lat_e5 = lat_e5.wrapping_add(p.lat_e5);
+            lon_e5 = lon_e5.wrapping_add(p.lon_e5);
+            Coordinate::new(lat_e5 as f64 / 1e5, lon_e5 as f64 / 1e5)
+        })
+        .collect()
+}
+
+/// Local equirectangular projection around `origin` (meters); plenty for route-sized extents.
+fn project(c: Coordinate, origin: Coordinate) -> (f64, f64) {
+    let x = (c.longitude - origin.longitude).to_radians()
+        * origin.latitude.to_radians().cos()
+        * EARTH_RADIUS_M;
+    let y = (c.latitude - origin.latitude).to_radians() * EARTH_RADIUS_M;
+    (x, y)
+}
+
+/// Distance from `p` to the segment `a`–`b` (meters, projected).
+fn segment_distance_m(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 > 0.0 {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    ((p.0 - a.0 - t * dx).powi(2) + (p.1 - a.1 - t * dy).powi(2)).sqrt()
+}
+
+/// A stretch between two kept points and its farthest point in between.
+struct Span {
+    start: usize,
+    end: usize,
+    max_d: f64,
+    max_i: usize,
+}
+
+impl Span {
+    fn new(start: usize, end: usize, xy: &[(f64, f64)]) -> Self {
+        let (mut max_d, mut max_i) = (0.0, start);
+        for (i, &p) in xy.iter().enumerate().take(end).skip(start + 1) {
+            let d = segment_distance_m(p, xy[start], xy[end]);
+            if d > max_d {
+                max_d = d;
+                max_i = i;
+            }
+        }
+        Self {
+            start,
+            end,
+            max_d,
+            max_i,
+        }
+    }
+}
+
+/// Keep at most `budget` points (at least the two ends), chosen Douglas–Peucker style: the
+/// point farthest from the simplified line is added until the budget is spent or every point
+/// is within `MIN_DEVIATION_M` of the line.
+pub fn simplify_to_budget(coords: &[Coordinate], budget: usize) -> Vec<Coordinate> {
+    let budget = budget.max(2);
+    if coords.len() <= budget {
+        return coords.to_vec();
+    }
+    let xy: Vec<(f64, f64)> = coords.iter().map(|&c| project(c, coords[0])).collect();
+    let mut keep = vec![false; coords.len()];
+    keep[0] = true;
+    keep[coords.len() - 1] = true;
+    let mut spans = vec![Span::new(0, coords.len() - 1, &xy)];
+    for _ in 2..budget {
+        let Some((k, _)) = spans
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.max_d > MIN_DEVIATION_M)
+            .max_by(|(_, a), (_, b)| a.max_d.total_cmp(&b.max_d))
+        else {
+            break;
+        };
+        let span = spans.swap_remove(k);
+        keep[span.max_i] = true;
+        spans.push(Span::new(span.start, span.max_i, &xy));
+        spans.push(Span::new(span.max_i, span.end, &xy));
+    }
+    coords
+        .iter()
+        .zip(keep)
+        .filter_map(|(&c, k)| k.then_some(c))
+        .collect()
+}
+
+fn mercator_y(latitude: f64) -> f64 {
+    let lat = latitude.clamp(-85.0511, 85.0511).to_radians();
+    (std::f64::consts::FRAC_PI_4 + lat / 2.0).tan().ln()
+}
+
+/// `(zoom_hint, preferred_zoom)` for a route's geometry.
+///
+/// `zoom_hint` is the highest Web Mercator zoom at which the whole route fits in one 256 px
+/// tile, for an overview. `preferred_zoom` is the zoom at which consecutive points (median
+/// spacing) are about 32 px apart, i.e. the most detail the geometry has to show; it is never
+/// below `zoom_hint`. Both are at most `MAX_ZOOM`.
+pub fn zoom_levels(coords: &[Coordinate]) -> (u32, u32) {
+    if coords.is_empty() {
+        return (0, 0);
+    }
+    let (mut min_lat, mut max_lat) = (f64::MAX, f64::MIN);
+    let (mut min_lon, mut max_lon) = (f64::MAX, f64::MIN);
+    for c in coords {
+        min_lat = min_lat.min(c.latitude);
+        max_lat = max_lat.max(c.latitude);
+        min_lon = min_lon.min(c.longitude);
+        max_lon = max_lon.max(c.longitude);
+    }
+    // Fraction of the world's width and height the route spans.
+    let span = ((max_lon - min_lon) / 360.0)
+        .max((mercator_y(max_lat) - mercator_y(min_lat)) / (2.0 * std::f64::consts::PI));
+    let zoom_hint = zoom_from_scale(1.0 / span);
+
+    let mut spacings: Vec<f64> = coords
+        .windows(2)
+        .map(|w| {
+            let (x, y) = project(w[1], w[0]);
+            x.hypot(y)
+        })
+        .filter(|d| *d > 0.0)
+        .collect();
+    if spacings.is_empty() {
+        return (zoom_hint, zoom_hint);
+    }
+    spacings.sort_by(f64::total_cmp);
+    let median_m = spacings[spacings.len() / 2];
+    // Meters per pixel at zoom z are 2πR·cos(lat) / (256·2^z); solve for median_m = 32 px.
+    let mid_lat = ((min_lat + max_lat) / 2.0).to_radians();
+    let scale = 2.0 * std::f64::consts::PI * EARTH_RADIUS_M * mid_lat.cos() * POINT_SPACING_PX
+        / (TILE_PX * median_m);
+    let preferred = zoom_from_scale(scale);
+    (zoom_hint, preferred.max(zoom_hint))
+}
+
+/// The zoom z with 2^z <= `scale`, clamped to 0..=MAX_ZOOM.
+fn zoom_from_scale(scale: f64) -> u32 {
+    if !scale.is_finite() || scale >= f64::from(1u32 << MAX_ZOOM) {
+        return MAX_ZOOM;
+    }
+    scale.log2().floor().clamp(0.0, MAX_ZOOM as f64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zigzag(n: usize) -> Vec<Coordinate> {
+        (0..n)
+            .map(|i| {
+                let wobble = if i % 2 == 0 { 0.0 } else { 0.0002 };
+                Coordinate::new(52.0 + i as f64 * 0.001 + wobble, 4.9 + i as f64 * 0.0005)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn raw_points_round_trip_through_deltas() {
+        let coords = zigzag(50);
+        let raw = encode_raw_points(&coords);
+        assert_eq!(raw.points[0].lat_e5, 5_200_000);
+        // Past the first point, only small deltas are carried.
+        assert!(raw.points[1..]
+            .iter()
+            .all(|p| p.lat_e5.abs() < 500 && p.lon_e5.abs() < 500));
+        for (a, b) in decode_raw_points(&raw).iter().zip(&coords) {
+            assert!((a.latitude - b.latitude).abs() <= 0.5e-5);
+            assert!((a.longitude - b.longitude).abs() <= 0.5e-5);
+        }
+    }
+
+    #[test]
+    fn simplification_keeps_ends_and_corners_within_budget() {
+        // An L: straight north, then straight east, densely sampled.
+        let mut coords: Vec<Coordinate> = (0..100)
+            .map(|i| Coordinate::new(52.0 + i as f64 * 1e-4, 4.9))
+            .collect();
+        coords.extend((1..100).map(|i| Coordinate::new(52.0099, 4.9 + i as f64 * 1e-4)));
+
+        let simplified = simplify_to_budget(&coords, 10);
+        assert_eq!(simplified.len(), 3, "the straight legs need no more points");
+        assert_eq!(simplified[0], coords[0]);
+        assert_eq!(simplified[1], coords[99]);
+        assert_eq!(simplified[2], *coords.last().unwrap());
+
+        let zigzag = zigzag(200);
+        let simplified = simplify_to_budget(&zigzag, 40);
+        assert_eq!(simplified.len(), 40);
+        assert_eq!(simplified.first(), zigzag.first());
+        assert_eq!(simplified.last(), zigzag.last());
+        assert_eq!(simplify_to_budget(&zigzag, 500).len(), 200);
+    }
+
+    #[test]
+    fn zoom_levels_follow_extent_and_point_density() {
+        // ~11 km north, a point every ~110 m.
+        let coords: Vec<Coordinate> = (0..=100)
+            .map(|i| Coordinate::new(52.0 + i as f64 * 1e-3, 4.9))
+            .collect();
+        let (hint, preferred) = zoom_levels(&coords);
+        assert_eq!(hint, 11);
+        assert_eq!(preferred, 14);
+
+        // Denser sampling of the same road: more detail to show.
+        let dense: Vec<Coordinate> = (0..=500)
+            .map(|i| Coordinate::new(52.0 + i as f64 * 2e-4, 4.9))
+            .collect();
+        assert_eq!(zoom_levels(&dense), (11, 17));
+
+        let point = [Coordinate::new(52.0, 4.9)];
+        assert_eq!(zoom_levels(&point), (MAX_ZOOM, MAX_ZOOM));
+        assert_eq!(zoom_levels(&[]), (0, 0));
+    }
+}
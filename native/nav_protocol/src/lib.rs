@@ -3,12 +3,26 @@ use crc32fast::Hasher;
 use prost::Message as ProstMessage;
 use uuid::Uuid;
 
+pub mod geometry;
 pub mod messages;
+pub mod reliable;
+pub mod signing;
 
+pub use geometry::{
+    decode_raw_points, encode_raw_points, simplify_to_budget, zoom_levels, RouteBlobOptions,
+};
 pub use messages::{
-    chunk_message_for_ble, create_control_message, nav_ir_route_to_route_blob,
+    chunk_message_for_ble, chunk_route_blob, create_control_message, nav_ir_route_to_route_blob,
     prepare_map_region_metadata_message, prepare_map_style_message, prepare_route_message,
-    prepare_tile_chunk_message, reassemble_frames,
+    prepare_route_message_with, prepare_tile_chunk_message, reassemble_frames,
+    route_json_to_nav_ir, verify_route_blob_checksum,
+};
+pub use reliable::{
+    Clock, ManualClock, ReliableConfig, ReliableReceiver, ReliableSender, SystemClock,
+    TransferFailure, TransferStatus,
+};
+pub use signing::{
+    canonical_route_blob_bytes, sign_route_blob, verify_route_blob_signature, SigningKey,
 };
 
 // Include generated protobuf code
@@ -21,12 +35,17 @@ use proto::*;
 
 const PROTOCOL_VERSION: u32 = 1;
 const FRAME_MAGIC: u32 = 0x4E415645; // "NAVE"
-const DEFAULT_MTU: usize = 247;
+/// BLE MTU assumed when the connection doesn't say otherwise.
+pub const DEFAULT_MTU: usize = 247;
 const FRAME_OVERHEAD: usize = 40;
 /// Android BLE write-with-response limit; frames must not exceed this.
 const BLE_MAX_WRITE_BYTES: usize = 512;
 /// Conservative overhead for serialized Frame (tags, length prefixes, fixed fields).
 const BLE_FRAME_SERIALIZED_OVERHEAD: usize = 50;
+/// Frame flag: the message is an LZ4 block, prefixed with its uncompressed size (u32 LE).
+pub const FLAG_COMPRESSED: u32 = 0x1;
+/// Largest message `FrameAssembler` will assemble or decompress by default.
+pub const MAX_MESSAGE_BYTES: usize = 512 * 1024;
 
 /// Result type for device communication operations
 pub type Result<T> = std::result::Result<T, DeviceError>;
@@ -50,6 +69,15 @@ pub enum DeviceError {
 
     #[error("Timeout waiting for ACK")]
     Timeout,
+
+    #[error("Decompression error: {0}")]
+    Decompression(String),
+
+    #[error("Message too large: {size} bytes, limit {limit}")]
+    MessageTooLarge { size: usize, limit: usize },
+
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
 }
 
 /// Creates a header with current protocol version
@@ -79,6 +107,25 @@ pub(crate) fn calculate_crc32(data: &[u8]) -> u32 {
     hasher.finalize()
 }
 
+/// LZ4-compress a message payload, prefixed with its size for `decompress_payload`.
+pub(crate) fn compress_payload(data: &[u8]) -> Vec<u8> {
+    lz4_flex::block::compress_prepend_size(data)
+}
+
+/// Undo `compress_payload`, refusing output larger than `limit` before allocating it.
+pub(crate) fn decompress_payload(data: &[u8], limit: usize) -> Result<Vec<u8>> {
+    let prefix: [u8; 4] = data
+        .get(..4)
+        .and_then(|p| p.try_into().ok())
+        .ok_or_else(|| DeviceError::Decompression("Missing size prefix".to_string()))?;
+    let size = u32::from_le_bytes(prefix) as usize;
+    if size > limit {
+        return Err(DeviceError::MessageTooLarge { size, limit });
+    }
+    lz4_flex::block::decompress(&data[4..], size)
+        .map_err(|e| DeviceError::Decompression(e.to_string()))
+}
+
 /// Split a message into frames for BLE transmission
 pub fn chunk_message(
     msg: &proto::Message,
@@ -87,6 +134,17 @@ pub fn chunk_message(
     mtu: usize,
 ) -> Result<Vec<Frame>> {
     let payload = serialize_proto_message(msg)?;
+    Ok(chunk_payload(&payload, route_id, msg_type, mtu, 0))
+}
+
+/// Split serialized bytes into frames, each carrying `flags`
+pub(crate) fn chunk_payload(
+    payload: &[u8],
+    route_id: &Uuid,
+    msg_type: u32,
+    mtu: usize,
+    flags: u32,
+) -> Vec<Frame> {
     // Keep serialized frame (header + payload) <= BLE_MAX_WRITE_BYTES (Android limit)
     let max_payload_per_frame = BLE_MAX_WRITE_BYTES.saturating_sub(BLE_FRAME_SERIALIZED_OVERHEAD);
     let chunk_size = (mtu - FRAME_OVERHEAD).min(max_payload_per_frame);
@@ -105,7 +163,7 @@ pub fn chunk_message(
             seq_no: seq as u32,
             total_seqs: total_chunks as u32,
             payload_len: chunk.len() as u32,
-            flags: 0,
+            flags,
             payload: chunk.to_vec(),
             crc32: crc,
         };
@@ -113,7 +171,7 @@ pub fn chunk_message(
         frames.push(frame);
     }
 
-    Ok(frames)
+    frames
 }
 
 /// Reassemble frames back into a message
@@ -121,6 +179,8 @@ pub struct FrameAssembler {
     frames: std::collections::HashMap<u32, Vec<u8>>,
     total_seqs: Option<u32>,
     route_id: Option<Uuid>,
+    flags: u32,
+    max_message_bytes: usize,
 }
 
 impl FrameAssembler {
@@ -129,9 +189,17 @@ impl FrameAssembler {
             frames: std::collections::HashMap::new(),
             total_seqs: None,
             route_id: None,
+            flags: 0,
+            max_message_bytes: MAX_MESSAGE_BYTES,
         }
     }
 
+    /// Refuse messages larger than `limit` bytes once assembled (and decompressed)
+    pub fn with_max_message_bytes(mut self, limit: usize) -> Self {
+        self.max_message_bytes = limit;
+        self
+    }
+
     /// Add a frame to the assembler
     pub fn add_frame(&mut self, frame: Frame) -> Result<()> {
         // Validate magic number
@@ -158,6 +226,7 @@ impl FrameAssembler {
                     .map_err(|e| DeviceError::InvalidFrame(e.to_string()))?,
             );
             self.total_seqs = Some(frame.total_seqs);
+            self.flags = frame.flags;
         }
 
         // Add payload
@@ -186,7 +255,7 @@ impl FrameAssembler {
         }
     }
 
-    /// Assemble the complete message
+    /// Assemble the complete message, decompressing it if the frames are flagged compressed
     pub fn assemble(&self) -> Result<Vec<u8>> {
         if !self.is_complete() {
             return Err(DeviceError::InvalidFrame(
@@ -205,6 +274,15 @@ impl FrameAssembler {
             }
         }
 
+        if self.flags & FLAG_COMPRESSED != 0 {
+            return decompress_payload(&result, self.max_message_bytes);
+        }
+        if result.len() > self.max_message_bytes {
+            return Err(DeviceError::MessageTooLarge {
+                size: result.len(),
+                limit: self.max_message_bytes,
+            });
+        }
         Ok(result)
     }
 
@@ -212,6 +290,7 @@ impl FrameAssembler {
         self.frames.clear();
         self.total_seqs = None;
         self.route_id = None;
+        self.flags = 0;
     }
 }
 
@@ -338,6 +417,11 @@ pub(crate) fn create_device_capabilities(
     screen_height: i32,
     battery_pct: i32,
     low_power_mode: bool,
+    supports_compression: bool,
+    prefers_raw_points: bool,
+    max_route_points: u32,
+    supports_acks: bool,
+    signing_key_id: u32,
 ) -> DeviceCapabilities {
     DeviceCapabilities {
         header: Some(create_header(1)),
@@ -349,6 +433,11 @@ pub(crate) fn create_device_capabilities(
         screen_height_px: screen_height,
         battery_level_pct: battery_pct,
         low_power_mode,
+        supports_compression,
+        prefers_raw_points,
+        max_route_points,
+        supports_acks,
+        signing_key_id,
     }
 }
 
@@ -411,6 +500,81 @@ mod tests {
         assert_eq!(reassembled, original);
     }
 
+    fn tile_message(len: usize) -> proto::Message {
+        proto::Message {
+            payload: Some(proto::message::Payload::TileChunk(TileChunk {
+                region_id: "r".to_string(),
+                z: 12,
+                x: 3,
+                y: 4,
+                data: (0..len).map(|i| (i % 16) as u8).collect(),
+            })),
+        }
+    }
+
+    #[test]
+    fn test_compressed_frames_reassemble() {
+        let route_id = Uuid::new_v4();
+        let original = serialize_proto_message(&tile_message(20_000)).unwrap();
+        let plain = chunk_payload(&original, &route_id, 1, DEFAULT_MTU, 0);
+        let compressed = chunk_payload(
+            &compress_payload(&original),
+            &route_id,
+            1,
+            DEFAULT_MTU,
+            FLAG_COMPRESSED,
+        );
+        assert!(compressed.len() * 4 < plain.len());
+
+        let mut assembler = FrameAssembler::new();
+        for frame in compressed {
+            assembler.add_frame(frame).unwrap();
+        }
+        assert_eq!(assembler.assemble().unwrap(), original);
+    }
+
+    #[test]
+    fn test_decompression_respects_size_limit() {
+        let route_id = Uuid::new_v4();
+        let original = serialize_proto_message(&tile_message(20_000)).unwrap();
+        let frames = chunk_payload(
+            &compress_payload(&original),
+            &route_id,
+            1,
+            DEFAULT_MTU,
+            FLAG_COMPRESSED,
+        );
+        let mut assembler = FrameAssembler::new().with_max_message_bytes(10_000);
+        for frame in frames {
+            assembler.add_frame(frame).unwrap();
+        }
+        assert!(matches!(
+            assembler.assemble(),
+            Err(DeviceError::MessageTooLarge { limit: 10_000, .. })
+        ));
+
+        // A forged size prefix is refused before anything is allocated.
+        let mut bomb = u32::MAX.to_le_bytes().to_vec();
+        bomb.extend_from_slice(&[0x1f, 0x00]);
+        assert!(matches!(
+            decompress_payload(&bomb, MAX_MESSAGE_BYTES),
+            Err(DeviceError::MessageTooLarge { .. })
+        ));
+        // So is a block that doesn't expand to the size it claims.
+        let mut short = compress_payload(&original);
+        short.truncate(short.len() / 2);
+        assert!(matches!(
+            decompress_payload(&short, MAX_MESSAGE_BYTES),
+            Err(DeviceError::Decompression(_))
+        ));
+        // Uncompressed messages are held to the same limit.
+        let mut assembler = FrameAssembler::new().with_max_message_bytes(10_000);
+        for frame in chunk_payload(&original, &route_id, 1, DEFAULT_MTU, 0) {
+            assembler.add_frame(frame).unwrap();
+        }
+        assert!(assembler.assemble().is_err());
+    }
+
     #[test]
     fn test_control_message_creation() {
         let control = create_control(
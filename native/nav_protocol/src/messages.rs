@@ -7,14 +7,22 @@ use prost::Message;
 use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+use crate::geometry::{encode_raw_points, simplify_to_budget, zoom_levels, RouteBlobOptions};
 use crate::proto;
-use crate::{chunk_message, create_header, FrameAssembler};
+use crate::proto::route_blob::PolylineData;
+use crate::{
+    chunk_message, chunk_payload, compress_payload, create_header, serialize_proto_message,
+    FrameAssembler, FLAG_COMPRESSED,
+};
 
 /// Build a RouteBlob from a Nav-IR Route. Uses first segment's geometry and flattens waypoints.
+/// The geometry goes as the encoded polyline, or as delta-encoded `RawPoints` (simplified to
+/// `max_points`) when `options` ask for them.
 /// Returns an error if the route fails validation.
 pub fn nav_ir_route_to_route_blob(
     route: &Route,
     header: proto::Header,
+    options: &RouteBlobOptions,
 ) -> Result<proto::RouteBlob> {
     route
         .validate()
@@ -50,14 +58,35 @@ pub fn nav_ir_route_to_route_blob(
         .map(|seg| seg.geometry.polyline.0.clone())
         .unwrap_or_default();
 
-    let mut hasher = Sha256::new();
-    hasher.update(encoded_polyline.as_bytes());
-    let checksum = hasher.finalize().to_vec();
+    let coords: Vec<nav_ir::Coordinate> = polyline::decode_polyline(&encoded_polyline, 5)
+        .map_err(|e| anyhow::anyhow!("Failed to decode route polyline: {}", e))?
+        .0
+        .into_iter()
+        .map(|c| nav_ir::Coordinate::new(c.y, c.x))
+        .collect();
+    let (zoom_hint, preferred_zoom) = zoom_levels(&coords);
+
+    let (polyline_data, total_points) = if options.raw_points {
+        let points = match options.max_points {
+            Some(budget) => simplify_to_budget(&coords, budget),
+            None => coords,
+        };
+        (
+            PolylineData::RawPoints(encode_raw_points(&points)),
+            points.len(),
+        )
+    } else {
+        (
+            PolylineData::EncodedPolyline(encoded_polyline),
+            coords.len(),
+        )
+    };
+    let checksum = geometry_checksum(Some(&polyline_data));
 
     let metadata = Some(proto::Metadata {
-        zoom_hint: 0,
-        preferred_zoom: 0,
-        total_points: 0,
+        zoom_hint,
+        preferred_zoom,
+        total_points: total_points as u32,
         route_name: route.metadata.name.clone(),
         created_at_ms: route.metadata.created_at.timestamp_millis() as u64,
     });
@@ -99,9 +128,7 @@ pub fn nav_ir_route_to_route_blob(
         route_id: route_id_bytes,
         waypoints: proto_waypoints,
         legs,
-        polyline_data: Some(proto::route_blob::PolylineData::EncodedPolyline(
-            encoded_polyline,
-        )),
+        polyline_data: Some(polyline_data),
         metadata,
         compressed: false,
         checksum,
@@ -109,11 +136,95 @@ pub fn nav_ir_route_to_route_blob(
     })
 }
 
+/// SHA256 of the (uncompressed) geometry, as carried in `RouteBlob.checksum`: over the encoded
+/// polyline's bytes, or over the `RawPoints` as carried (lat_e5 and lon_e5 deltas, i32
+/// big-endian, point by point).
+fn geometry_checksum(polyline_data: Option<&PolylineData>) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    match polyline_data {
+        Some(PolylineData::EncodedPolyline(polyline)) => hasher.update(polyline.as_bytes()),
+        Some(PolylineData::RawPoints(raw)) => {
+            for point in &raw.points {
+                hasher.update(point.lat_e5.to_be_bytes());
+                hasher.update(point.lon_e5.to_be_bytes());
+            }
+        }
+        None => {}
+    }
+    hasher.finalize().to_vec()
+}
+
+/// Check `RouteBlob.checksum` against the blob's geometry. Run it on the reassembled,
+/// decompressed blob: the checksum is over the uncompressed data.
+pub fn verify_route_blob_checksum(blob: &proto::RouteBlob) -> Result<()> {
+    if geometry_checksum(blob.polyline_data.as_ref()) != blob.checksum {
+        bail!("RouteBlob checksum mismatch");
+    }
+    Ok(())
+}
+
+/// Chunk a RouteBlob into frames. With `compress` (the device announced
+/// `supports_compression`), the message is LZ4-compressed and the frames flagged
+/// `FLAG_COMPRESSED`, unless that doesn't make it smaller.
+pub fn chunk_route_blob(
+    blob: proto::RouteBlob,
+    mtu: usize,
+    compress: bool,
+) -> Result<Vec<proto::Frame>> {
+    let route_id = Uuid::from_slice(&blob.route_id).context("Invalid route UUID in RouteBlob")?;
+    let plain = route_blob_message_bytes(&blob)?;
+    if compress {
+        let compressed = compress_payload(&plain);
+        if compressed.len() < plain.len() {
+            return Ok(chunk_payload(
+                &compressed,
+                &route_id,
+                1,
+                mtu,
+                FLAG_COMPRESSED,
+            ));
+        }
+    }
+    Ok(chunk_payload(&plain, &route_id, 1, mtu, 0))
+}
+
+fn route_blob_message_bytes(blob: &proto::RouteBlob) -> Result<Vec<u8>> {
+    let message = proto::Message {
+        payload: Some(proto::message::Payload::RouteBlob(blob.clone())),
+    };
+    serialize_proto_message(&message).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
 /// Prepare a route message for sending to a device.
 /// Parses route JSON (waypoints, polyline, distance_m, duration_s) into Nav-IR, then RouteBlob, then serialized bytes.
 pub fn prepare_route_message(route_json: String) -> Result<Vec<u8>> {
+    prepare_route_message_with(route_json, &RouteBlobOptions::default())
+}
+
+/// `prepare_route_message` with the geometry encoded as `options` ask.
+pub fn prepare_route_message_with(
+    route_json: String,
+    options: &RouteBlobOptions,
+) -> Result<Vec<u8>> {
+    let nav_ir_route = route_json_to_nav_ir(&route_json)?;
+    let header = create_header(1);
+    let route_blob = nav_ir_route_to_route_blob(&nav_ir_route, header, options)?;
+
+    let message = proto::Message {
+        payload: Some(proto::message::Payload::RouteBlob(route_blob)),
+    };
+
+    let mut buf = Vec::new();
+    message
+        .encode(&mut buf)
+        .context("Failed to encode protobuf message")?;
+    Ok(buf)
+}
+
+/// Parse route JSON (waypoints, polyline, distance_m, duration_s) into Nav-IR.
+pub fn route_json_to_nav_ir(route_json: &str) -> Result<nav_ir::Route> {
     let route_value: serde_json::Value =
-        serde_json::from_str(&route_json).context("Failed to parse route JSON")?;
+        serde_json::from_str(route_json).context("Failed to parse route JSON")?;
 
     let waypoints_arr = route_value["waypoints"]
         .as_array()
@@ -150,26 +261,13 @@ pub fn prepare_route_message(route_json: String) -> Result<Vec<u8>> {
     let distance_m = route_value["distance_m"].as_f64().unwrap_or(0.0);
     let duration_s = route_value["duration_s"].as_f64().unwrap_or(0.0) as u64;
 
-    let nav_ir_route = nav_ir::normalize_custom(
+    nav_ir::normalize_custom(
         &waypoints,
         &encoded_polyline,
         Some(distance_m),
         Some(duration_s),
     )
-    .map_err(|e| anyhow::anyhow!("Route normalization failed: {}", e))?;
-
-    let header = create_header(1);
-    let route_blob = nav_ir_route_to_route_blob(&nav_ir_route, header)?;
-
-    let message = proto::Message {
-        payload: Some(proto::message::Payload::RouteBlob(route_blob)),
-    };
-
-    let mut buf = Vec::new();
-    message
-        .encode(&mut buf)
-        .context("Failed to encode protobuf message")?;
-    Ok(buf)
+    .map_err(|e| anyhow::anyhow!("Route normalization failed: {}", e))
 }
 
 /// Chunk a protobuf message into BLE frames. Returns a vector of serialized frame bytes.
@@ -289,6 +387,9 @@ pub fn reassemble_frames(frame_bytes: Vec<Vec<u8>>) -> Result<Vec<u8>> {
         .map_err(|e| anyhow::anyhow!("{}", e))?;
     let message = proto::Message::decode(&message_bytes[..])
         .context("Failed to decode reassembled message")?;
+    if let Some(proto::message::Payload::RouteBlob(blob)) = &message.payload {
+        verify_route_blob_checksum(blob)?;
+    }
     let mut buf = Vec::new();
     message
         .encode(&mut buf)
@@ -345,9 +446,8 @@ mod tests {
         WaypointId, WaypointKind,
     };
 
-    #[test]
-    fn nav_ir_to_route_blob_roundtrip() {
-        let route = Route {
+    fn route(polyline: &str) -> Route {
+        Route {
             schema_version: Route::CURRENT_SCHEMA_VERSION,
             id: nav_ir::RouteId::new(),
             metadata: RouteMetadata {
@@ -364,7 +464,7 @@ mod tests {
                 id: nav_ir::SegmentId::new(),
                 intent: SegmentIntent::Recalculatable,
                 geometry: RouteGeometry {
-                    polyline: EncodedPolyline("_p~iF~ps|U".into()),
+                    polyline: EncodedPolyline(polyline.into()),
                     source: GeometrySource::SnappedToGraph,
                     confidence: GeometryConfidence::High,
                     bounding_box: BoundingBox {
@@ -401,12 +501,20 @@ mod tests {
                 legs: vec![],
                 instructions: vec![],
                 constraints: SegmentConstraints::default(),
+                street_names: vec![],
+                road_spans: vec![],
             }],
             policies: RoutePolicies::default(),
-        };
+        }
+    }
+
+    #[test]
+    fn nav_ir_to_route_blob_roundtrip() {
+        let route = route("_p~iF~ps|U");
 
         let header = create_header(1);
-        let blob = nav_ir_route_to_route_blob(&route, header).unwrap();
+        let blob =
+            nav_ir_route_to_route_blob(&route, header, &RouteBlobOptions::default()).unwrap();
 
         assert_eq!(blob.route_id.len(), 16);
         assert_eq!(blob.waypoints.len(), 2);
@@ -419,5 +527,98 @@ mod tests {
             Some(proto::route_blob::PolylineData::EncodedPolyline(s)) if s == "_p~iF~ps|U"
         ));
         assert!(!blob.checksum.is_empty());
+        assert_eq!(blob.metadata.unwrap().total_points, 1);
+    }
+
+    fn wiggly_polyline() -> String {
+        let coords: Vec<geo_types::Coord<f64>> = (0..400)
+            .map(|i| geo_types::Coord {
+                x: -74.0 + i as f64 * 0.0005,
+                y: 40.7 + (i % 20) as f64 * 0.0001,
+            })
+            .collect();
+        polyline::encode_coordinates(coords, 5).unwrap()
+    }
+
+    #[test]
+    fn route_blob_carries_raw_points_when_asked() {
+        let route = route(&wiggly_polyline());
+        let polyline_blob =
+            nav_ir_route_to_route_blob(&route, create_header(1), &RouteBlobOptions::default())
+                .unwrap();
+        let meta = polyline_blob.metadata.clone().unwrap();
+        assert_eq!(meta.total_points, 400);
+        assert!(meta.zoom_hint > 0 && meta.preferred_zoom >= meta.zoom_hint);
+
+        let options = RouteBlobOptions {
+            raw_points: true,
+            // The sawtooth has ~40 corners; fewer points must drop some of them.
+            max_points: Some(30),
+        };
+        let blob = nav_ir_route_to_route_blob(&route, create_header(1), &options).unwrap();
+        let Some(PolylineData::RawPoints(raw)) = &blob.polyline_data else {
+            panic!("expected RawPoints");
+        };
+        assert_eq!(raw.points.len(), 30);
+        assert_eq!(blob.metadata.as_ref().unwrap().total_points, 30);
+        assert_eq!(
+            blob.metadata.as_ref().unwrap().zoom_hint,
+            meta.zoom_hint,
+            "zoom levels come from the full geometry"
+        );
+        verify_route_blob_checksum(&blob).unwrap();
+
+        let points = crate::decode_raw_points(raw);
+        assert!((points[0].latitude - 40.7).abs() < 1e-5);
+        assert!((points[29].longitude - (-74.0 + 399.0 * 0.0005)).abs() < 1e-5);
+
+        let mut tampered = blob.clone();
+        if let Some(PolylineData::RawPoints(raw)) = &mut tampered.polyline_data {
+            raw.points[10].lat_e5 += 1;
+        }
+        assert!(verify_route_blob_checksum(&tampered).is_err());
+
+        // Without a budget every point goes.
+        let all = RouteBlobOptions {
+            raw_points: true,
+            max_points: None,
+        };
+        let blob = nav_ir_route_to_route_blob(&route, create_header(1), &all).unwrap();
+        assert_eq!(blob.metadata.unwrap().total_points, 400);
+    }
+
+    #[test]
+    fn route_blob_is_compressed_only_when_asked_and_smaller() {
+        let blob = nav_ir_route_to_route_blob(
+            &route(&wiggly_polyline()),
+            create_header(1),
+            &RouteBlobOptions::default(),
+        )
+        .unwrap();
+
+        let plain = chunk_route_blob(blob.clone(), crate::DEFAULT_MTU, false).unwrap();
+        let compressed = chunk_route_blob(blob.clone(), crate::DEFAULT_MTU, true).unwrap();
+        assert!(plain.iter().all(|f| f.flags == 0));
+        assert!(compressed.iter().all(|f| f.flags == FLAG_COMPRESSED));
+        assert!(compressed.len() < plain.len());
+
+        let bytes = compressed.iter().map(|f| f.encode_to_vec()).collect();
+        let message = proto::Message::decode(&reassemble_frames(bytes).unwrap()[..]).unwrap();
+        let Some(proto::message::Payload::RouteBlob(received)) = message.payload else {
+            panic!("expected a RouteBlob");
+        };
+        assert_eq!(received, blob);
+        assert_eq!(received.checksum, blob.checksum);
+        verify_route_blob_checksum(&received).unwrap();
+
+        // A tiny blob doesn't shrink, so it goes uncompressed.
+        let tiny = nav_ir_route_to_route_blob(
+            &route("_p~iF~ps|U"),
+            create_header(1),
+            &RouteBlobOptions::default(),
+        )
+        .unwrap();
+        let frames = chunk_route_blob(tiny, crate::DEFAULT_MTU, true).unwrap();
+        assert!(frames.iter().all(|f| f.flags == 0));
     }
 }
@@ -0,0 +1,475 @@
+//! Reliable frame transfer over a lossy link.
+//!
+//! `ReliableSender` keeps a sliding window of frames in flight and retransmits them on NACK
+//! or when no ACK arrives in time; `ReliableReceiver` feeds frames into a `FrameAssembler`
+//! and answers with `Control` ACK/NACK messages. Neither side does I/O: the caller moves
+//! frames and controls over its transport and polls the sender so timeouts fire.
+//!
+//! On the wire, a `Control` refers to a frame by `route_id` and `seq_no`. ACK means that
+//! frame arrived intact; NACK means it arrived corrupted or was skipped. The link is assumed
+//! to keep frames in order (BLE does), so a frame arriving past a gap NACKs the gap.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::proto::{Control, ControlType, Frame};
+use crate::{create_header, DeviceError, FrameAssembler, Result};
+
+/// Source of time for transfer timeouts, in milliseconds.
+pub trait Clock: Send + Sync {
+    fn now_ms(&self) -> u64;
+}
+
+/// Wall-clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        chrono::Utc::now().timestamp_millis() as u64
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock(Arc<AtomicU64>);
+
+impl ManualClock {
+    pub fn new(start_ms: u64) -> Self {
+        Self(Arc::new(AtomicU64::new(start_ms)))
+    }
+
+    pub fn advance(&self, ms: u64) {
+        self.0.fetch_add(ms, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Tuning for `ReliableSender`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReliableConfig {
+    /// Frames that may be unacknowledged at once, counted from the oldest unacknowledged one.
+    pub window: u32,
+    /// Time to wait for an ACK before sending a frame again.
+    pub ack_timeout_ms: u64,
+    /// Resends allowed per frame before the transfer fails.
+    pub max_retries: u32,
+}
+
+impl Default for ReliableConfig {
+    fn default() -> Self {
+        Self {
+            window: 8,
+            ack_timeout_ms: 1500,
+            max_retries: 5,
+        }
+    }
+}
+
+/// Why a transfer was given up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferFailure {
+    /// The frame was never acknowledged.
+    Timeout { seq_no: u32 },
+    /// The receiver kept reporting the frame as missing or corrupted.
+    Rejected { seq_no: u32 },
+}
+
+impl From<TransferFailure> for DeviceError {
+    fn from(failure: TransferFailure) -> Self {
+        match failure {
+            TransferFailure::Timeout { .. } => DeviceError::Timeout,
+            TransferFailure::Rejected { seq_no } => DeviceError::MissingSequence(seq_no),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    InProgress,
+    /// Every frame was acknowledged.
+    Complete,
+    Failed(TransferFailure),
+}
+
+#[derive(Debug)]
+struct Slot {
+    frame: Frame,
+    /// Time of the last send; `None` until first sent.
+    sent_at_ms: Option<u64>,
+    sends: u32,
+    acked: bool,
+    nacked: bool,
+}
+
+/// Sending side of one transfer: the frames of a single message, as made by `chunk_message`.
+pub struct ReliableSender {
+    route_id: Vec<u8>,
+    slots: Vec<Slot>,
+    /// Oldest unacknowledged frame; the window starts here.
+    base: usize,
+    config: ReliableConfig,
+    clock: Arc<dyn Clock>,
+    status: TransferStatus,
+    retransmits: u32,
+}
+
+impl ReliableSender {
+    pub fn new(frames: Vec<Frame>, config: ReliableConfig, clock: Arc<dyn Clock>) -> Self {
+        let route_id = frames
+            .first()
+            .map(|f| f.route_id.clone())
+            .unwrap_or_default();
+        let status = if frames.is_empty() {
+            TransferStatus::Complete
+        } else {
+            TransferStatus::InProgress
+        };
+        Self {
+            route_id,
+            slots: frames
+                .into_iter()
+                .map(|frame| Slot {
+                    frame,
+                    sent_at_ms: None,
+                    sends: 0,
+                    acked: false,
+                    nacked: false,
+                })
+                .collect(),
+            base: 0,
+            config,
+            clock,
+            status,
+            retransmits: 0,
+        }
+    }
+
+    pub fn route_id(&self) -> &[u8] {
+        &self.route_id
+    }
+
+    pub fn status(&self) -> TransferStatus {
+        self.status
+    }
+
+    /// Frames sent again after a NACK or timeout.
+    pub fn retransmits(&self) -> u32 {
+        self.retransmits
+    }
+
+    /// Frames to send now: new frames that fit in the window, NACKed frames and frames
+    /// whose ACK timed out. Fails once a frame needs more than `max_retries` resends.
+    pub fn poll(&mut self) -> Result<Vec<Frame>> {
+        match self.status {
+            TransferStatus::Complete => return Ok(vec![]),
+            TransferStatus::Failed(failure) => return Err(failure.into()),
+            TransferStatus::InProgress => {}
+        }
+        let now_ms = self.clock.now_ms();
+        let end = (self.base + self.config.window.max(1) as usize).min(self.slots.len());
+        let mut out = Vec::new();
+        for slot in &mut self.slots[self.base..end] {
+            let due = match slot.sent_at_ms {
+                _ if slot.acked => false,
+                None => true,
+                Some(sent_ms) => {
+                    slot.nacked || now_ms.saturating_sub(sent_ms) >= self.config.ack_timeout_ms
+                }
+            };
+            if !due {
+                continue;
+            }
+            if slot.sends > self.config.max_retries {
+                let seq_no = slot.frame.seq_no;
+                let failure = if slot.nacked {
+                    TransferFailure::Rejected { seq_no }
+                } else {
+                    TransferFailure::Timeout { seq_no }
+                };
+                self.status = TransferStatus::Failed(failure);
+                return Err(failure.into());
+            }
+            if slot.sends > 0 {
+                self.retransmits += 1;
+            }
+            slot.sends += 1;
+            slot.sent_at_ms = Some(now_ms);
+            slot.nacked = false;
+            out.push(slot.frame.clone());
+        }
+        Ok(out)
+    }
+
+    /// Apply an ACK or NACK from the receiver. Returns false for controls that don't belong
+    /// to this transfer.
+    pub fn on_control(&mut self, control: &Control) -> bool {
+        if control.route_id != self.route_id {
+            return false;
+        }
+        let kind = ControlType::try_from(control.r#type);
+        if !matches!(kind, Ok(ControlType::Ack | ControlType::Nack)) {
+            return false;
+        }
+        if self.status != TransferStatus::InProgress {
+            return true;
+        }
+        let Some(slot) = self.slots.get_mut(control.seq_no as usize) else {
+            return true;
+        };
+        if kind == Ok(ControlType::Ack) {
+            slot.acked = true;
+            while self.slots.get(self.base).is_some_and(|s| s.acked) {
+                self.base += 1;
+            }
+            if self.base == self.slots.len() {
+                self.status = TransferStatus::Complete;
+            }
+        } else if !slot.acked && slot.sent_at_ms.is_some() {
+            slot.nacked = true;
+        }
+        true
+    }
+}
+
+/// Receiving side: collects the frames of one message and says which arrived.
+///
+/// A frame of another transfer (a different `route_id`) starts over with that transfer.
+#[derive(Default)]
+pub struct ReliableReceiver {
+    assembler: FrameAssembler,
+    route_id: Option<Vec<u8>>,
+    /// One past the highest sequence number seen.
+    next_seq: u32,
+}
+
+impl ReliableReceiver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take in a frame and return the controls to send back: an ACK for the frame, or a NACK
+    /// if it is corrupted, plus a NACK for each frame skipped before it.
+    pub fn on_frame(&mut self, frame: Frame) -> Result<Vec<Control>> {
+        if frame.seq_no >= frame.total_seqs {
+            return Err(DeviceError::InvalidFrame(format!(
+                "Sequence {} out of range (total {})",
+                frame.seq_no, frame.total_seqs
+            )));
+        }
+        if self.route_id.as_ref() != Some(&frame.route_id) {
+            self.assembler.reset();
+            self.route_id = Some(frame.route_id.clone());
+            self.next_seq = 0;
+        }
+        let route_id = frame.route_id.clone();
+        let seq_no = frame.seq_no;
+        match self.assembler.add_frame(frame) {
+            Ok(()) => {}
+            Err(DeviceError::CrcMismatch { .. }) => {
+                return Ok(vec![control(ControlType::Nack, &route_id, seq_no)]);
+            }
+            Err(e) => return Err(e),
+        }
+
+        let mut controls = vec![control(ControlType::Ack, &route_id, seq_no)];
+        if seq_no > self.next_seq {
+            let missing = self.assembler.missing_sequences();
+            controls.extend(
+                missing
+                    .into_iter()
+                    .filter(|seq| (self.next_seq..seq_no).contains(seq))
+                    .map(|seq| control(ControlType::Nack, &route_id, seq)),
+            );
+        }
+        self.next_seq = self.next_seq.max(seq_no + 1);
+        Ok(controls)
+    }
+
+    pub fn route_id(&self) -> Option<Uuid> {
+        self.route_id
+            .as_ref()
+            .and_then(|id| Uuid::from_slice(id).ok())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.assembler.is_complete()
+    }
+
+    /// The message payload, once every frame has arrived.
+    pub fn assemble(&self) -> Result<Vec<u8>> {
+        self.assembler.assemble()
+    }
+}
+
+fn control(kind: ControlType, route_id: &[u8], seq_no: u32) -> Control {
+    Control {
+        header: Some(create_header(1)),
+        r#type: kind as i32,
+        route_id: route_id.to_vec(),
+        status_code: 0,
+        message_text: String::new(),
+        seq_no,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chunk_message, proto, serialize_proto_message, DEFAULT_MTU};
+
+    /// Drops items pseudo-randomly, reproducibly.
+    struct LossyLink {
+        state: u64,
+        loss: f64,
+    }
+
+    impl LossyLink {
+        fn new(seed: u64, loss: f64) -> Self {
+            Self { state: seed, loss }
+        }
+
+        fn pass<T>(&mut self, items: Vec<T>) -> Vec<T> {
+            items
+                .into_iter()
+                .filter(|_| {
+                    self.state = self
+                        .state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    ((self.state >> 33) as f64 / (1u64 << 31) as f64) >= self.loss
+                })
+                .collect()
+        }
+    }
+
+    fn message(len: usize) -> proto::Message {
+        proto::Message {
+            payload: Some(proto::message::Payload::TileChunk(proto::TileChunk {
+                region_id: "r".into(),
+                z: 10,
+                x: 1,
+                y: 2,
+                data: (0..len).map(|i| (i % 251) as u8).collect(),
+            })),
+        }
+    }
+
+    fn frames(len: usize) -> Vec<Frame> {
+        chunk_message(&message(len), &Uuid::new_v4(), 1, DEFAULT_MTU).unwrap()
+    }
+
+    #[test]
+    fn lossy_link_delivers_the_whole_message() {
+        let clock = ManualClock::new(0);
+        let config = ReliableConfig::default();
+        let mut sender = ReliableSender::new(frames(6_000), config, Arc::new(clock.clone()));
+        let mut receiver = ReliableReceiver::new();
+        let mut out_link = LossyLink::new(7, 0.2);
+        let mut back_link = LossyLink::new(11, 0.2);
+
+        for _ in 0..1_000 {
+            let sent = sender.poll().unwrap();
+            let mut controls = Vec::new();
+            for frame in out_link.pass(sent) {
+                controls.extend(receiver.on_frame(frame).unwrap());
+            }
+            for control in back_link.pass(controls) {
+                assert!(sender.on_control(&control));
+            }
+            if sender.status() != TransferStatus::InProgress {
+                break;
+            }
+            clock.advance(250);
+        }
+
+        assert_eq!(sender.status(), TransferStatus::Complete);
+        assert!(sender.retransmits() > 0);
+        assert_eq!(
+            receiver.assemble().unwrap(),
+            serialize_proto_message(&message(6_000)).unwrap()
+        );
+    }
+
+    #[test]
+    fn nack_resends_only_the_skipped_frame_within_the_window() {
+        let clock = ManualClock::new(0);
+        let config = ReliableConfig {
+            window: 4,
+            ..ReliableConfig::default()
+        };
+        let mut sender = ReliableSender::new(frames(3_000), config, Arc::new(clock.clone()));
+        let mut receiver = ReliableReceiver::new();
+
+        let first = sender.poll().unwrap();
+        assert_eq!(first.len(), 4);
+        assert!(sender.poll().unwrap().is_empty());
+
+        let mut controls = Vec::new();
+        for frame in first.into_iter().filter(|f| f.seq_no != 1) {
+            controls.extend(receiver.on_frame(frame).unwrap());
+        }
+        let nacks: Vec<u32> = controls
+            .iter()
+            .filter(|c| c.r#type == ControlType::Nack as i32)
+            .map(|c| c.seq_no)
+            .collect();
+        assert_eq!(nacks, vec![1]);
+        for control in &controls {
+            sender.on_control(control);
+        }
+
+        // Frame 0 is acknowledged, so the window slides by one; frame 1 goes again at once.
+        clock.advance(10);
+        let seqs: Vec<u32> = sender.poll().unwrap().iter().map(|f| f.seq_no).collect();
+        assert_eq!(seqs, vec![1, 4]);
+        assert_eq!(sender.retransmits(), 1);
+    }
+
+    #[test]
+    fn dead_link_times_out_after_the_retry_limit() {
+        let clock = ManualClock::new(0);
+        let config = ReliableConfig {
+            window: 2,
+            ack_timeout_ms: 1_000,
+            max_retries: 3,
+        };
+        let mut sender = ReliableSender::new(frames(1_000), config, Arc::new(clock.clone()));
+
+        let mut sends_of_first = 0;
+        let result = loop {
+            match sender.poll() {
+                Ok(sent) => sends_of_first += sent.iter().filter(|f| f.seq_no == 0).count(),
+                Err(e) => break e,
+            }
+            clock.advance(500);
+        };
+        assert!(matches!(result, DeviceError::Timeout));
+        assert_eq!(sends_of_first, 4);
+        assert_eq!(clock.now_ms(), 4_000);
+        assert_eq!(
+            sender.status(),
+            TransferStatus::Failed(TransferFailure::Timeout { seq_no: 0 })
+        );
+        assert!(sender.poll().is_err());
+    }
+
+    #[test]
+    fn corrupted_frame_is_nacked() {
+        let mut receiver = ReliableReceiver::new();
+        let mut frames = frames(1_000);
+        frames[0].payload[0] ^= 0xff;
+        let controls = receiver.on_frame(frames[0].clone()).unwrap();
+        assert_eq!(controls.len(), 1);
+        assert_eq!(controls[0].r#type, ControlType::Nack as i32);
+        assert_eq!(controls[0].seq_no, 0);
+        assert!(!receiver.is_complete());
+    }
+}
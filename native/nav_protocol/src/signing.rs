@@ -0,0 +1,221 @@
+//! HMAC-SHA256 signatures on RouteBlobs.
+//!
+//! The phone signs each blob with the key it shares with the device; the device checks the
+//! signature before accepting the route, so a phone without the key can't push routes to it.
+//! Keys carry a `key_id` so the device can hold the old and new key while a rotation settles.
+//!
+//! The HMAC is computed over `canonical_route_blob_bytes`, not over the protobuf encoding,
+//! which other protobuf libraries needn't reproduce byte for byte.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::proto::{route_blob::PolylineData, RouteBlob, Signature};
+use crate::{DeviceError, Result};
+
+/// Domain separation prefix of the canonical encoding; bump on layout changes.
+const CANONICAL_PREFIX: &[u8] = b"NAVE-ROUTEBLOB-1";
+
+/// A shared secret for signing RouteBlobs, identified by `key_id`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SigningKey {
+    pub key_id: u32,
+    pub secret: Vec<u8>,
+}
+
+impl std::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The bytes a RouteBlob signature covers, for `key_id`.
+///
+/// Fields follow in proto tag order: integers as fixed-width big-endian, doubles as their
+/// IEEE-754 bits, strings and bytes as a u32 length then the bytes, repeated fields as a u32
+/// count then the items, and a message field as a 0/1 presence byte then its fields.
+/// `polyline_data` is a byte (0 none, 5 encoded polyline, 6 raw points) then its value.
+/// `compressed` (unused; compression is a frame flag) and `signature` are left out.
+pub fn canonical_route_blob_bytes(blob: &RouteBlob, key_id: u32) -> Vec<u8> {
+    let mut out = CANONICAL_PREFIX.to_vec();
+    out.extend(key_id.to_be_bytes());
+
+    match &blob.header {
+        Some(header) => {
+            out.push(1);
+            out.extend(header.protocol_version.to_be_bytes());
+            out.extend(header.message_version.to_be_bytes());
+        }
+        None => out.push(0),
+    }
+    put_bytes(&mut out, &blob.route_id);
+
+    out.extend((blob.waypoints.len() as u32).to_be_bytes());
+    for wp in &blob.waypoints {
+        out.extend(wp.lat.to_bits().to_be_bytes());
+        out.extend(wp.lon.to_bits().to_be_bytes());
+        put_bytes(&mut out, wp.name.as_bytes());
+        out.extend(wp.index.to_be_bytes());
+    }
+
+    out.extend((blob.legs.len() as u32).to_be_bytes());
+    for leg in &blob.legs {
+        out.extend(leg.distance_m.to_be_bytes());
+        out.extend(leg.duration_s.to_be_bytes());
+        put_bytes(&mut out, leg.summary.as_bytes());
+        out.extend((leg.steps.len() as u32).to_be_bytes());
+        for step in &leg.steps {
+            put_bytes(&mut out, step.instruction.as_bytes());
+            out.extend(step.distance_m.to_be_bytes());
+            out.extend(step.duration_s.to_be_bytes());
+            out.extend(step.start_lat.to_bits().to_be_bytes());
+            out.extend(step.start_lon.to_bits().to_be_bytes());
+            out.extend(step.bearing_deg.to_be_bytes());
+            put_bytes(&mut out, step.maneuver_type.as_bytes());
+        }
+    }
+
+    match &blob.polyline_data {
+        None => out.push(0),
+        Some(PolylineData::EncodedPolyline(polyline)) => {
+            out.push(5);
+            put_bytes(&mut out, polyline.as_bytes());
+        }
+        Some(PolylineData::RawPoints(raw)) => {
+            out.push(6);
+            out.extend((raw.points.len() as u32).to_be_bytes());
+            for point in &raw.points {
+                out.extend(point.lat_e5.to_be_bytes());
+                out.extend(point.lon_e5.to_be_bytes());
+            }
+        }
+    }
+
+    match &blob.metadata {
+        Some(meta) => {
+            out.push(1);
+            out.extend(meta.zoom_hint.to_be_bytes());
+            out.extend(meta.preferred_zoom.to_be_bytes());
+            out.extend(meta.total_points.to_be_bytes());
+            put_bytes(&mut out, meta.route_name.as_bytes());
+            out.extend(meta.created_at_ms.to_be_bytes());
+        }
+        None => out.push(0),
+    }
+    put_bytes(&mut out, &blob.checksum);
+    out
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend((bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+fn mac(key: &SigningKey) -> Hmac<Sha256> {
+    Hmac::<Sha256>::new_from_slice(&key.secret).expect("HMAC accepts any key length")
+}
+
+/// Set `blob.signature` to the HMAC-SHA256 of the blob under `key`.
+pub fn sign_route_blob(blob: &mut RouteBlob, key: &SigningKey) {
+    let mut mac = mac(key);
+    mac.update(&canonical_route_blob_bytes(blob, key.key_id));
+    blob.signature = Some(Signature {
+        key_id: key.key_id,
+        hmac: mac.finalize().into_bytes().to_vec(),
+    });
+}
+
+/// Check a blob's signature against the device's keys, picking the key by `key_id`.
+/// Fails for unsigned blobs, unknown key ids and signatures that don't match.
+pub fn verify_route_blob_signature(blob: &RouteBlob, keys: &[SigningKey]) -> Result<()> {
+    let signature = blob
+        .signature
+        .as_ref()
+        .ok_or_else(|| DeviceError::InvalidSignature("RouteBlob is not signed".to_string()))?;
+    let key = keys
+        .iter()
+        .find(|k| k.key_id == signature.key_id)
+        .ok_or_else(|| {
+            DeviceError::InvalidSignature(format!("Unknown key id {}", signature.key_id))
+        })?;
+    let mut mac = mac(key);
+    mac.update(&canonical_route_blob_bytes(blob, key.key_id));
+    mac.verify_slice(&signature.hmac)
+        .map_err(|_| DeviceError::InvalidSignature("HMAC mismatch".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proto;
+
+    fn blob() -> RouteBlob {
+        RouteBlob {
+            header: Some(crate::create_header(1)),
+            route_id: vec![7; 16],
+            waypoints: vec![proto::Waypoint {
+                lat: 52.37,
+                lon: 4.9,
+                name: "Start".into(),
+                index: 0,
+            }],
+            legs: vec![],
+            polyline_data: Some(PolylineData::EncodedPolyline("_p~iF~ps|U".into())),
+            metadata: None,
+            compressed: false,
+            checksum: vec![1, 2, 3],
+            signature: None,
+        }
+    }
+
+    fn key(key_id: u32, byte: u8) -> SigningKey {
+        SigningKey {
+            key_id,
+            secret: vec![byte; 32],
+        }
+    }
+
+    #[test]
+    fn signed_blob_verifies_and_tampering_is_caught() {
+        let mut blob = blob();
+        sign_route_blob(&mut blob, &key(1, 0xaa));
+        verify_route_blob_signature(&blob, &[key(1, 0xaa)]).unwrap();
+
+        let mut moved = blob.clone();
+        moved.waypoints[0].lat += 0.001;
+        assert!(verify_route_blob_signature(&moved, &[key(1, 0xaa)]).is_err());
+
+        // A phone with another secret can't produce a valid signature for key 1.
+        let mut rogue = self::blob();
+        sign_route_blob(&mut rogue, &key(1, 0xbb));
+        assert!(verify_route_blob_signature(&rogue, &[key(1, 0xaa)]).is_err());
+
+        let mut unsigned = blob.clone();
+        unsigned.signature = None;
+        assert!(verify_route_blob_signature(&unsigned, &[key(1, 0xaa)]).is_err());
+    }
+
+    #[test]
+    fn rotation_picks_the_key_by_id() {
+        let keys = [key(1, 0xaa), key(2, 0xcc)];
+        let mut old = blob();
+        sign_route_blob(&mut old, &key(1, 0xaa));
+        let mut new = blob();
+        sign_route_blob(&mut new, &key(2, 0xcc));
+        verify_route_blob_signature(&old, &keys).unwrap();
+        verify_route_blob_signature(&new, &keys).unwrap();
+
+        // Once key 1 is retired, blobs signed with it are refused.
+        assert!(matches!(
+            verify_route_blob_signature(&old, &keys[1..]),
+            Err(DeviceError::InvalidSignature(_))
+        ));
+
+        // The key id is part of what is signed: relabelling a signature doesn't pass.
+        let mut relabelled = new.clone();
+        relabelled.signature.as_mut().unwrap().key_id = 1;
+        assert!(verify_route_blob_signature(&relabelled, &[key(1, 0xcc)]).is_err());
+    }
+}
@@ -30,7 +30,7 @@ impl nav_core::RouteService for OsrmRouteService {
         let coords_str = coords.join(";");
 
         let url = format!(
-            "{}/route/v1/driving/{}?overview=full&geometries=polyline",
+            "{}/route/v1/driving/{}?overview=full&geometries=polyline&steps=true",
             self.base_url, coords_str
         );
 
@@ -0,0 +1,115 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:flutter_test/flutter_test.dart';
+import 'package:mocktail/mocktail.dart';
+import 'package:nav_e/bridge/ffi_models.dart';
+import 'package:nav_e/bridge/frb_generated.dart';
+import 'package:nav_e/core/device_comm/device_comm_transport.dart';
+import 'package:nav_e/core/device_comm/device_communication_service.dart';
+import '../../helpers/mocks.dart';
+
+/// Records the frames written to each device.
+class FakeTransport implements DeviceCommTransport {
+  final written = <(String, Uint8List)>[];
+  final _incoming = StreamController<DeviceMessage>.broadcast();
+
+  @override
+  Stream<DeviceMessage> get messageStream => _incoming.stream;
+
+  @override
+  Future<List<ConnectedDeviceInfo>> getConnectedDeviceIds() async => const [];
+
+  @override
+  Future<int> getMtu(String deviceId) async => 247;
+
+  @override
+  Future<void> sendFrames(
+    String deviceId,
+    List<Uint8List> frames, {
+    void Function(double progress)? onProgress,
+  }) async {
+    for (final frame in frames) {
+      written.add((deviceId, frame));
+    }
+  }
+
+  @override
+  void dispose() => _incoming.close();
+}
+
+void main() {
+  group('DeviceCommunicationService', () {
+    final rust = MockRustBridgeApi();
+    late StreamController<DeviceMessageDto> outgoing;
+    late FakeTransport transport;
+    late DeviceCommunicationService service;
+
+    setUpAll(() => RustBridge.initMock(api: rust));
+
+    setUp(() {
+      reset(rust);
+      outgoing = StreamController<DeviceMessageDto>();
+      when(
+        () => rust.crateSubscribeDeviceMessages(),
+      ).thenAnswer((_) => outgoing.stream);
+      transport = FakeTransport();
+      service = DeviceCommunicationService(transport);
+    });
+
+    tearDown(() {
+      service.dispose();
+      outgoing.close();
+    });
+
+    group('sendRoute', () {
+      test('sends the route through the native device adapter', () async {
+        const routeJson = '{"waypoints": [[52.37, 4.9], [52.37, 5.11]]}';
+        when(
+          () => rust.crateSendRouteToRemoteDevice(
+            remoteId: any(named: 'remoteId'),
+            routeJson: any(named: 'routeJson'),
+          ),
+        ).thenAnswer((_) async {
+          // The adapter emits the signed frames on its device message channel.
+          outgoing.add(
+            DeviceMessageDto(deviceId: 'AA:BB', bytes: Uint8List.fromList([1])),
+          );
+          outgoing.add(
+            DeviceMessageDto(deviceId: 'AA:BB', bytes: Uint8List.fromList([2])),
+          );
+        });
+
+        await service.sendRoute(remoteId: 'AA:BB', routeJson: routeJson);
+        await pumpEventQueue();
+
+        verify(
+          () => rust.crateSendRouteToRemoteDevice(
+            remoteId: 'AA:BB',
+            routeJson: routeJson,
+          ),
+        ).called(1);
+        verifyNever(
+          () => rust.cratePrepareRouteMessage(
+            routeJson: any(named: 'routeJson'),
+          ),
+        );
+        expect(transport.written.map((w) => w.$1), ['AA:BB', 'AA:BB']);
+        expect(transport.written.map((w) => w.$2), [
+          [1],
+          [2],
+        ]);
+      });
+
+      test('writes frames the adapter sends later, e.g. resends', () async {
+        outgoing.add(
+          DeviceMessageDto(deviceId: 'CC:DD', bytes: Uint8List.fromList([3])),
+        );
+        await pumpEventQueue();
+
+        expect(transport.written.single.$1, 'CC:DD');
+        expect(transport.written.single.$2, [3]);
+      });
+    });
+  });
+}
@@ -1,4 +1,5 @@
 import 'package:mocktail/mocktail.dart';
+import 'package:nav_e/bridge/frb_generated.dart';
 import 'package:nav_e/core/domain/repositories/map_source_repository.dart';
 import 'package:nav_e/core/domain/repositories/geocoding_repository.dart';
 import 'package:nav_e/core/domain/repositories/saved_places_repository.dart';
@@ -14,3 +15,6 @@ class MockSavedPlacesRepository extends Mock
 
 // Platform service mocks
 class MockSharedPreferences extends Mock implements SharedPreferences {}
+
+// Rust bridge mock: install with `RustBridge.initMock(api: ...)` (once per test file)
+class MockRustBridgeApi extends Mock implements RustBridgeApi {}